@@ -0,0 +1,119 @@
+"""
+Alert outcomes for Mindful Touch
+What happened after each alert (the response, and whether the hand came away), to show which alert styles work
+"""
+
+import json
+import time
+from pathlib import Path
+from typing import List, Optional
+
+from PyQt6.QtCore import QObject, QTimer, pyqtSignal
+
+from backend.detection.config import Config
+
+OUTCOMES_PATH = Path.home() / ".mindful-touch" / "alert_outcomes.jsonl"
+
+# Responses besides the toast's snooze/aware/false_positive
+DISMISSED = "dismissed"  # The toast timed out untouched
+
+
+class AlertOutcomes(QObject):
+    """Follows each alert for Config.ALERT_OUTCOME_WINDOW seconds, then appends its outcome to OUTCOMES_PATH
+
+    An outcome: {"time", "regions", "actions", "response", "stopped_after"}, where
+    stopped_after is how many seconds it took the hand to come away (None if it didn't in time).
+    """
+
+    recorded = pyqtSignal(dict)
+
+    def __init__(self, path: Path = OUTCOMES_PATH, parent=None):
+        super().__init__(parent)
+        self.path = path
+        self.current = None  # The alert being followed
+
+        self.window_timer = QTimer(self)
+        self.window_timer.setSingleShot(True)
+        self.window_timer.timeout.connect(self.finalize)
+
+    def alert_shown(self, regions: List[str], actions: List[str], now: Optional[float] = None):
+        self.finalize()
+        self.current = {
+            "time": time.time() if now is None else now,
+            "regions": list(regions),
+            "actions": list(actions),
+            "response": None,
+            "stopped_after": None,
+        }
+        self.window_timer.start(int(Config.ALERT_OUTCOME_WINDOW * 1000))
+
+    def responded(self, response: str):
+        """The first response to the current alert counts"""
+        if self.current is not None and self.current["response"] is None:
+            self.current["response"] = response
+
+    def contact_ended(self, now: Optional[float] = None):
+        if self.current is not None and self.current["stopped_after"] is None:
+            now = time.time() if now is None else now
+            self.current["stopped_after"] = round(now - self.current["time"], 2)
+
+    def finalize(self):
+        """Record the current alert's outcome now, e.g. when the next alert comes or the app quits"""
+        self.window_timer.stop()
+        outcome, self.current = self.current, None
+        if outcome is None:
+            return
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            with self.path.open("a") as out:
+                out.write(json.dumps(outcome) + "\n")
+        except OSError as e:
+            print(f"Could not record alert outcome: {e}")
+        self.recorded.emit(outcome)
+
+    def load(self) -> List[dict]:
+        try:
+            lines = self.path.read_text().splitlines() if self.path.exists() else []
+        except OSError as e:
+            print(f"Could not read alert outcomes: {e}")
+            return []
+        outcomes = []
+        for line in lines:
+            try:
+                outcomes.append(json.loads(line))
+            except ValueError:
+                continue  # A line cut short by a crash
+        return outcomes
+
+    def merge(self, outcomes: List[dict]) -> int:
+        """Add outcomes from elsewhere (an imported settings file) that aren't recorded yet; returns how many were added"""
+        known = {outcome.get("time") for outcome in self.load()}
+        fields = {"time", "regions", "actions", "response", "stopped_after"}
+        added = [outcome for outcome in outcomes if isinstance(outcome, dict) and fields <= set(outcome) and outcome["time"] not in known]
+        added.sort(key=lambda outcome: outcome["time"])
+        if not added:
+            return 0
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            with self.path.open("a") as out:
+                out.writelines(json.dumps(outcome) + "\n" for outcome in added)
+        except OSError as e:
+            print(f"Could not record alert outcomes: {e}")
+            return 0
+        return len(added)
+
+
+def efficacy(outcomes: List[dict]) -> dict:
+    """Per alert style ("pulse+sound", ...): alerts, how many got the hand away in time, and the responses"""
+    styles = {}
+    for outcome in outcomes:
+        style = "+".join(outcome["actions"]) or "none"
+        entry = styles.setdefault(style, {"alerts": 0, "stopped": 0, "responses": {}})
+        entry["alerts"] += 1
+        if outcome["stopped_after"] is not None:
+            entry["stopped"] += 1
+        response = outcome["response"] or "none"
+        entry["responses"][response] = entry["responses"].get(response, 0) + 1
+    for entry in styles.values():
+        entry["stop_rate"] = entry["stopped"] / entry["alerts"]
+    return styles
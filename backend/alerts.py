@@ -0,0 +1,207 @@
+"""
+Alert pipeline for Mindful Touch
+Sits between the bridge's touch events and the sound/toast, so a flurry of detections doesn't become a flurry of alerts
+"""
+
+import time
+from collections import deque
+from dataclasses import dataclass, field
+from datetime import datetime
+from typing import Dict, List, Optional
+
+from PyQt6.QtCore import QObject, QTimer, pyqtSignal
+
+from backend import schedule
+
+HOUR = 3600.0
+
+# What an alert does, from subtle to loud
+PULSE = "pulse"  # Blink the tray icon
+NOTIFY = "notify"  # System notification
+SOUND = "sound"
+TOAST = "toast"  # Alert overlay with snooze/aware/false-positive buttons
+SPEAK = "speak"  # Read a phrase out loud
+ACTIONS = [PULSE, NOTIFY, SOUND, TOAST, SPEAK]
+
+
+class AlertLimiter:
+    """Cooldown per region and a cap on alerts per hour"""
+
+    def __init__(self, min_interval: float = 10.0, max_per_hour: int = 60):
+        self.min_interval = min_interval  # Seconds between alerts for the same region
+        self.region_intervals: Dict[str, float] = {}  # Region -> its own min_interval
+        self.max_per_hour = max_per_hour  # 0 = no cap
+        self.last_alert = {}  # Region -> when it last alerted
+        self.history = deque()  # When each alert in the last hour went out
+
+    def allow(self, regions: List[str], now: float) -> List[str]:
+        """The regions that may alert now (recording the alert), or [] if none may"""
+        while self.history and now - self.history[0] >= HOUR:
+            self.history.popleft()
+        due = [
+            region
+            for region in regions
+            if region not in self.last_alert or now - self.last_alert[region] >= self.region_intervals.get(region, self.min_interval)
+        ]
+        if not due or (self.max_per_hour and len(self.history) >= self.max_per_hour):
+            return []
+        for region in due:
+            self.last_alert[region] = now
+        self.history.append(now)
+        return due
+
+    def reset(self):
+        self.last_alert = {}
+        self.history.clear()
+
+
+@dataclass
+class RegionAlert:
+    """One region's own alert settings; None means the general setting applies"""
+
+    enabled: bool = True
+    actions: Optional[List[str]] = None  # Instead of what the escalation policy chooses
+    cooldown: Optional[float] = None  # Instead of alert_min_interval
+    quiet_hours: List[dict] = field(default_factory=list)  # Windows when this region doesn't alert
+
+    @classmethod
+    def from_settings(cls, config: dict) -> "RegionAlert":
+        actions = config.get("actions")
+        cooldown = config.get("cooldown")
+        return cls(
+            enabled=bool(config.get("enabled", True)),
+            actions=None if actions is None else [action for action in actions if action in ACTIONS],
+            cooldown=None if cooldown is None else max(0.0, float(cooldown)),
+            quiet_hours=[window for window in config.get("quiet_hours", []) if schedule.validate_window(window)],
+        )
+
+
+@dataclass
+class EscalationLevel:
+    touches: int  # Reached at this many touches within the policy window
+    actions: List[str]
+
+
+DEFAULT_LEVELS = [EscalationLevel(1, [PULSE]), EscalationLevel(3, [PULSE, NOTIFY]), EscalationLevel(5, [PULSE, SOUND, TOAST])]
+
+
+class EscalationPolicy:
+    """Repeated touches get louder alerts: the more touches in the window, the higher the level"""
+
+    def __init__(self, levels: Optional[List[EscalationLevel]] = None, window: float = 600.0):
+        self.levels = sorted(levels or DEFAULT_LEVELS, key=lambda level: level.touches)
+        self.window = window  # Seconds
+        self.touches = deque()
+
+    @classmethod
+    def from_settings(cls, settings: dict) -> "EscalationPolicy":
+        """Levels from settings ([{"touches": n, "actions": [...]}, ...]), skipping invalid ones"""
+        levels = []
+        for level in settings.get("escalation_levels", []):
+            try:
+                actions = [action for action in level["actions"] if action in ACTIONS]
+                levels.append(EscalationLevel(max(1, int(level["touches"])), actions))
+            except (KeyError, TypeError, ValueError):
+                print(f"Ignoring invalid escalation level {level!r}")
+        return cls(levels, max(1.0, float(settings.get("escalation_window_minutes", 10.0))) * 60)
+
+    def record(self, now: float):
+        """Count a touch"""
+        self.touches.append(now)
+
+    def actions(self, now: float) -> List[str]:
+        """What an alert should do now, given the touches in the window"""
+        while self.touches and now - self.touches[0] >= self.window:
+            self.touches.popleft()
+        actions = []
+        for level in self.levels:
+            if len(self.touches) >= level.touches:
+                actions = level.actions
+        return list(actions)
+
+    def reset(self):
+        self.touches.clear()
+
+
+class AlertPipeline(QObject):
+    """Rate-limits alerts and coalesces bursts
+
+    The first alert goes out straight away; alerts in the burst_window after it are
+    gathered and go out together, once, when the window closes.
+    """
+
+    alert = pyqtSignal(list, list)  # Regions to alert for, and the actions the escalation policy chose
+    suppressed = pyqtSignal(list)  # Regions held back by the cooldown or hourly cap
+
+    def __init__(self, burst_window: float = 2.0, parent=None):
+        super().__init__(parent)
+        self.limiter = AlertLimiter()
+        self.escalation = EscalationPolicy()
+        self.regions: Dict[str, RegionAlert] = {}
+        self.burst_window = burst_window  # Seconds
+        self.burst_until = 0.0
+        self.pending = []
+
+        self.burst_timer = QTimer(self)
+        self.burst_timer.setSingleShot(True)
+        self.burst_timer.timeout.connect(self._flush)
+
+    def configure(self, settings: dict):
+        self.limiter.min_interval = max(0.0, float(settings["alert_min_interval"]))
+        self.limiter.max_per_hour = max(0, int(settings["alert_max_per_hour"]))
+        self.burst_window = max(0.0, float(settings["alert_burst_window"]))
+        self.escalation = EscalationPolicy.from_settings(settings)
+        self.regions = {region: RegionAlert.from_settings(config) for region, config in settings["region_alerts"].items()}
+        self.limiter.region_intervals = {region: config.cooldown for region, config in self.regions.items() if config.cooldown is not None}
+
+    def region_may_alert(self, region: str, now: float) -> bool:
+        """Not switched off, and outside the region's own quiet hours"""
+        config = self.regions.get(region)
+        if config is None:
+            return True
+        return config.enabled and schedule.active_window(config.quiet_hours, datetime.fromtimestamp(now)) is None
+
+    def submit(self, regions: List[str], now: Optional[float] = None):
+        now = time.time() if now is None else now
+        self.escalation.record(now)
+        regions = [region for region in regions if self.region_may_alert(region, now)]
+        if not regions:
+            return
+        if now < self.burst_until:
+            self.pending.extend(region for region in regions if region not in self.pending)
+            if not self.burst_timer.isActive():
+                self.burst_timer.start(int((self.burst_until - now) * 1000))
+            return
+        self._deliver(list(regions), now)
+
+    def reset(self):
+        """Forget cooldowns and anything pending, e.g. for a new session"""
+        self.burst_timer.stop()
+        self.pending = []
+        self.burst_until = 0.0
+        self.limiter.reset()
+        self.escalation.reset()
+
+    def actions_for(self, regions: List[str], now: float) -> List[str]:
+        """Everything any of the regions asks for: its own actions, or the escalation policy's"""
+        escalated = self.escalation.actions(now)
+        wanted = set()
+        for region in regions:
+            config = self.regions.get(region)
+            wanted.update(config.actions if config and config.actions is not None else escalated)
+        return [action for action in ACTIONS if action in wanted]
+
+    def _flush(self):
+        regions, self.pending = self.pending, []
+        if regions:
+            self._deliver(regions, time.time())
+
+    def _deliver(self, regions: List[str], now: float):
+        allowed = self.limiter.allow(regions, now)
+        held = [region for region in regions if region not in allowed]
+        if allowed:
+            self.burst_until = now + self.burst_window
+            self.alert.emit(allowed, self.actions_for(allowed, now))
+        if held:
+            print(f"Alert for {', '.join(held)} held back (cooldown or hourly limit)")
+            self.suppressed.emit(held)
@@ -0,0 +1,77 @@
+"""
+Launch at login for Mindful Touch
+Registers the app with the OS login items: a LaunchAgent, an XDG autostart entry or the Run registry key
+"""
+
+import os
+import plistlib
+import shlex
+import sys
+from pathlib import Path
+from typing import List
+
+APP_ID = "com.mindfultouch.app"
+SILENT_FLAG = "--background"  # Start hidden in the tray and begin detecting
+
+LAUNCH_AGENT_PATH = Path.home() / "Library" / "LaunchAgents" / f"{APP_ID}.plist"
+DESKTOP_ENTRY_PATH = Path(os.environ.get("XDG_CONFIG_HOME", Path.home() / ".config")) / "autostart" / "mindful-touch.desktop"
+RUN_KEY = r"Software\Microsoft\Windows\CurrentVersion\Run"
+RUN_VALUE = "Mindful Touch"
+
+
+def launch_command(silent: bool) -> List[str]:
+    """How the OS should start us: the bundled executable, or this interpreter and main.py"""
+    if getattr(sys, "frozen", False):
+        command = [sys.executable]
+    else:
+        command = [sys.executable, str(Path(__file__).resolve().parent.parent / "main.py")]
+    return command + ([SILENT_FLAG] if silent else [])
+
+
+def enable(silent: bool = False) -> bool:
+    """Start at login (replacing any earlier registration); returns False if it couldn't be written"""
+    command = launch_command(silent)
+    try:
+        if sys.platform == "darwin":
+            LAUNCH_AGENT_PATH.parent.mkdir(parents=True, exist_ok=True)
+            LAUNCH_AGENT_PATH.write_bytes(plistlib.dumps({"Label": APP_ID, "ProgramArguments": command, "RunAtLoad": True}))
+        elif sys.platform == "win32":
+            import winreg
+
+            with winreg.OpenKey(winreg.HKEY_CURRENT_USER, RUN_KEY, 0, winreg.KEY_SET_VALUE) as key:
+                winreg.SetValueEx(key, RUN_VALUE, 0, winreg.REG_SZ, " ".join(f'"{part}"' for part in command))
+        else:
+            DESKTOP_ENTRY_PATH.parent.mkdir(parents=True, exist_ok=True)
+            DESKTOP_ENTRY_PATH.write_text(
+                "[Desktop Entry]\n"
+                "Type=Application\n"
+                "Name=Mindful Touch\n"
+                f"Exec={shlex.join(command)}\n"
+                "X-GNOME-Autostart-enabled=true\n"
+            )
+        return True
+    except Exception as e:
+        print(f"Could not enable launch at login: {e}")
+        return False
+
+
+def disable() -> bool:
+    """Stop starting at login; returns False if the registration couldn't be removed"""
+    try:
+        if sys.platform == "darwin":
+            if LAUNCH_AGENT_PATH.exists():
+                LAUNCH_AGENT_PATH.unlink()
+        elif sys.platform == "win32":
+            import winreg
+
+            with winreg.OpenKey(winreg.HKEY_CURRENT_USER, RUN_KEY, 0, winreg.KEY_SET_VALUE) as key:
+                try:
+                    winreg.DeleteValue(key, RUN_VALUE)
+                except FileNotFoundError:
+                    pass
+        elif DESKTOP_ENTRY_PATH.exists():
+            DESKTOP_ENTRY_PATH.unlink()
+        return True
+    except Exception as e:
+        print(f"Could not disable launch at login: {e}")
+        return False
@@ -0,0 +1,216 @@
+"""
+Detection event bridge for Mindful Touch
+Turns the per-frame detection dicts into typed session events for the UI
+"""
+
+import time
+from typing import Optional
+
+from PyQt6.QtCore import QObject, QTimer, pyqtSignal
+
+from backend.detection.config import Config
+
+
+class DetectionBridge(QObject):
+    """Session state derived from the detection stream
+
+    The camera thread reports every frame; the bridge only emits when something
+    changes, and owns the session counters the UI displays.
+
+    Contact flickers from frame to frame, so it is debounced into touch episodes:
+    breaks shorter than merge_gap seconds don't end the episode.
+    """
+
+    touch_detected = pyqtSignal(list)  # Regions whose alert just fired (play the sound)
+    hand_near_face = pyqtSignal(bool)  # A touch episode started or ended
+    touch_episode = pyqtSignal(dict)  # A finished episode, see _finish_episode()
+    alert_changed = pyqtSignal(bool)  # Some region is (or no longer is) in alert
+    mindful_stop = pyqtSignal(list)  # Regions the hand was pulled away from in time
+    session_stats = pyqtSignal(dict)  # See stats()
+    touch_labeled = pyqtSignal(dict)  # The user's response to an alert, see label_touch()
+
+    def __init__(self, merge_gap: float = Config.EPISODE_MERGE_GAP, parent=None):
+        super().__init__(parent)
+        self.merge_gap = merge_gap
+        self.episode = None  # The touch in progress
+        self.session_start_time = None
+        self.touches = 0
+        self.mindful_stops = 0
+        self.labels = {}  # Response -> how often it was given this session
+        self.breathing = {"completed": 0, "skipped": 0}  # Breathing exercises offered this session
+        self.last_alert_time = None  # When the most recent alert fired
+        self.last_touch_time = None  # When the most recent touch (alert) started
+        self.longest_clean_streak = 0.0  # Longest stretch between touches that has already ended
+        self.alert_active = False
+        self.contact = False
+
+        # Ticks the session clock once a second
+        self.stats_timer = QTimer(self)
+        self.stats_timer.timeout.connect(self._emit_stats)
+
+    def start_session(self):
+        self.session_start_time = time.time()
+        self.touches = 0
+        self.mindful_stops = 0
+        self.labels = {}
+        self.breathing = {"completed": 0, "skipped": 0}
+        self.last_alert_time = None
+        self.last_touch_time = None
+        self.longest_clean_streak = 0.0
+        self.reset_frame_state()
+        self.stats_timer.start(1000)
+        self._emit_stats()
+
+    def end_session(self):
+        self.stats_timer.stop()
+        if self.episode:
+            self._finish_episode()
+        self.session_start_time = None
+        self.reset_frame_state()
+
+    def reset_frame_state(self):
+        """Forget contact/alert state, e.g. when the camera restarts mid-session"""
+        self.alert_active = False
+        self.contact = False
+        self.episode = None
+
+    def session_seconds(self) -> int:
+        if self.session_start_time:
+            return int(time.time() - self.session_start_time)
+        return 0
+
+    def stats(self) -> dict:
+        return {
+            "touches": self.touches,
+            "mindful_stops": self.mindful_stops,
+            "session_seconds": self.session_seconds(),
+            "labels": dict(self.labels),
+            "breathing": dict(self.breathing),
+        }
+
+    def record_breathing(self, completed: bool):
+        """Count a breathing exercise the user finished, or skipped"""
+        if self.session_start_time is None:
+            return
+        self.breathing["completed" if completed else "skipped"] += 1
+        self._emit_stats()
+
+    def current_clean_streak(self, now: Optional[float] = None) -> float:
+        """Seconds since the last touch (or the session start); 0 outside a session"""
+        if self.session_start_time is None:
+            return 0.0
+        now = time.time() if now is None else now
+        return now - (self.last_touch_time or self.session_start_time)
+
+    def summary(self, now: Optional[float] = None) -> dict:
+        """End-of-session numbers: duration, touches, mindful stops and the longest clean streak (seconds)"""
+        now = time.time() if now is None else now
+        if self.session_start_time is None:
+            return {"duration": 0.0, "touches": 0, "mindful_stops": 0, "longest_clean_streak": 0.0}
+        # The stretch since the last touch counts too
+        current_streak = self.current_clean_streak(now)
+        return {
+            "duration": now - self.session_start_time,
+            "touches": self.touches,
+            "mindful_stops": self.mindful_stops,
+            "longest_clean_streak": max(self.longest_clean_streak, current_streak),
+        }
+
+    def label_touch(self, label: str):
+        """Record the user's response (snooze, aware, false_positive) to the most recent alert"""
+        if self.session_start_time is None:
+            return
+        self.labels[label] = self.labels.get(label, 0) + 1
+        self.touch_labeled.emit({"label": label, "time": time.time(), "alert_time": self.last_alert_time})
+        self._emit_stats()
+
+    def process(self, data: dict, now: Optional[float] = None):
+        """Feed one detection dict from the backend"""
+        if not data or self.session_start_time is None:
+            return
+        now = time.time() if now is None else now
+
+        if data.get("alerts_active"):
+            self.last_alert_time = now
+            self.touch_detected.emit(list(data["alerts_active"]))
+
+        region_details = data.get("region_details", {})
+        alert_active = any(details.get("alert_active", False) for details in region_details.values())
+        regions_with_contact = data.get("regions_with_contact", [])
+        stats_changed = False
+
+        if alert_active != self.alert_active:
+            if alert_active:
+                self.touches += 1
+                self.longest_clean_streak = max(self.longest_clean_streak, now - (self.last_touch_time or self.session_start_time))
+                self.last_touch_time = now
+            else:
+                # Ending an alert by moving the hand away counts as a mindful stop too
+                self.mindful_stops += 1
+            self.alert_active = alert_active
+            stats_changed = True
+            self.alert_changed.emit(alert_active)
+
+        # Quick hand removals before the alert fired
+        mindful_stops_detected = data.get("mindful_stops_detected", [])
+        if mindful_stops_detected:
+            self.mindful_stops += len(mindful_stops_detected)
+            stats_changed = True
+            self.mindful_stop.emit(list(mindful_stops_detected))
+
+        self._track_episode(regions_with_contact, data.get("contact_points", 0), alert_active, now)
+
+        if stats_changed:
+            self._emit_stats()
+
+    def _track_episode(self, regions_with_contact, contact_points, alert_active, now):
+        if regions_with_contact:
+            if self.episode is None:
+                self.episode = {
+                    "start": now,
+                    "last_contact": now,
+                    "regions": set(),
+                    "peak_contacts": 0,
+                    "alerted": False,
+                    "alerted_at": None,  # When its first alert fired
+                    "frames": 0,  # Frames seen during the episode, and how many of them had contact
+                    "contact_frames": 0,
+                }
+                self.contact = True
+                self.hand_near_face.emit(True)
+            self.episode["last_contact"] = now
+            self.episode["regions"].update(regions_with_contact)
+            self.episode["peak_contacts"] = max(self.episode["peak_contacts"], contact_points)
+        elif self.episode and now - self.episode["last_contact"] >= self.merge_gap:
+            self._finish_episode()
+
+        if self.episode:
+            self.episode["frames"] += 1
+            self.episode["contact_frames"] += bool(regions_with_contact)
+        if self.episode and alert_active and not self.episode["alerted"]:
+            self.episode["alerted"], self.episode["alerted_at"] = True, now
+
+    def _finish_episode(self):
+        """Emit the episode: start/end timestamps, duration, regions, peak contact points, alerted (and when), confidence
+
+        Confidence is the share of the episode's frames that had contact; a touch that flickers in and out scores lower.
+        """
+        episode, self.episode = self.episode, None
+        self.contact = False
+        self.hand_near_face.emit(False)
+        self.touch_episode.emit(
+            {
+                "start": episode["start"],
+                "end": episode["last_contact"],
+                "duration": episode["last_contact"] - episode["start"],
+                "regions": sorted(episode["regions"]),
+                "peak_contacts": episode["peak_contacts"],
+                "alerted": episode["alerted"],
+                "alerted_at": episode["alerted_at"],
+                "confidence": round(episode["contact_frames"] / episode["frames"], 3),
+            }
+        )
+
+    def _emit_stats(self):
+        if self.session_start_time is not None:
+            self.session_stats.emit(self.stats())
@@ -0,0 +1,39 @@
+"""
+Camera enumeration for Mindful Touch
+The cameras on this machine, with the index OpenCV opens each one by
+"""
+
+import sys
+from pathlib import Path
+from typing import List
+
+V4L_PATH = Path("/sys/class/video4linux")
+
+
+def _linux_cameras() -> List[dict]:
+    # /dev/videoN is opened as index N; each camera also has metadata nodes, which report index 1 and can't capture
+    cameras = []
+    for device in V4L_PATH.glob("video*"):
+        try:
+            if (device / "index").read_text().strip() != "0":
+                continue
+            cameras.append({"index": int(device.name[len("video") :]), "name": (device / "name").read_text().strip()})
+        except (OSError, ValueError):
+            continue
+    return sorted(cameras, key=lambda camera: camera["index"])
+
+
+def _qt_cameras() -> List[dict]:
+    # AVFoundation and DirectShow hand cameras to OpenCV in the order Qt lists them
+    from PyQt6.QtMultimedia import QMediaDevices
+
+    return [{"index": index, "name": device.description()} for index, device in enumerate(QMediaDevices.videoInputs())]
+
+
+def list_cameras() -> List[dict]:
+    """[{"index", "name"}] for every camera; empty if they can't be listed"""
+    try:
+        return _linux_cameras() if sys.platform.startswith("linux") else _qt_cameras()
+    except Exception as e:
+        print(f"Could not list cameras: {e}")
+        return []
@@ -0,0 +1,54 @@
+"""
+Bounded channels for Mindful Touch
+Carry per-frame data from the camera thread to the UI thread without letting it pile up
+"""
+
+import threading
+from collections import deque
+from typing import Callable, List, Optional
+
+
+class BoundedChannel:
+    """Thread-safe FIFO with a size cap; when full, the oldest droppable item makes room
+
+    Items for which is_critical() is true are never dropped, so a channel holding
+    nothing but critical items grows past its cap instead of losing one.
+    """
+
+    def __init__(self, maxlen: int, is_critical: Optional[Callable[[object], bool]] = None):
+        self.maxlen = maxlen
+        self.is_critical = is_critical or (lambda item: False)
+        self.items = deque()
+        self.dropped = 0  # Items discarded to make room, for diagnostics
+        self._lock = threading.Lock()
+
+    def put(self, item) -> bool:
+        """Add an item; returns True if the channel was empty, i.e. the reader needs a nudge"""
+        with self._lock:
+            was_empty = not self.items
+            if len(self.items) >= self.maxlen:
+                self._drop_oldest()
+            self.items.append(item)
+            return was_empty
+
+    def _drop_oldest(self):
+        for index, item in enumerate(self.items):
+            if not self.is_critical(item):
+                del self.items[index]
+                self.dropped += 1
+                return
+
+    def drain(self) -> List[object]:
+        """Take everything queued so far, oldest first"""
+        with self._lock:
+            items = list(self.items)
+            self.items.clear()
+            return items
+
+    def __len__(self):
+        return len(self.items)
+
+
+def is_critical_detection(data: dict) -> bool:
+    """Detections carrying a one-shot event (alert fired, mindful stop) must reach the UI"""
+    return bool(data.get("alerts_active") or data.get("mindful_stops_detected"))
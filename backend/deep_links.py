@@ -0,0 +1,74 @@
+"""
+Deep links for Mindful Touch
+mindfultouch:// URLs from scripts, notifications or Stream Deck, parsed into actions for the window
+"""
+
+import shlex
+import subprocess
+import sys
+from pathlib import Path
+from typing import Optional, Tuple
+from urllib.parse import parse_qs, urlparse
+
+from backend.autostart import launch_command
+
+SCHEME = "mindfultouch"
+ACTIONS = ["pause", "resume", "start", "stop", "snooze", "unsnooze", "open", "stats"]
+
+DESKTOP_ENTRY_PATH = Path.home() / ".local" / "share" / "applications" / "mindful-touch-url.desktop"
+CLASSES_KEY = rf"Software\Classes\{SCHEME}"
+
+
+def parse(url: str) -> Optional[Tuple[str, dict]]:
+    """'mindfultouch://pause?minutes=30' -> ("pause", {"minutes": 30.0}); None if it isn't one of ours
+
+    The host is the action; a path after it is kept as "view" (mindfultouch://stats/today).
+    """
+    parsed = urlparse(url)
+    if parsed.scheme != SCHEME or parsed.netloc not in ACTIONS:
+        return None
+    params = {}
+    view = parsed.path.strip("/")
+    if view:
+        params["view"] = view
+    for key, values in parse_qs(parsed.query).items():
+        if key == "minutes":
+            try:
+                params["minutes"] = max(0.0, float(values[-1]))
+            except ValueError:
+                return None
+        else:
+            params[key] = values[-1]
+    return parsed.netloc, params
+
+
+def register() -> bool:
+    """Make the OS open mindfultouch:// links with us; macOS reads the scheme from the app bundle instead"""
+    command = launch_command(silent=False)
+    try:
+        if sys.platform == "darwin":
+            return True
+        if sys.platform == "win32":
+            import winreg
+
+            with winreg.CreateKey(winreg.HKEY_CURRENT_USER, CLASSES_KEY) as key:
+                winreg.SetValueEx(key, None, 0, winreg.REG_SZ, "URL:Mindful Touch")
+                winreg.SetValueEx(key, "URL Protocol", 0, winreg.REG_SZ, "")
+            with winreg.CreateKey(winreg.HKEY_CURRENT_USER, CLASSES_KEY + r"\shell\open\command") as key:
+                winreg.SetValueEx(key, None, 0, winreg.REG_SZ, " ".join(f'"{part}"' for part in command) + ' "%1"')
+            return True
+
+        DESKTOP_ENTRY_PATH.parent.mkdir(parents=True, exist_ok=True)
+        DESKTOP_ENTRY_PATH.write_text(
+            "[Desktop Entry]\n"
+            "Type=Application\n"
+            "Name=Mindful Touch\n"
+            f"Exec={shlex.join(command)} %u\n"
+            f"MimeType=x-scheme-handler/{SCHEME};\n"
+            "NoDisplay=true\n"
+        )
+        subprocess.run(["xdg-mime", "default", DESKTOP_ENTRY_PATH.name, f"x-scheme-handler/{SCHEME}"], capture_output=True, timeout=5)
+        return True
+    except Exception as e:
+        print(f"Could not register {SCHEME}:// links: {e}")
+        return False
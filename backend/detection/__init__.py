@@ -4,5 +4,6 @@ Detection components for Mindful Touch
 
 from .config import Config
 from .multi_region_detector import MultiRegionDetector
+from .options import BackendOptions
 
-__all__ = ["MultiRegionDetector", "Config"]
+__all__ = ["MultiRegionDetector", "Config", "BackendOptions"]
@@ -15,9 +15,41 @@ class Config:
 
     # Detection settings
     CONTACT_THRESHOLD = 0.05  # Distance threshold for contact detection
+    CONTACT_TOLERANCE_PX = 20  # Fingertips this far outside a region still count (scaled by sensitivity)
     MIN_DETECTION_TIME = 0.3  # Seconds before triggering alert
     MIN_MINDFUL_CONTACT_TIME = 0.2  # Minimum contact time to count as mindful stop
 
+    # Backend supervision (automatic restart after the camera thread dies)
+    MAX_FRAME_FAILURES = 30  # Consecutive failed camera reads before giving up on the camera
+    RESTART_BASE_DELAY = 1.0  # Seconds before the first restart attempt, doubled per attempt
+    RESTART_MAX_DELAY = 30.0  # Upper bound on the restart backoff
+    MAX_RESTART_ATTEMPTS = 5  # Give up after this many restarts in a row
+    RESTART_STABLE_TIME = 60.0  # Seconds of uptime after which the restart counter resets
+    HEARTBEAT_INTERVAL = 1.0  # Seconds between health checks of the camera thread
+    HEARTBEAT_TIMEOUT = 3.0  # Seconds without a processed frame before the thread counts as unresponsive
+    METRICS_INTERVAL = 2.0  # Seconds between CPU/memory samples while detecting
+    STARTUP_TIMEOUT = 20.0  # Seconds to wait for the first processed frame after starting
+    SHUTDOWN_TIMEOUT = 3.0  # Seconds to let the camera thread finish its frame before forcing it down
+    CRASH_LOG_LINES = 50  # stderr lines attached to a crash report
+    LOW_PRIORITY_NICE = 10  # Nice value for the camera thread in low-priority mode (Linux)
+    COMMAND_TIMEOUT = 2.0  # Seconds for the camera thread to answer a command
+    COMMAND_BUFFER_TIMEOUT = 30.0  # Seconds a command may wait for a starting camera before it is dropped
+    EPISODE_MERGE_GAP = 0.5  # Contact breaks shorter than this belong to the same touch episode
+    PREVIEW_MAX_FPS = 15.0  # Preview frames sent to the UI per second, at most
+    PREVIEW_MAX_WIDTH = 960  # Preview frames are downscaled to this width
+    FRAME_CHANNEL_SIZE = 2  # Preview frames waiting for the UI; older ones are dropped
+    DETECTION_CHANNEL_SIZE = 30  # Detections waiting for the UI; older ones without alerts are dropped
+    SESSION_POLL_INTERVAL = 3.0  # Seconds between screen-lock checks
+    SLEEP_DETECTION_GAP = 10.0  # Wall-clock time unaccounted for by this much means the machine slept
+    DND_POLL_INTERVAL = 10.0  # Seconds between Do Not Disturb checks
+    FOREGROUND_POLL_INTERVAL = 2.0  # Seconds between looks at the foreground app, while quiet-app rules need it
+    POWER_POLL_INTERVAL = 30.0  # Seconds between battery checks
+    ALERT_OUTCOME_WINDOW = 10.0  # Seconds after an alert in which the hand coming away counts as the alert working
+    SCHEDULE_POLL_INTERVAL = 30.0  # Seconds between checks whether we're inside the active hours
+    STREAK_UPDATE_INTERVAL = 60.0  # Seconds between updates of the clean streak shown in the tray
+    PRUNE_INTERVAL = 6 * 3600.0  # Seconds between roll-ups of touch events past the history_days setting
+    STATS_TICK_INTERVAL = 5.0  # Seconds between the live stats sent to whatever listens to stats_tick
+
     # Visual settings
     REGION_COLOR = (0, 255, 255)  # Yellow region outlines
     CONTACT_COLOR = (0, 0, 255)  # Red contact points
@@ -38,6 +70,27 @@ class Config:
         "beard": {"contact_threshold": 0.04, "min_detection_time": 1.0, "alert_cooldown_time": 1.0, "show_landmarks": True},
     }
 
+    # User-defined regions (see custom_regions.py): id -> definition, from the settings
+    CUSTOM_REGIONS = {}
+    CUSTOM_REGION_SETTINGS = {"contact_threshold": 0.03, "alert_cooldown_time": 1.0, "show_landmarks": True}
+
+    @classmethod
+    def all_regions(cls):
+        """Built-in regions first, then the custom ones"""
+        return cls.AVAILABLE_REGIONS + list(cls.CUSTOM_REGIONS)
+
+    @classmethod
+    def set_custom_regions(cls, definitions: dict):
+        """Replace the custom regions; they get the same detection time as the built-in ones"""
+        duration = cls.REGION_SETTINGS[cls.AVAILABLE_REGIONS[0]]["min_detection_time"]
+        for region in list(cls.REGION_SETTINGS):
+            if region not in cls.AVAILABLE_REGIONS and region not in definitions:
+                del cls.REGION_SETTINGS[region]
+        for region in definitions:
+            cls.REGION_SETTINGS.setdefault(region, {**cls.CUSTOM_REGION_SETTINGS, "min_detection_time": duration})
+        cls.CUSTOM_REGIONS = dict(definitions)
+        cls.ACTIVE_REGIONS = [region for region in cls.ACTIVE_REGIONS if region in cls.all_regions()]
+
     @classmethod
     def update_contact_duration(cls, duration: float):
         """Update min_detection_time for all regions"""
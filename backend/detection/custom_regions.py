@@ -0,0 +1,70 @@
+"""
+Custom regions for Mindful Touch
+User-defined zones placed relative to the face mesh (the neck, a cheek), watched alongside the built-in regions
+"""
+
+import re
+from typing import Optional
+
+import cv2
+import numpy as np
+
+from .config import Config
+
+MAX_LANDMARK = 477  # Face mesh with refined landmarks
+FACE_EDGES = (234, 454)  # Outer edges of the face; their distance is the unit for offsets and padding
+ID_PATTERN = re.compile(r"[a-z][a-z0-9_]{0,31}")
+
+# Starting points for the regions people ask for most; offsets reach past the mesh, e.g. down to the neck
+PRESETS = {
+    "neck": {"name": "Neck", "landmarks": [172, 136, 150, 149, 176, 148, 152, 377, 400, 378, 379, 365, 397], "offset": [0.0, 0.45], "padding": 0.15},
+    "nose": {"name": "Nose", "landmarks": [168, 6, 197, 195, 5, 4, 1, 19, 94, 2, 98, 327, 129, 358], "offset": [0.0, 0.0], "padding": 0.03},
+    "left_cheek": {"name": "Left cheek", "landmarks": [50, 101, 118, 117, 116, 123, 147, 187, 205, 36], "offset": [0.0, 0.0], "padding": 0.03},
+    "right_cheek": {"name": "Right cheek", "landmarks": [280, 330, 347, 346, 345, 352, 376, 411, 425, 266], "offset": [0.0, 0.0], "padding": 0.03},
+}
+
+
+def _number(value, low: float, high: float) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool) and low <= value <= high
+
+
+def validate(region_id: str, definition) -> Optional[dict]:
+    """A clean {"name", "landmarks", "offset", "padding"}, or None if the region can't be used
+
+    landmarks: at least three face mesh indices outlining the region. offset: [dx, dy] it is
+    moved by, and padding: how far it is widened, both in face widths.
+    """
+    if not isinstance(region_id, str) or not ID_PATTERN.fullmatch(region_id) or region_id in Config.AVAILABLE_REGIONS:
+        return None
+    if not isinstance(definition, dict):
+        return None
+    landmarks = definition.get("landmarks")
+    if not isinstance(landmarks, list) or len(set(landmarks)) < 3:
+        return None
+    if not all(isinstance(index, int) and not isinstance(index, bool) and 0 <= index <= MAX_LANDMARK for index in landmarks):
+        return None
+    offset = definition.get("offset", [0.0, 0.0])
+    if not isinstance(offset, list) or len(offset) != 2 or not all(_number(value, -2.0, 2.0) for value in offset):
+        return None
+    padding = definition.get("padding", 0.0)
+    if not _number(padding, 0.0, 1.0):
+        return None
+    name = definition.get("name")
+    name = " ".join(name.split()) if isinstance(name, str) and name.strip() else region_id.replace("_", " ").capitalize()
+    return {"name": name, "landmarks": list(landmarks), "offset": [float(value) for value in offset], "padding": float(padding)}
+
+
+def polygon(definition: dict, face_landmarks: np.ndarray) -> np.ndarray:
+    """The region's outline in pixels for this frame's face"""
+    face_width = float(np.linalg.norm(face_landmarks[FACE_EDGES[0]][:2] - face_landmarks[FACE_EDGES[1]][:2]))
+    points = face_landmarks[definition["landmarks"]][:, :2].astype(np.float64)
+    points += np.array(definition["offset"]) * face_width
+
+    if definition["padding"] > 0:
+        # Push every point away from the middle by the same distance
+        directions = points - points.mean(axis=0)
+        lengths = np.linalg.norm(directions, axis=1, keepdims=True)
+        points += np.divide(directions, lengths, out=np.zeros_like(directions), where=lengths > 0) * definition["padding"] * face_width
+
+    hull = cv2.convexHull(points.astype(np.int32))
+    return hull.reshape(-1, 2)
@@ -10,11 +10,16 @@ import cv2
 import mediapipe as mp
 import numpy as np
 
+from backend.protocol import PROTOCOL_VERSION
+
+from . import custom_regions
 from .config import Config
 
 
 class MultiRegionDetector:
-    def __init__(self):
+    def __init__(self, sensitivity: float = 1.0):
+        self.sensitivity = sensitivity
+
         # Initialize MediaPipe
         self.mp_hands = mp.solutions.hands
         self.mp_face_mesh = mp.solutions.face_mesh
@@ -38,21 +43,25 @@ class MultiRegionDetector:
             min_tracking_confidence=Config.FACE_TRACKING_CONFIDENCE,
         )
 
-        # Detection state for each region
+        # Detection state for each region (custom regions get theirs when first watched)
         self.region_states = {}
         for region in Config.AVAILABLE_REGIONS:
-            self.region_states[region] = {
-                "contact_start_time": None,
-                "alert_active": False,
-                "last_alert_time": 0,
-                "alert_triggered": False,
-                "should_play_sound": False,
-                "mindful_stop_detected": False,
-            }
+            self.region_states[region] = self._new_region_state()
 
         # Fingertip indices
         self.FINGERTIPS = [4, 8, 12, 16, 20]
 
+    @staticmethod
+    def _new_region_state() -> Dict[str, Any]:
+        return {
+            "contact_start_time": None,
+            "alert_active": False,
+            "last_alert_time": 0,
+            "alert_triggered": False,
+            "should_play_sound": False,
+            "mindful_stop_detected": False,
+        }
+
     def process_frame(self, frame: np.ndarray) -> Tuple[np.ndarray, Dict[str, Any]]:
         """Process frame with multi-region detection"""
         # Convert BGR to RGB for MediaPipe
@@ -85,6 +94,7 @@ class MultiRegionDetector:
 
         # Prepare detection data
         detection_data = {
+            "protocol_version": PROTOCOL_VERSION,
             "hands_detected": len(hand_landmarks) > 0,
             "face_detected": face_landmarks is not None,
             "contact_points": sum(len(data["contacts"]) for data in filtered_data.values()),
@@ -152,7 +162,7 @@ class MultiRegionDetector:
                             distance = cv2.pointPolygonTest(region_polygon, tuple(fingertip), True)
 
                             # Within contact threshold
-                            if distance >= -20:  # 20 pixels tolerance
+                            if distance >= -Config.CONTACT_TOLERANCE_PX * self.sensitivity:
                                 contact_data[region].append({"point": fingertip, "fingertip_idx": fingertip_idx, "distance": abs(distance)})
 
         return contact_data
@@ -176,6 +186,10 @@ class MultiRegionDetector:
         if "beard" in Config.ACTIVE_REGIONS:
             regions["beard"] = self._create_beard_region(face_landmarks)
 
+        for region, definition in Config.CUSTOM_REGIONS.items():
+            if region in Config.ACTIVE_REGIONS:
+                regions[region] = custom_regions.polygon(definition, face_landmarks)
+
         return regions
 
     def _create_scalp_region(self, face_landmarks: np.ndarray) -> np.ndarray:
@@ -332,7 +346,7 @@ class MultiRegionDetector:
         filtered_data = {}
 
         for region, contacts in contact_data.items():
-            state = self.region_states[region]
+            state = self.region_states.setdefault(region, self._new_region_state())
             settings = Config.REGION_SETTINGS[region]
             has_contact = len(contacts) > 0
 
@@ -421,7 +435,8 @@ class MultiRegionDetector:
 
                 # Add region label
                 center = np.mean(region_polygon, axis=0).astype(int)
-                cv2.putText(frame, region_name.upper(), tuple(center), cv2.FONT_HERSHEY_SIMPLEX, 0.6, Config.REGION_COLOR, 2)
+                label = Config.CUSTOM_REGIONS[region_name]["name"] if region_name in Config.CUSTOM_REGIONS else region_name
+                cv2.putText(frame, label.upper(), tuple(center), cv2.FONT_HERSHEY_SIMPLEX, 0.6, Config.REGION_COLOR, 2)
 
     def _draw_contact_points(self, frame: np.ndarray, filtered_data: Dict[str, Dict]):
         """Draw contact points and alerts"""
@@ -441,7 +456,7 @@ class MultiRegionDetector:
 
     def toggle_region(self, region: str):
         """Toggle region on/off"""
-        if region in Config.AVAILABLE_REGIONS:
+        if region in Config.all_regions():
             if region in Config.ACTIVE_REGIONS:
                 Config.ACTIVE_REGIONS.remove(region)
             else:
@@ -0,0 +1,47 @@
+"""
+Startup options for the detection backend
+Built from the user's saved settings and handed to the camera thread
+"""
+
+from dataclasses import dataclass, field
+from typing import Dict, List
+
+from . import custom_regions
+from .config import Config
+
+LOG_LEVELS = ["info", "debug"]
+
+
+@dataclass
+class BackendOptions:
+    camera_index: int = 0
+    sensitivity: float = 1.0  # Multiplier on the contact tolerance around each region
+    enabled_regions: List[str] = field(default_factory=lambda: list(Config.AVAILABLE_REGIONS))
+    alert_delay: float = 1.0
+    log_level: str = "info"
+    low_priority: bool = False  # Run the camera thread below normal CPU priority
+    idle_shutdown_minutes: float = 10.0  # Suspended this long, the backend shuts down (0 = never)
+    max_fps: float = 0.0  # Frames processed per second, at most (0 = as fast as the camera delivers)
+    custom_regions: Dict[str, dict] = field(default_factory=dict)  # Id -> custom_regions.validate()d definition
+
+    @classmethod
+    def from_settings(cls, settings: dict) -> "BackendOptions":
+        """Build options from a settings dict, ignoring unknown or invalid values"""
+        defaults = cls()
+        log_level = settings.get("log_level", defaults.log_level)
+        custom = {}
+        for region, definition in settings.get("custom_regions", {}).items():
+            cleaned = custom_regions.validate(region, definition)
+            if cleaned is not None:
+                custom[region] = cleaned
+        known = Config.AVAILABLE_REGIONS + list(custom)
+        return cls(
+            camera_index=int(settings.get("camera_index", defaults.camera_index)),
+            sensitivity=max(0.1, float(settings.get("sensitivity", defaults.sensitivity))),
+            enabled_regions=[r for r in settings.get("active_regions", defaults.enabled_regions) if r in known],
+            alert_delay=float(settings.get("alert_delay", defaults.alert_delay)),
+            log_level=log_level if log_level in LOG_LEVELS else defaults.log_level,
+            low_priority=bool(settings.get("low_priority", defaults.low_priority)),
+            idle_shutdown_minutes=max(0.0, float(settings.get("idle_shutdown_minutes", defaults.idle_shutdown_minutes))),
+            custom_regions=custom,
+        )
@@ -3,31 +3,273 @@ Settings persistence for Mindful Touch
 Simple JSON file store in the user's home directory
 """
 
+import copy
 import json
+import os
+import shutil
+from datetime import datetime
 from pathlib import Path
+from typing import Callable, List, Optional, Tuple
+
+from backend import onboarding
 
 SETTINGS_PATH = Path.home() / ".mindful-touch" / "settings.json"
 
+# Backups made before a reset, one timestamped folder each
+BACKUPS_PATH = SETTINGS_PATH.parent / "backups"
+RESET_KEEPS = ["onboarding_steps", "autostart", "silent_start"]  # Mirror this machine's state, so a reset leaves them
+
+# Exported settings files
+BUNDLE_FORMAT = "mindful-touch-settings"
+BUNDLE_VERSION = 1
+LOCAL_KEYS = [
+    "camera_index",
+    "extra_cameras",
+    "audio_output",
+    "status_overlay_position",
+    "widget_screen",
+    "widget_corners",
+    "autostart",
+    "silent_start",
+]  # About this machine, so never imported
+
+SCHEMA_VERSION = 1  # Bumped with every entry in MIGRATIONS
+
 DEFAULTS = {
+    "schema_version": SCHEMA_VERSION,
     "active_regions": ["scalp", "eyebrows", "eyes", "mouth", "beard"],
+    "custom_regions": {},  # Id -> {"name", "landmarks", "offset", "padding"}: zones of your own, see custom_regions.py
     "alert_delay": 1.0,
+    "camera_index": 0,
+    "extra_cameras": [],  # Additional camera indices whose detections are merged in
+    "sensitivity": 1.0,
+    "log_level": "info",
+    "low_priority": False,
+    "idle_shutdown_minutes": 10.0,  # Shut detection down after being suspended this long (0 = never)
+    "close_to_tray": True,  # Closing the window hides it; quitting is done from the tray menu
+    "menu_bar_only": False,  # macOS: no Dock icon, only the menu bar icon
+    "autostart": False,  # Launch at login
+    "silent_start": False,  # ...hidden in the tray, detecting right away
+    "toggle_shortcut": "Ctrl+Shift+M",  # Global shortcut that pauses/resumes detection
+    "battery_saver": True,  # On battery, cap the frame rate...
+    "battery_max_fps": 5.0,
+    "battery_pause_percent": 15,  # ...and suspend detection at or below this charge
+    "away_minutes": 10.0,  # Suspend detection after this long without keyboard/mouse input (0 = never)
+    "alert_min_interval": 10.0,  # Seconds between alerts for the same region
+    "alert_max_per_hour": 60,  # At most this many alerts an hour (0 = no limit)
+    "alert_burst_window": 2.0,  # Alerts this soon after another are gathered into one
+    # Region -> {"enabled", "actions", "cooldown", "quiet_hours"} overriding the general alert settings for it
+    "region_alerts": {},
+    "escalation_window_minutes": 10.0,  # Touches within this long of each other escalate the alert...
+    "escalation_levels": [  # ...to the actions (pulse, notify, sound, toast, speak) of the highest level reached
+        {"touches": 1, "actions": ["pulse"]},
+        {"touches": 3, "actions": ["pulse", "notify"]},
+        {"touches": 5, "actions": ["pulse", "sound", "toast"]},
+    ],
+    "alert_sound": "system",  # A built-in chime (chime, bell, blip), "system" or a path to an audio file
+    "region_sounds": {},  # Region -> sound, for regions that should sound different
+    "alert_volume": 0.8,
+    "audio_output": "",  # Output device id ("" = system default)
+    "speech_phrases": [],  # Spoken in turn by the "speak" alert action (none = the language's default)
+    "edge_flash": False,  # Also flash a colored border around every screen on each alert
+    "edge_flash_color": "#B67F5C",
+    "edge_flash_duration_ms": 600,
+    "edge_flash_intensity": 0.6,  # 0..1: border width and opacity
+    "status_overlay": False,  # Small always-on-top status window
+    "status_overlay_position": None,  # [x, y] where it was last dragged to
+    "widget": False,  # Tiny always-on-top streak widget with a pause button
+    "widget_screen": None,  # Name of the screen it was last pinned on
+    "widget_corners": {},  # Screen name -> the corner ("top_left" ... "bottom_right") it's pinned to there
+    "breathing_after_seconds": 0.0,  # Offer a breathing exercise after a touch this long (0 = never); alerts wait until it's closed
+    "language": "",  # Alert wording, tray menu, notifications and dialogs: en, de, es, fr, el ("" = follow the system)
+    "alert_templates": {},  # Key (notify_title, notify_body, toast_title, toast_detail, speech) -> text with {region} etc.
+    "active_hours": [],  # [{"start": "09:00", "end": "18:00", "days": [0..4]}]: detection only runs in these windows (none = any time)
+    "quiet_hours": [],  # [{"start": "21:00", "end": "08:00", "days": [0..6]}]: alerts only logged in these windows
+    "quiet_apps": ["zoom", "teams", "obs", "facetime", "webex", "google meet", "discord"],  # Alerts only logged while one of these has focus
+    "quiet_fullscreen": True,  # ...or any fullscreen window
+    "icon_badge": True,  # Today's touch count on the Dock/taskbar icon (turn off for screen sharing)
+    "history_days": 0,  # Touch events older than this many days are rolled up into daily totals (0 = keep them all)
+    "record_foreground_app": False,  # Note which app had focus with each touch in the history, for get_stats_summary's apps (opt-in)
+    "health_export": False,  # Allow exporting breathing exercises and daily summaries for Apple Health / Google Fit (opt-in)
+    "goals": [],  # [{"kind": "max_touches", "streak_minutes" or "baseline_percent", "target": 20}]: daily goals, see stats/goals.py
+    "profiles": {},  # Name -> the settings in profiles.PROFILE_KEYS
+    "active_profile": "",  # The profile last switched to or saved ("" = none)
+    "onboarding_steps": [],  # First-run steps done (see onboarding.STEPS); detection only starts on its own once all are
 }
 
 
+def _onboarded(settings: dict) -> dict:
+    # Whoever saved settings before first-run setup existed has been through it already
+    settings["onboarding_steps"] = list(onboarding.STEPS)
+    return settings
+
+
+# (version, what it does, step): each step upgrades settings from the version before to its own
+MIGRATIONS: List[Tuple[int, str, Callable[[dict], dict]]] = [
+    (1, "count existing installs as having finished first-run setup", _onboarded),
+]
+last_migrations: List[str] = []  # What the last load() upgraded, for the log and the UI
+last_saved: dict = {}  # The settings as last read from or written to disk, to tell edits made elsewhere from ours
+
+
+def migrate(stored: dict) -> Tuple[dict, List[str]]:
+    """Stored settings brought up to SCHEMA_VERSION one step at a time, and what was done
+
+    Files from before versioning count as version 0. A file from a newer version is left alone.
+    """
+    version = stored.get("schema_version", 0)
+    if not isinstance(version, int) or version > SCHEMA_VERSION:
+        print(f"Settings are from a newer version of Mindful Touch (schema {version}), not migrating")
+        return stored, []
+    settings, applied = dict(stored), []
+    for target, description, step in MIGRATIONS:
+        if target > version:
+            settings = step(settings)
+            settings["schema_version"] = target
+            applied.append(f"{target}: {description}")
+    return settings, applied
+
+
+def _type_ok(key: str, value) -> bool:
+    default = DEFAULTS[key]
+    if default is None:
+        return True  # Optional: anything JSON can hold
+    if isinstance(default, bool):
+        return isinstance(value, bool)
+    if isinstance(default, (int, float)):
+        return isinstance(value, (int, float)) and not isinstance(value, bool)
+    return isinstance(value, type(default))
+
+
+def typed(settings: dict) -> dict:
+    """Settings with any value of the wrong type replaced by its default; keys we don't know are kept as they are"""
+    result = {**copy.deepcopy(DEFAULTS), **settings}
+    for key in DEFAULTS:
+        if not _type_ok(key, result[key]):
+            print(f"Setting {key} should be a {type(DEFAULTS[key]).__name__}, using the default")
+            result[key] = copy.deepcopy(DEFAULTS[key])
+        elif isinstance(DEFAULTS[key], float) and isinstance(result[key], int):
+            result[key] = float(result[key])
+    return result
+
+
 def load() -> dict:
     """Load settings from disk, falling back to defaults on any error"""
+    global last_migrations
+    last_migrations = []
     try:
         if SETTINGS_PATH.exists():
-            return {**DEFAULTS, **json.loads(SETTINGS_PATH.read_text())}
+            stored = json.loads(SETTINGS_PATH.read_text())
+            settings, applied = migrate(stored)
+            if applied:
+                _backup(stored.get("schema_version", 0))
+                save(settings)
+                last_migrations = applied
+                print(f"Settings migrated: {'; '.join(applied)}")
+            settings = typed(settings)
+            _remember(settings)
+            return settings
     except Exception as e:
         print(f"Could not load settings, using defaults: {e}")
-    return DEFAULTS.copy()
+    _remember(DEFAULTS)
+    return copy.deepcopy(DEFAULTS)
+
+
+def _remember(settings: dict):
+    global last_saved
+    last_saved = json.loads(json.dumps(settings))  # A deep copy, safe from later in-place edits
+
+
+def _backup(version):
+    """Keep the file as it was before migrating, next to it (settings.v0.json and so on)"""
+    try:
+        shutil.copy2(SETTINGS_PATH, SETTINGS_PATH.with_name(f"settings.v{version}.json"))
+    except OSError as e:
+        print(f"Could not back up settings before migrating: {e}")
 
 
 def save(settings: dict):
-    """Save settings to disk, silently ignoring write errors"""
+    """Save settings to disk, silently ignoring write errors
+
+    Written to a temporary file that then replaces the old one, so a crash mid-write can't leave half a file.
+    """
     try:
         SETTINGS_PATH.parent.mkdir(parents=True, exist_ok=True)
-        SETTINGS_PATH.write_text(json.dumps(settings, indent=2))
+        temporary = SETTINGS_PATH.with_suffix(".json.tmp")
+        temporary.write_text(json.dumps(settings, indent=2))
+        os.replace(temporary, SETTINGS_PATH)
+        _remember(settings)
     except Exception as e:
         print(f"Could not save settings: {e}")
+
+
+def export_bundle(settings: dict, path, stats: Optional[List[dict]] = None):
+    """Write settings (profiles and per-region settings included) and optionally the alert history to one file
+
+    Raises OSError if it can't be written.
+    """
+    bundle = {"format": BUNDLE_FORMAT, "version": BUNDLE_VERSION, "exported": datetime.now().isoformat(timespec="seconds"), "settings": settings}
+    if stats is not None:
+        bundle["stats"] = stats
+    Path(path).write_text(json.dumps(bundle, indent=2))
+
+
+def read_bundle(path) -> Tuple[dict, Optional[List[dict]]]:
+    """The settings to apply from an exported file (this machine's own keys left out) and its stats, if it has any
+
+    Raises OSError if it can't be read and ValueError if it isn't a settings file this version understands.
+    """
+    bundle = json.loads(Path(path).read_text())
+    if not isinstance(bundle, dict) or bundle.get("format") != BUNDLE_FORMAT:
+        raise ValueError("not a Mindful Touch settings file")
+    if not isinstance(bundle.get("version"), int) or bundle["version"] > BUNDLE_VERSION:
+        raise ValueError(f"made by a newer version of Mindful Touch (format {bundle.get('version')})")
+    if not isinstance(bundle.get("settings"), dict):
+        raise ValueError("no settings in the file")
+    migrated, _ = migrate(bundle["settings"])
+    settings = {key: value for key, value in migrated.items() if key in DEFAULTS and key not in LOCAL_KEYS}
+    stats = bundle.get("stats")
+    return settings, stats if isinstance(stats, list) else None
+
+
+def backup(paths: List[Path]) -> Path:
+    """Copy these files (those that exist) into a new timestamped folder under BACKUPS_PATH; raises OSError"""
+    stamp = datetime.now().strftime("%Y-%m-%d_%H-%M-%S")
+    folder, copy = BACKUPS_PATH / stamp, 1
+    while folder.exists():
+        copy += 1
+        folder = BACKUPS_PATH / f"{stamp}-{copy}"
+    folder.mkdir(parents=True)
+    for path in paths:
+        if path.exists():
+            shutil.copy2(path, folder / path.name)
+    return folder
+
+
+def list_backups() -> List[dict]:
+    """[{"name", "created", "files"}], newest first"""
+    if not BACKUPS_PATH.exists():
+        return []
+    backups = []
+    for folder in BACKUPS_PATH.iterdir():
+        if folder.is_dir():
+            created = datetime.fromtimestamp(folder.stat().st_mtime).isoformat(timespec="seconds")
+            backups.append({"name": folder.name, "created": created, "files": sorted(path.name for path in folder.iterdir())})
+    return sorted(backups, key=lambda entry: entry["name"], reverse=True)
+
+
+def restore_backup(name: str, paths: List[Path]) -> List[str]:
+    """Put back the files of a backup over these paths (matched by file name); returns the names restored
+
+    Raises FileNotFoundError for an unknown backup and OSError if a file can't be copied.
+    """
+    folder = BACKUPS_PATH / name
+    if not name or folder.parent != BACKUPS_PATH or not folder.is_dir():
+        raise FileNotFoundError(f"No backup named {name!r}")
+    restored = []
+    for path in paths:
+        if (folder / path.name).exists():
+            path.parent.mkdir(parents=True, exist_ok=True)
+            shutil.copy2(folder / path.name, path)
+            restored.append(path.name)
+    return restored
@@ -0,0 +1,63 @@
+"""
+Erasing Mindful Touch's data
+Every file under ~/.mindful-touch (history, logs, settings, backups, sounds) overwritten and then deleted, for machines that are shared
+"""
+
+import os
+from pathlib import Path
+from typing import Callable, List, Optional
+
+from backend.detection.settings_store import SETTINGS_PATH
+from backend.instance_lock import LOCK_PATH
+
+DATA_PATH = SETTINGS_PATH.parent
+KEEP = [LOCK_PATH]  # Held while the app runs; released as it quits
+CHUNK = 1024 * 1024  # Overwritten this many bytes at a time
+
+
+def data_files(folder: Path = DATA_PATH) -> List[Path]:
+    """The files to erase, deepest first so folders empty out as they go"""
+    if not folder.is_dir():
+        return []
+    files = [path for path in folder.rglob("*") if (path.is_file() or path.is_symlink()) and path not in KEEP]
+    return sorted(files, key=lambda path: (-len(path.parts), str(path)))
+
+
+def shred(path: Path):
+    """Overwrite a file with zeros, flush it to disk, then delete it; raises OSError
+
+    On SSDs and copy-on-write file systems the old blocks may survive the overwrite, so this
+    makes recovery harder rather than impossible; full-disk encryption is what closes that gap.
+    """
+    if not path.is_symlink():
+        size = path.stat().st_size
+        with path.open("r+b") as out:
+            zeros = bytes(min(size, CHUNK))
+            for offset in range(0, size, CHUNK):
+                out.write(zeros[: min(CHUNK, size - offset)])
+            out.flush()
+            os.fsync(out.fileno())
+    path.unlink()
+
+
+def erase(files: List[Path], progress: Optional[Callable[[int, int, Path], None]] = None, folder: Path = DATA_PATH) -> List[Path]:
+    """Shred each file, calling progress(done, total, path) after each; returns those that couldn't be erased
+
+    Folders under folder left empty are removed too.
+    """
+    failed = []
+    for done, path in enumerate(files, 1):
+        try:
+            shred(path)
+        except OSError as e:
+            print(f"Could not erase {path}: {e}")
+            failed.append(path)
+        if progress is not None:
+            progress(done, len(files), path)
+    if folder.is_dir():
+        for directory in sorted((path for path in folder.rglob("*") if path.is_dir()), key=lambda path: -len(path.parts)):
+            try:
+                directory.rmdir()
+            except OSError:
+                continue  # Not empty
+    return failed
@@ -0,0 +1,92 @@
+"""
+Do Not Disturb detection for Mindful Touch
+Reads macOS Focus, Windows Focus Assist / quiet hours and GNOME's banner setting
+"""
+
+import ctypes
+import json
+import subprocess
+import sys
+from pathlib import Path
+from typing import Optional
+
+from PyQt6.QtCore import QObject, QTimer, pyqtSignal
+
+from backend.detection.config import Config
+
+# macOS 12+ records active Focus modes here
+MACOS_ASSERTIONS_PATH = Path.home() / "Library" / "DoNotDisturb" / "DB" / "Assertions.json"
+
+# SHQueryUserNotificationState results that mean "don't interrupt"
+_QUNS_BUSY = 2  # Full-screen app
+_QUNS_RUNNING_D3D_FULL_SCREEN = 3
+_QUNS_PRESENTATION_MODE = 4
+_QUNS_QUIET_TIME = 6
+_WINDOWS_QUIET_STATES = {_QUNS_BUSY, _QUNS_RUNNING_D3D_FULL_SCREEN, _QUNS_PRESENTATION_MODE, _QUNS_QUIET_TIME}
+
+
+def _macos_dnd() -> Optional[bool]:
+    if not MACOS_ASSERTIONS_PATH.exists():
+        return None
+    data = json.loads(MACOS_ASSERTIONS_PATH.read_text())
+    return any(store.get("storeAssertionRecords") for store in data.get("data", []))
+
+
+def _windows_dnd() -> Optional[bool]:
+    state = ctypes.c_int()
+    if ctypes.windll.shell32.SHQueryUserNotificationState(ctypes.byref(state)) != 0:
+        return None
+    return state.value in _WINDOWS_QUIET_STATES
+
+
+def _linux_dnd() -> Optional[bool]:
+    result = subprocess.run(
+        ["gsettings", "get", "org.gnome.desktop.notifications", "show-banners"], capture_output=True, text=True, timeout=2
+    )
+    value = result.stdout.strip()
+    return value == "false" if value in ("true", "false") else None
+
+
+def get_dnd_state() -> Optional[bool]:
+    """True while the OS asks apps not to interrupt; None if this platform can't tell"""
+    try:
+        if sys.platform == "darwin":
+            return _macos_dnd()
+        if sys.platform == "win32":
+            return _windows_dnd()
+        return _linux_dnd()
+    except Exception:
+        return None
+
+
+class FocusModeMonitor(QObject):
+    """Polls the Do Not Disturb state and reports changes"""
+
+    dnd_changed = pyqtSignal(bool)
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.active = False
+        self.supported = True  # Cleared once the platform can't report the state
+
+        self.timer = QTimer(self)
+        self.timer.timeout.connect(self.refresh)
+
+    def start(self):
+        self.refresh()
+        self.timer.start(int(Config.DND_POLL_INTERVAL * 1000))
+
+    def stop(self):
+        self.timer.stop()
+
+    def refresh(self):
+        if not self.supported:
+            return
+        active = get_dnd_state()
+        if active is None:
+            self.supported = False
+            self.timer.stop()
+            print("Do Not Disturb state unavailable, alerts always play")
+        elif active != self.active:
+            self.active = active
+            self.dnd_changed.emit(active)
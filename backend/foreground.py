@@ -0,0 +1,149 @@
+"""
+Foreground app awareness for Mindful Touch
+Which application has focus, so alerts can go quiet during video calls, streams and fullscreen apps
+"""
+
+import ctypes
+import re
+import subprocess
+import sys
+import threading
+from pathlib import Path
+from typing import List, Optional
+
+from backend.detection.config import Config
+
+
+def _macos_foreground() -> Optional[dict]:
+    # lsappinfo needs no Accessibility permission, unlike asking System Events
+    front = subprocess.run(["lsappinfo", "front"], capture_output=True, text=True, timeout=2).stdout.strip()
+    if not front:
+        return None
+    info = subprocess.run(["lsappinfo", "info", "-only", "name", front], capture_output=True, text=True, timeout=2).stdout
+    match = re.search(r'"LSDisplayName"="([^"]*)"', info)
+    if not match:
+        return None
+    # Window titles and fullscreen state need Screen Recording / Accessibility access; go by the app alone
+    return {"app": match.group(1), "title": "", "fullscreen": False}
+
+
+def _windows_foreground() -> Optional[dict]:
+    import ctypes.wintypes
+
+    PROCESS_QUERY_LIMITED_INFORMATION = 0x1000
+    user32, kernel32 = ctypes.windll.user32, ctypes.windll.kernel32
+    window = user32.GetForegroundWindow()
+    if not window:
+        return None
+
+    title = ctypes.create_unicode_buffer(512)
+    user32.GetWindowTextW(window, title, len(title))
+
+    pid = ctypes.wintypes.DWORD()
+    user32.GetWindowThreadProcessId(window, ctypes.byref(pid))
+    app = ""
+    process = kernel32.OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, False, pid.value)
+    if process:
+        path = ctypes.create_unicode_buffer(1024)
+        size = ctypes.wintypes.DWORD(len(path))
+        if kernel32.QueryFullProcessImageNameW(process, 0, path, ctypes.byref(size)):
+            app = Path(path.value).stem
+        kernel32.CloseHandle(process)
+
+    # Fullscreen: the window covers its whole monitor
+    MONITOR_DEFAULTTONEAREST = 2
+
+    class MonitorInfo(ctypes.Structure):
+        _fields_ = [
+            ("cbSize", ctypes.wintypes.DWORD),
+            ("rcMonitor", ctypes.wintypes.RECT),
+            ("rcWork", ctypes.wintypes.RECT),
+            ("dwFlags", ctypes.wintypes.DWORD),
+        ]
+
+    rect = ctypes.wintypes.RECT()
+    info = MonitorInfo(ctypes.sizeof(MonitorInfo))
+    user32.GetWindowRect(window, ctypes.byref(rect))
+    user32.GetMonitorInfoW(user32.MonitorFromWindow(window, MONITOR_DEFAULTTONEAREST), ctypes.byref(info))
+    monitor = info.rcMonitor
+    fullscreen = (rect.left, rect.top, rect.right, rect.bottom) == (monitor.left, monitor.top, monitor.right, monitor.bottom)
+    return {"app": app, "title": title.value, "fullscreen": fullscreen}
+
+
+def _linux_foreground() -> Optional[dict]:
+    # X11 (and XWayland windows); Wayland compositors don't tell other clients what has focus
+    active = subprocess.run(["xprop", "-root", "_NET_ACTIVE_WINDOW"], capture_output=True, text=True, timeout=2).stdout
+    match = re.search(r"window id # (0x[0-9a-f]+)", active)
+    if not match or int(match.group(1), 16) == 0:
+        return None
+    props = subprocess.run(
+        ["xprop", "-id", match.group(1), "WM_CLASS", "_NET_WM_NAME", "_NET_WM_STATE"], capture_output=True, text=True, timeout=2
+    ).stdout
+    wm_class = re.findall(r'WM_CLASS\(STRING\) = "([^"]*)", "([^"]*)"', props)
+    title = re.search(r'_NET_WM_NAME\(UTF8_STRING\) = "(.*)"', props)
+    return {
+        "app": wm_class[0][1] if wm_class else "",
+        "title": title.group(1) if title else "",
+        "fullscreen": "_NET_WM_STATE_FULLSCREEN" in props,
+    }
+
+
+def get_foreground_app() -> Optional[dict]:
+    """{"app": name, "title": window title, "fullscreen": bool}; None if this platform or session can't tell"""
+    try:
+        if sys.platform == "darwin":
+            return _macos_foreground()
+        if sys.platform == "win32":
+            return _windows_foreground()
+        return _linux_foreground()
+    except Exception:
+        return None
+
+
+class ForegroundMonitor:
+    """Polls get_foreground_app() on a thread of its own; current is what it last found
+
+    The lookup shells out on macOS and Linux, and a slow or missing X server can keep it waiting for
+    seconds, so nothing on the UI thread ever does; an alert goes by the app focused up to a poll ago.
+    """
+
+    def __init__(self, interval: float = Config.FOREGROUND_POLL_INTERVAL):
+        self.interval = interval
+        self.current = None  # None before the first lookup, while stopped, or if this platform can't tell
+        self._stopped = None  # Set to end the running poll; None while stopped
+
+    @property
+    def running(self) -> bool:
+        return self._stopped is not None
+
+    def start(self):
+        if self._stopped is None:
+            self._stopped = threading.Event()
+            threading.Thread(target=self._poll, args=(self._stopped,), name="foreground-app", daemon=True).start()
+
+    def stop(self):
+        if self._stopped is not None:
+            self._stopped.set()
+            self._stopped = None
+        self.current = None
+
+    def _poll(self, stopped: threading.Event):
+        while not stopped.is_set():
+            found = get_foreground_app()
+            if not stopped.is_set():
+                self.current = found
+            stopped.wait(self.interval)
+
+
+def matching_rule(foreground: Optional[dict], rules: List[str], quiet_fullscreen: bool = True) -> Optional[str]:
+    """The rule that makes this foreground app quiet ("fullscreen" for fullscreen windows), or None"""
+    if not foreground:
+        return None
+    haystack = f"{foreground.get('app', '')} {foreground.get('title', '')}".lower()
+    for rule in rules:
+        # Whole words, so "obs" doesn't match "jobs.py" in an editor title
+        if rule.strip() and re.search(rf"\b{re.escape(rule.strip().lower())}\b", haystack):
+            return rule
+    if quiet_fullscreen and foreground.get("fullscreen"):
+        return "fullscreen"
+    return None
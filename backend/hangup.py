@@ -0,0 +1,63 @@
+"""
+SIGHUP watcher for Mindful Touch
+`kill -HUP <pid>` reloads the settings file, so scripts can change the configuration without a restart
+"""
+
+import signal
+import socket
+
+from PyQt6.QtCore import QObject, QSocketNotifier, pyqtSignal
+
+
+class HangupWatcher(QObject):
+    """Turns SIGHUP into a Qt signal on the main thread
+
+    Python only runs signal handlers between bytecodes, which never happens while Qt
+    sits in its event loop; the wakeup fd lets the loop notice the signal instead.
+    """
+
+    hangup = pyqtSignal()
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.supported = hasattr(signal, "SIGHUP")  # Not on Windows
+        self.notifier = None
+        self._reader = self._writer = None
+
+    def install(self) -> bool:
+        if not self.supported:
+            return False
+        try:
+            self._reader, self._writer = socket.socketpair()
+            self._reader.setblocking(False)
+            self._writer.setblocking(False)
+            signal.set_wakeup_fd(self._writer.fileno())
+            # A Python-level handler replaces the default action (terminate)
+            signal.signal(signal.SIGHUP, lambda signum, frame: None)
+        except (OSError, ValueError) as e:
+            print(f"Could not watch for SIGHUP: {e}")
+            self.supported = False
+            return False
+        self.notifier = QSocketNotifier(self._reader.fileno(), QSocketNotifier.Type.Read, self)
+        self.notifier.activated.connect(self._on_wakeup)
+        return True
+
+    def uninstall(self):
+        if self.notifier is None:
+            return
+        self.notifier.setEnabled(False)
+        signal.set_wakeup_fd(-1)
+        signal.signal(signal.SIGHUP, signal.SIG_DFL)
+        self._reader.close()
+        self._writer.close()
+        self.notifier = None
+
+    def _on_wakeup(self, _=None):
+        try:
+            received = self._reader.recv(64)
+        except OSError:
+            return
+        # The wakeup fd carries the numbers of every signal that arrived
+        if signal.SIGHUP in received:
+            print("SIGHUP received, reloading settings")
+            self.hangup.emit()
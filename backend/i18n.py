@@ -0,0 +1,642 @@
+"""
+Interface translations for Mindful Touch
+Tray menu, notification and dialog text in the same languages as the alert wording, looked up by the English text
+"""
+
+from backend.templates import DEFAULTS as ALERT_LANGUAGES
+from backend.templates import FALLBACK_LANGUAGE, system_language
+
+# English text -> translation; placeholders stay as they are. Missing entries fall back to English.
+CATALOGS = {
+    "de": {
+        # Tray
+        "{duration} hands-free": "{duration} ohne Berührung",
+        "Pause Detection": "Erkennung pausieren",
+        "Resume": "Fortsetzen",
+        "Pause": "Pause",
+        "Not detecting": "Nicht aktiv",
+        "Detector view": "Detektoransicht",
+        "Start detection to see what the detector sees.": "Starte die Erkennung, um zu sehen, was der Detektor sieht.",
+        "Detection is suspended.": "Die Erkennung ist ausgesetzt.",
+        "The camera feed is hidden.": "Das Kamerabild ist ausgeblendet.",
+        "Waiting for the camera…": "Warte auf die Kamera…",
+        "Snooze Alerts": "Hinweise stummschalten",
+        "For {minutes} Minutes": "Für {minutes} Minuten",
+        "Alerts snoozed · {minutes} min left": "Hinweise stumm · noch {minutes} Min.",
+        "Resume Alerts": "Hinweise wieder einschalten",
+        "Outside scheduled hours": "Außerhalb der geplanten Zeiten",
+        "Detect Anyway for an Hour": "Trotzdem eine Stunde erkennen",
+        "Profile": "Profil",
+        "Open Dashboard": "Übersicht öffnen",
+        "Hide Dock Icon": "Dock-Symbol ausblenden",
+        "Quit": "Beenden",
+        "Mindful Touch (alerts snoozed, {minutes} min left)": "Mindful Touch (Hinweise stumm, noch {minutes} Min.)",
+        "Mindful Touch — quiet while Do Not Disturb is on": "Mindful Touch – still, solange „Nicht stören“ an ist",
+        # Notifications
+        "Switched to {name}": "Zu {name} gewechselt",
+        "Alerts resumed": "Hinweise sind wieder an",
+        "Detection resumed": "Erkennung fortgesetzt",
+        "Detection paused": "Erkennung pausiert",
+        "Detection started": "Erkennung gestartet",
+        "Detection could not start": "Erkennung konnte nicht starten",
+        "While Do Not Disturb was on": "Während „Nicht stören“ an war",
+        "1 touch noticed ({regions})": "1 Berührung bemerkt ({regions})",
+        "{count} touches noticed ({regions})": "{count} Berührungen bemerkt ({regions})",
+        "On battery, detecting at a lower frame rate": "Im Akkubetrieb, Erkennung mit niedrigerer Bildrate",
+        "Plugged in, detecting at full speed": "Am Netz, Erkennung mit voller Geschwindigkeit",
+        "Battery at {percent}%, detection suspended until you plug in": "Akku bei {percent} %, Erkennung ruht bis zum Anschließen",
+        "Mindful Touch is still running": "Mindful Touch läuft weiter",
+        "Detection continues in the background. Quit from the tray icon.": "Die Erkennung läuft im Hintergrund weiter. Beenden über das Symbol in der Menüleiste.",
+        "Goal reached": "Ziel erreicht",
+        "Fewer than {count} touches today": "Weniger als {count} Berührungen heute",
+        "At least one {minutes}-minute streak": "Mindestens eine Serie von {minutes} Minuten",
+        "{percent}% fewer touches than your baseline": "{percent} % weniger Berührungen als in deiner Ausgangsphase",
+        "Achievement unlocked": "Erfolg freigeschaltet",
+        "Hour of calm": "Eine Stunde Ruhe",
+        "An hour without touching your face": "Eine Stunde, ohne das Gesicht zu berühren",
+        "Week of awareness": "Eine Woche Achtsamkeit",
+        "Mindful Touch ran on seven days in a row": "Mindful Touch lief sieben Tage in Folge",
+        "Half as often": "Halb so oft",
+        "Half as many touches in a week as in the week before": "Halb so viele Berührungen in einer Woche wie in der Woche davor",
+        # App menu
+        "About Mindful Touch": "Über Mindful Touch",
+        "Restart Detection": "Erkennung neu starten",
+        "Suspend Detection": "Erkennung aussetzen",
+        "Show Status Overlay": "Statusanzeige einblenden",
+        "Show Streak Widget": "Serien-Widget einblenden",
+        "Show Detector View": "Detektoransicht einblenden",
+        "Flash Screen Edges on Alert": "Bildschirmränder bei Hinweisen aufleuchten lassen",
+        "Show Today's Count on Icon": "Heutige Anzahl am Symbol zeigen",
+        "Note the App in Use with Each Touch": "Bei jeder Berührung die aktive App notieren",
+        "Run at Low Priority": "Mit niedriger Priorität ausführen",
+        "Keep Running When Closed": "Nach dem Schließen weiterlaufen",
+        "Open at Login": "Bei Anmeldung öffnen",
+        "Start in Background at Login": "Bei Anmeldung im Hintergrund starten",
+        "Camera": "Kamera",
+        "Camera {index} (not connected)": "Kamera {index} (nicht verbunden)",
+        "Custom Regions": "Eigene Bereiche",
+        "Add {name}": "{name} hinzufügen",
+        "Remove {name}": "{name} entfernen",
+        "Alert Sound": "Hinweiston",
+        "System Alert": "Systemton",
+        "Choose File…": "Datei auswählen…",
+        "Custom: {name}": "Eigener: {name}",
+        "Output Device": "Ausgabegerät",
+        "System Default": "Systemstandard",
+        "Choose Alert Sound": "Hinweiston auswählen",
+        "Export Settings…": "Einstellungen exportieren…",
+        "Import Settings…": "Einstellungen importieren…",
+        "Export History…": "Verlauf exportieren…",
+        "Weekly Report…": "Wochenbericht…",
+        "Back Up Data…": "Daten sichern…",
+        "Restore Data…": "Daten wiederherstellen…",
+        "Export for Apple Health / Google Fit…": "Für Apple Health / Google Fit exportieren…",
+        "Reset to Defaults…": "Auf Standard zurücksetzen…",
+        "Erase All Data…": "Alle Daten löschen…",
+        "Restore Backup": "Sicherung wiederherstellen",
+        "No Backups": "Keine Sicherungen",
+        "Show Logs": "Protokolle anzeigen",
+        # Dialogs
+        "Reset to Defaults": "Auf Standard zurücksetzen",
+        "Go back to the default settings? The current ones are backed up first.": "Zu den Standardeinstellungen zurückkehren? Die aktuellen werden vorher gesichert.",
+        "The current settings couldn't be backed up, so nothing was reset.": "Die aktuellen Einstellungen konnten nicht gesichert werden, daher wurde nichts zurückgesetzt.",
+        "Export Settings": "Einstellungen exportieren",
+        "Include your alert history?": "Den Verlauf der Hinweise mit exportieren?",
+        "The settings could not be saved there.": "Die Einstellungen konnten dort nicht gespeichert werden.",
+        "Export History": "Verlauf exportieren",
+        "Leave out when each touch happened? Times become seconds after the first one.": "Weglassen, wann jede Berührung war? Die Zeiten werden zu Sekunden nach der ersten.",
+        "The history could not be saved there.": "Der Verlauf konnte dort nicht gespeichert werden.",
+        "Export for Health": "Export für Health",
+        "Export your completed breathing exercises and daily touch counts for Apple Health or Google Fit? They leave Mindful Touch in the file you save.": (
+            "Deine abgeschlossenen Atemübungen und täglichen Berührungszahlen für Apple Health oder Google Fit exportieren? Sie verlassen Mindful Touch in der Datei, die du speicherst."
+        ),
+        "Back Up Data": "Daten sichern",
+        "The backup could not be saved there.": "Die Sicherung konnte dort nicht gespeichert werden.",
+        "Restore Data": "Daten wiederherstellen",
+        "Replace your history and settings with the backup's?": "Verlauf und Einstellungen durch die der Sicherung ersetzen?",
+        "That file isn't a backup this version can restore, or it is damaged.": "Diese Datei ist keine Sicherung, die diese Version wiederherstellen kann, oder sie ist beschädigt.",
+        "Erase All Data": "Alle Daten löschen",
+        "Erase your touch history, logs, settings and backups from this computer, and quit? This can't be undone.": "Berührungsverlauf, Protokolle, Einstellungen und Sicherungen von diesem Computer löschen und beenden? Das lässt sich nicht rückgängig machen.",
+        "Import Settings": "Einstellungen importieren",
+        "That file isn't a settings file this version can read.": "Diese Datei ist keine Einstellungsdatei, die diese Version lesen kann.",
+        "Mindful Touch is already running": "Mindful Touch läuft bereits",
+        "An earlier Mindful Touch (process {pid}) is still running and may be holding the camera.\n\nQuit it now?": (
+            "Ein früheres Mindful Touch (Prozess {pid}) läuft noch und belegt vielleicht die Kamera.\n\nJetzt beenden?"
+        ),
+        "Could not start detection": "Erkennung konnte nicht starten",
+        "Detection stopped": "Erkennung gestoppt",
+        "Detection stopped and could not be restarted automatically.": "Die Erkennung wurde gestoppt und konnte nicht automatisch neu gestartet werden.",
+        "Check that no other app is using the camera, then start detection again.": "Prüfe, ob eine andere App die Kamera nutzt, und starte die Erkennung dann erneut.",
+        "Detection crashed ({error}) and is restarting. The crash report is in {path}": (
+            "Die Erkennung ist abgestürzt ({error}) und startet neu. Der Absturzbericht steht in {path}"
+        ),
+        "The detection backend sent a message this version can't read.": "Die Erkennung hat eine Nachricht gesendet, die diese Version nicht lesen kann.",
+        "Reinstalling Mindful Touch usually fixes this.": "Eine Neuinstallation von Mindful Touch behebt das meist.",
+        "Camera access needed": "Kamerazugriff nötig",
+        "Mindful Touch isn't allowed to use the camera.": "Mindful Touch darf die Kamera nicht verwenden.",
+        "Turn on Mindful Touch under Privacy & Security → Camera, then start detection again.": (
+            "Aktiviere Mindful Touch unter Datenschutz & Sicherheit → Kamera und starte die Erkennung dann erneut."
+        ),
+        "Open System Settings": "Systemeinstellungen öffnen",
+        "Quit Mindful Touch?": "Mindful Touch beenden?",
+        "Quitting ends the current session.": "Beenden schließt die laufende Sitzung ab.",
+        "Session length: {duration}\nTouches: {touches}\nLongest streak without touching: {streak}": (
+            "Sitzungsdauer: {duration}\nBerührungen: {touches}\nLängste Zeit ohne Berührung: {streak}"
+        ),
+        # Weekly report
+        "Weekly Report": "Wochenbericht",
+        "The report could not be saved there.": "Der Bericht konnte dort nicht gespeichert werden.",
+        "Mindful Touch weekly report": "Mindful Touch – Wochenbericht",
+        "Week of {start} to {end}": "Woche vom {start} bis {end}",
+        "Touches": "Berührungen",
+        "Time touching": "Berührungsdauer",
+        "Alerts": "Hinweise",
+        "{change} on the week before": "{change} gegenüber der Vorwoche",
+        "Touches per day": "Berührungen pro Tag",
+        "Regions": "Bereiche",
+        "Region": "Bereich",
+        "Week before": "Vorwoche",
+        "No touches recorded": "Keine Berührungen erfasst",
+        "Streaks": "Serien",
+        "Longest clean streak": "Längste Serie ohne Berührung",
+        "Busiest hours": "Häufigste Uhrzeiten",
+        "Work sessions": "Arbeitssitzungen",
+        "Notes": "Notizen",
+        "Generated {time}": "Erstellt {time}",
+    },
+    "es": {
+        "{duration} hands-free": "{duration} sin tocarte",
+        "Pause Detection": "Pausar detección",
+        "Resume": "Reanudar",
+        "Pause": "Pausa",
+        "Not detecting": "Sin detección",
+        "Detector view": "Vista del detector",
+        "Start detection to see what the detector sees.": "Inicia la detección para ver lo que ve el detector.",
+        "Detection is suspended.": "La detección está suspendida.",
+        "The camera feed is hidden.": "La imagen de la cámara está oculta.",
+        "Waiting for the camera…": "Esperando a la cámara…",
+        "Snooze Alerts": "Silenciar avisos",
+        "For {minutes} Minutes": "Durante {minutes} minutos",
+        "Alerts snoozed · {minutes} min left": "Avisos silenciados · quedan {minutes} min",
+        "Resume Alerts": "Reactivar avisos",
+        "Outside scheduled hours": "Fuera del horario programado",
+        "Detect Anyway for an Hour": "Detectar igualmente durante una hora",
+        "Profile": "Perfil",
+        "Open Dashboard": "Abrir panel",
+        "Hide Dock Icon": "Ocultar icono del Dock",
+        "Quit": "Salir",
+        "Mindful Touch (alerts snoozed, {minutes} min left)": "Mindful Touch (avisos silenciados, quedan {minutes} min)",
+        "Mindful Touch — quiet while Do Not Disturb is on": "Mindful Touch: en silencio mientras No molestar esté activado",
+        "Switched to {name}": "Cambiado a {name}",
+        "Alerts resumed": "Avisos reactivados",
+        "Detection resumed": "Detección reanudada",
+        "Detection paused": "Detección en pausa",
+        "Detection started": "Detección iniciada",
+        "Detection could not start": "No se pudo iniciar la detección",
+        "While Do Not Disturb was on": "Mientras No molestar estaba activado",
+        "1 touch noticed ({regions})": "1 contacto detectado ({regions})",
+        "{count} touches noticed ({regions})": "{count} contactos detectados ({regions})",
+        "On battery, detecting at a lower frame rate": "Con batería, detectando a menos fotogramas por segundo",
+        "Plugged in, detecting at full speed": "Conectado, detectando a máxima velocidad",
+        "Battery at {percent}%, detection suspended until you plug in": "Batería al {percent} %, detección suspendida hasta que conectes el equipo",
+        "Mindful Touch is still running": "Mindful Touch sigue en marcha",
+        "Detection continues in the background. Quit from the tray icon.": "La detección continúa en segundo plano. Sal desde el icono de la bandeja.",
+        "Goal reached": "Objetivo cumplido",
+        "Fewer than {count} touches today": "Menos de {count} contactos hoy",
+        "At least one {minutes}-minute streak": "Al menos una racha de {minutes} minutos",
+        "{percent}% fewer touches than your baseline": "{percent} % menos contactos que en tu periodo de referencia",
+        "Achievement unlocked": "Logro desbloqueado",
+        "Hour of calm": "Una hora de calma",
+        "An hour without touching your face": "Una hora sin tocarte la cara",
+        "Week of awareness": "Una semana de atención",
+        "Mindful Touch ran on seven days in a row": "Mindful Touch funcionó siete días seguidos",
+        "Half as often": "La mitad de veces",
+        "Half as many touches in a week as in the week before": "La mitad de contactos en una semana que en la anterior",
+        "About Mindful Touch": "Acerca de Mindful Touch",
+        "Restart Detection": "Reiniciar la detección",
+        "Suspend Detection": "Suspender la detección",
+        "Show Status Overlay": "Mostrar la superposición de estado",
+        "Show Streak Widget": "Mostrar el widget de racha",
+        "Show Detector View": "Mostrar la vista del detector",
+        "Flash Screen Edges on Alert": "Iluminar los bordes de la pantalla al avisar",
+        "Show Today's Count on Icon": "Mostrar el recuento de hoy en el icono",
+        "Note the App in Use with Each Touch": "Anotar la app en uso con cada toque",
+        "Run at Low Priority": "Ejecutar con prioridad baja",
+        "Keep Running When Closed": "Seguir en ejecución al cerrar",
+        "Open at Login": "Abrir al iniciar sesión",
+        "Start in Background at Login": "Iniciar en segundo plano al iniciar sesión",
+        "Camera": "Cámara",
+        "Camera {index} (not connected)": "Cámara {index} (no conectada)",
+        "Custom Regions": "Zonas personalizadas",
+        "Add {name}": "Añadir {name}",
+        "Remove {name}": "Quitar {name}",
+        "Alert Sound": "Sonido de aviso",
+        "System Alert": "Sonido del sistema",
+        "Choose File…": "Elegir archivo…",
+        "Custom: {name}": "Personalizado: {name}",
+        "Output Device": "Dispositivo de salida",
+        "System Default": "Predeterminado del sistema",
+        "Choose Alert Sound": "Elegir sonido de aviso",
+        "Export Settings…": "Exportar ajustes…",
+        "Import Settings…": "Importar ajustes…",
+        "Export History…": "Exportar historial…",
+        "Weekly Report…": "Informe semanal…",
+        "Back Up Data…": "Copia de seguridad de los datos…",
+        "Restore Data…": "Restaurar datos…",
+        "Export for Apple Health / Google Fit…": "Exportar para Apple Health / Google Fit…",
+        "Reset to Defaults…": "Restablecer valores predeterminados…",
+        "Erase All Data…": "Borrar todos los datos…",
+        "Restore Backup": "Restaurar copia de seguridad",
+        "No Backups": "No hay copias de seguridad",
+        "Show Logs": "Mostrar registros",
+        "Reset to Defaults": "Restablecer valores predeterminados",
+        "Go back to the default settings? The current ones are backed up first.": "¿Volver a los ajustes predeterminados? Antes se guarda una copia de los actuales.",
+        "The current settings couldn't be backed up, so nothing was reset.": "No se pudo guardar una copia de los ajustes actuales, así que no se ha restablecido nada.",
+        "Export Settings": "Exportar ajustes",
+        "Include your alert history?": "¿Incluir el historial de avisos?",
+        "The settings could not be saved there.": "No se pudieron guardar los ajustes ahí.",
+        "Export History": "Exportar historial",
+        "Leave out when each touch happened? Times become seconds after the first one.": "¿Omitir cuándo ocurrió cada contacto? Los tiempos pasan a ser segundos desde el primero.",
+        "The history could not be saved there.": "No se pudo guardar el historial ahí.",
+        "Export for Health": "Exportar para Salud",
+        "Export your completed breathing exercises and daily touch counts for Apple Health or Google Fit? They leave Mindful Touch in the file you save.": (
+            "¿Exportar tus ejercicios de respiración completados y el recuento diario de contactos para Apple Salud o Google Fit? Salen de Mindful Touch en el archivo que guardes."
+        ),
+        "Back Up Data": "Copia de seguridad",
+        "The backup could not be saved there.": "No se pudo guardar la copia de seguridad ahí.",
+        "Restore Data": "Restaurar datos",
+        "Replace your history and settings with the backup's?": "¿Sustituir tu historial y ajustes por los de la copia?",
+        "That file isn't a backup this version can restore, or it is damaged.": "Ese archivo no es una copia que esta versión pueda restaurar, o está dañado.",
+        "Erase All Data": "Borrar todos los datos",
+        "Erase your touch history, logs, settings and backups from this computer, and quit? This can't be undone.": "¿Borrar de este equipo tu historial de contactos, registros, ajustes y copias de seguridad, y salir? No se puede deshacer.",
+        "Import Settings": "Importar ajustes",
+        "That file isn't a settings file this version can read.": "Ese archivo no es un archivo de ajustes que esta versión pueda leer.",
+        "Mindful Touch is already running": "Mindful Touch ya está en marcha",
+        "An earlier Mindful Touch (process {pid}) is still running and may be holding the camera.\n\nQuit it now?": (
+            "Un Mindful Touch anterior (proceso {pid}) sigue en marcha y puede estar usando la cámara.\n\n¿Cerrarlo ahora?"
+        ),
+        "Could not start detection": "No se pudo iniciar la detección",
+        "Detection stopped": "Detección detenida",
+        "Detection stopped and could not be restarted automatically.": "La detección se detuvo y no se pudo reiniciar automáticamente.",
+        "Check that no other app is using the camera, then start detection again.": "Comprueba que ninguna otra app use la cámara y vuelve a iniciar la detección.",
+        "Detection crashed ({error}) and is restarting. The crash report is in {path}": (
+            "La detección falló ({error}) y se está reiniciando. El informe del fallo está en {path}"
+        ),
+        "The detection backend sent a message this version can't read.": "El motor de detección envió un mensaje que esta versión no puede leer.",
+        "Reinstalling Mindful Touch usually fixes this.": "Reinstalar Mindful Touch suele solucionarlo.",
+        "Camera access needed": "Se necesita acceso a la cámara",
+        "Mindful Touch isn't allowed to use the camera.": "Mindful Touch no tiene permiso para usar la cámara.",
+        "Turn on Mindful Touch under Privacy & Security → Camera, then start detection again.": (
+            "Activa Mindful Touch en Privacidad y seguridad → Cámara y vuelve a iniciar la detección."
+        ),
+        "Open System Settings": "Abrir Ajustes del Sistema",
+        "Quit Mindful Touch?": "¿Salir de Mindful Touch?",
+        "Quitting ends the current session.": "Al salir termina la sesión actual.",
+        "Session length: {duration}\nTouches: {touches}\nLongest streak without touching: {streak}": (
+            "Duración de la sesión: {duration}\nContactos: {touches}\nRacha más larga sin tocarte: {streak}"
+        ),
+        # Weekly report
+        "Weekly Report": "Informe semanal",
+        "The report could not be saved there.": "No se pudo guardar el informe ahí.",
+        "Mindful Touch weekly report": "Informe semanal de Mindful Touch",
+        "Week of {start} to {end}": "Semana del {start} al {end}",
+        "Touches": "Contactos",
+        "Time touching": "Tiempo de contacto",
+        "Alerts": "Avisos",
+        "{change} on the week before": "{change} respecto a la semana anterior",
+        "Touches per day": "Contactos por día",
+        "Regions": "Zonas",
+        "Region": "Zona",
+        "Week before": "Semana anterior",
+        "No touches recorded": "No se registraron contactos",
+        "Streaks": "Rachas",
+        "Longest clean streak": "Racha más larga sin contacto",
+        "Busiest hours": "Horas con más contactos",
+        "Work sessions": "Sesiones de trabajo",
+        "Notes": "Notas",
+        "Generated {time}": "Generado {time}",
+    },
+    "fr": {
+        "{duration} hands-free": "{duration} sans contact",
+        "Pause Detection": "Mettre la détection en pause",
+        "Resume": "Reprendre",
+        "Pause": "Pause",
+        "Not detecting": "Détection arrêtée",
+        "Detector view": "Vue du détecteur",
+        "Start detection to see what the detector sees.": "Lancez la détection pour voir ce que voit le détecteur.",
+        "Detection is suspended.": "La détection est suspendue.",
+        "The camera feed is hidden.": "L'image de la caméra est masquée.",
+        "Waiting for the camera…": "En attente de la caméra…",
+        "Snooze Alerts": "Mettre les alertes en sourdine",
+        "For {minutes} Minutes": "Pendant {minutes} minutes",
+        "Alerts snoozed · {minutes} min left": "Alertes en sourdine · encore {minutes} min",
+        "Resume Alerts": "Réactiver les alertes",
+        "Outside scheduled hours": "En dehors des horaires prévus",
+        "Detect Anyway for an Hour": "Détecter quand même pendant une heure",
+        "Profile": "Profil",
+        "Open Dashboard": "Ouvrir le tableau de bord",
+        "Hide Dock Icon": "Masquer l'icône du Dock",
+        "Quit": "Quitter",
+        "Mindful Touch (alerts snoozed, {minutes} min left)": "Mindful Touch (alertes en sourdine, encore {minutes} min)",
+        "Mindful Touch — quiet while Do Not Disturb is on": "Mindful Touch — silencieux tant que Ne pas déranger est activé",
+        "Switched to {name}": "Passé à {name}",
+        "Alerts resumed": "Alertes réactivées",
+        "Detection resumed": "Détection reprise",
+        "Detection paused": "Détection en pause",
+        "Detection started": "Détection démarrée",
+        "Detection could not start": "La détection n'a pas pu démarrer",
+        "While Do Not Disturb was on": "Pendant que Ne pas déranger était activé",
+        "1 touch noticed ({regions})": "1 contact remarqué ({regions})",
+        "{count} touches noticed ({regions})": "{count} contacts remarqués ({regions})",
+        "On battery, detecting at a lower frame rate": "Sur batterie, détection à une fréquence d'images réduite",
+        "Plugged in, detecting at full speed": "Sur secteur, détection à pleine vitesse",
+        "Battery at {percent}%, detection suspended until you plug in": "Batterie à {percent} %, détection suspendue jusqu'au branchement",
+        "Mindful Touch is still running": "Mindful Touch est toujours actif",
+        "Detection continues in the background. Quit from the tray icon.": "La détection continue en arrière-plan. Quittez depuis l'icône de la barre des menus.",
+        "Goal reached": "Objectif atteint",
+        "Fewer than {count} touches today": "Moins de {count} contacts aujourd'hui",
+        "At least one {minutes}-minute streak": "Au moins une série de {minutes} minutes",
+        "{percent}% fewer touches than your baseline": "{percent} % de contacts en moins que votre période de référence",
+        "Achievement unlocked": "Succès débloqué",
+        "Hour of calm": "Une heure de calme",
+        "An hour without touching your face": "Une heure sans toucher votre visage",
+        "Week of awareness": "Une semaine d'attention",
+        "Mindful Touch ran on seven days in a row": "Mindful Touch a fonctionné sept jours d'affilée",
+        "Half as often": "Deux fois moins",
+        "Half as many touches in a week as in the week before": "Deux fois moins de contacts en une semaine que la semaine précédente",
+        "About Mindful Touch": "À propos de Mindful Touch",
+        "Restart Detection": "Redémarrer la détection",
+        "Suspend Detection": "Suspendre la détection",
+        "Show Status Overlay": "Afficher l'indicateur d'état",
+        "Show Streak Widget": "Afficher le widget de série",
+        "Show Detector View": "Afficher la vue du détecteur",
+        "Flash Screen Edges on Alert": "Faire clignoter les bords de l'écran lors des alertes",
+        "Show Today's Count on Icon": "Afficher le total du jour sur l'icône",
+        "Note the App in Use with Each Touch": "Noter l'app utilisée à chaque contact",
+        "Run at Low Priority": "Exécuter en priorité basse",
+        "Keep Running When Closed": "Continuer après la fermeture",
+        "Open at Login": "Ouvrir à l'ouverture de session",
+        "Start in Background at Login": "Démarrer en arrière-plan à l'ouverture de session",
+        "Camera": "Caméra",
+        "Camera {index} (not connected)": "Caméra {index} (non connectée)",
+        "Custom Regions": "Zones personnalisées",
+        "Add {name}": "Ajouter {name}",
+        "Remove {name}": "Supprimer {name}",
+        "Alert Sound": "Son d'alerte",
+        "System Alert": "Son du système",
+        "Choose File…": "Choisir un fichier…",
+        "Custom: {name}": "Personnalisé : {name}",
+        "Output Device": "Périphérique de sortie",
+        "System Default": "Par défaut du système",
+        "Choose Alert Sound": "Choisir le son d'alerte",
+        "Export Settings…": "Exporter les réglages…",
+        "Import Settings…": "Importer les réglages…",
+        "Export History…": "Exporter l'historique…",
+        "Weekly Report…": "Rapport hebdomadaire…",
+        "Back Up Data…": "Sauvegarder les données…",
+        "Restore Data…": "Restaurer les données…",
+        "Export for Apple Health / Google Fit…": "Exporter pour Apple Santé / Google Fit…",
+        "Reset to Defaults…": "Rétablir les réglages par défaut…",
+        "Erase All Data…": "Effacer toutes les données…",
+        "Restore Backup": "Restaurer une sauvegarde",
+        "No Backups": "Aucune sauvegarde",
+        "Show Logs": "Afficher les journaux",
+        "Reset to Defaults": "Rétablir les réglages par défaut",
+        "Go back to the default settings? The current ones are backed up first.": "Revenir aux réglages par défaut ? Les réglages actuels sont d'abord sauvegardés.",
+        "The current settings couldn't be backed up, so nothing was reset.": "Les réglages actuels n'ont pas pu être sauvegardés, rien n'a donc été rétabli.",
+        "Export Settings": "Exporter les réglages",
+        "Include your alert history?": "Inclure l'historique des alertes ?",
+        "The settings could not be saved there.": "Les réglages n'ont pas pu être enregistrés à cet endroit.",
+        "Export History": "Exporter l'historique",
+        "Leave out when each touch happened? Times become seconds after the first one.": "Omettre le moment de chaque contact ? Les heures deviennent des secondes après le premier.",
+        "The history could not be saved there.": "L'historique n'a pas pu être enregistré à cet endroit.",
+        "Export for Health": "Exporter pour Santé",
+        "Export your completed breathing exercises and daily touch counts for Apple Health or Google Fit? They leave Mindful Touch in the file you save.": (
+            "Exporter vos exercices de respiration terminés et vos contacts quotidiens pour Apple Santé ou Google Fit ? Ils quittent Mindful Touch dans le fichier que vous enregistrez."
+        ),
+        "Back Up Data": "Sauvegarder les données",
+        "The backup could not be saved there.": "La sauvegarde n'a pas pu être enregistrée à cet endroit.",
+        "Restore Data": "Restaurer les données",
+        "Replace your history and settings with the backup's?": "Remplacer votre historique et vos réglages par ceux de la sauvegarde ?",
+        "That file isn't a backup this version can restore, or it is damaged.": "Ce fichier n'est pas une sauvegarde que cette version peut restaurer, ou il est endommagé.",
+        "Erase All Data": "Effacer toutes les données",
+        "Erase your touch history, logs, settings and backups from this computer, and quit? This can't be undone.": "Effacer de cet ordinateur votre historique de contacts, les journaux, les réglages et les sauvegardes, puis quitter ? C'est irréversible.",
+        "Import Settings": "Importer des réglages",
+        "That file isn't a settings file this version can read.": "Ce fichier n'est pas un fichier de réglages lisible par cette version.",
+        "Mindful Touch is already running": "Mindful Touch est déjà ouvert",
+        "An earlier Mindful Touch (process {pid}) is still running and may be holding the camera.\n\nQuit it now?": (
+            "Une instance précédente de Mindful Touch (processus {pid}) tourne encore et utilise peut-être la caméra.\n\nLa quitter maintenant ?"
+        ),
+        "Could not start detection": "Impossible de démarrer la détection",
+        "Detection stopped": "Détection arrêtée",
+        "Detection stopped and could not be restarted automatically.": "La détection s'est arrêtée et n'a pas pu redémarrer automatiquement.",
+        "Check that no other app is using the camera, then start detection again.": "Vérifiez qu'aucune autre app n'utilise la caméra, puis relancez la détection.",
+        "Detection crashed ({error}) and is restarting. The crash report is in {path}": (
+            "La détection a planté ({error}) et redémarre. Le rapport de plantage se trouve dans {path}"
+        ),
+        "The detection backend sent a message this version can't read.": "Le moteur de détection a envoyé un message que cette version ne sait pas lire.",
+        "Reinstalling Mindful Touch usually fixes this.": "Réinstaller Mindful Touch règle généralement le problème.",
+        "Camera access needed": "Accès à la caméra requis",
+        "Mindful Touch isn't allowed to use the camera.": "Mindful Touch n'est pas autorisé à utiliser la caméra.",
+        "Turn on Mindful Touch under Privacy & Security → Camera, then start detection again.": (
+            "Activez Mindful Touch dans Confidentialité et sécurité → Caméra, puis relancez la détection."
+        ),
+        "Open System Settings": "Ouvrir les Réglages Système",
+        "Quit Mindful Touch?": "Quitter Mindful Touch ?",
+        "Quitting ends the current session.": "Quitter met fin à la session en cours.",
+        "Session length: {duration}\nTouches: {touches}\nLongest streak without touching: {streak}": (
+            "Durée de la session : {duration}\nContacts : {touches}\nPlus longue période sans contact : {streak}"
+        ),
+        # Weekly report
+        "Weekly Report": "Rapport hebdomadaire",
+        "The report could not be saved there.": "Le rapport n'a pas pu être enregistré à cet endroit.",
+        "Mindful Touch weekly report": "Rapport hebdomadaire Mindful Touch",
+        "Week of {start} to {end}": "Semaine du {start} au {end}",
+        "Touches": "Contacts",
+        "Time touching": "Durée des contacts",
+        "Alerts": "Alertes",
+        "{change} on the week before": "{change} par rapport à la semaine précédente",
+        "Touches per day": "Contacts par jour",
+        "Regions": "Zones",
+        "Region": "Zone",
+        "Week before": "Semaine précédente",
+        "No touches recorded": "Aucun contact enregistré",
+        "Streaks": "Séries",
+        "Longest clean streak": "Plus longue série sans contact",
+        "Busiest hours": "Heures les plus chargées",
+        "Work sessions": "Sessions de travail",
+        "Notes": "Notes",
+        "Generated {time}": "Généré le {time}",
+    },
+    "el": {
+        "{duration} hands-free": "{duration} χωρίς άγγιγμα",
+        "Pause Detection": "Παύση ανίχνευσης",
+        "Resume": "Συνέχεια",
+        "Pause": "Παύση",
+        "Not detecting": "Χωρίς ανίχνευση",
+        "Detector view": "Προβολή ανιχνευτή",
+        "Start detection to see what the detector sees.": "Ξεκινήστε την ανίχνευση για να δείτε τι βλέπει ο ανιχνευτής.",
+        "Detection is suspended.": "Η ανίχνευση έχει ανασταλεί.",
+        "The camera feed is hidden.": "Η εικόνα της κάμερας είναι κρυφή.",
+        "Waiting for the camera…": "Αναμονή για την κάμερα…",
+        "Snooze Alerts": "Σίγαση ειδοποιήσεων",
+        "For {minutes} Minutes": "Για {minutes} λεπτά",
+        "Alerts snoozed · {minutes} min left": "Ειδοποιήσεις σε σίγαση · απομένουν {minutes} λεπ.",
+        "Resume Alerts": "Επαναφορά ειδοποιήσεων",
+        "Outside scheduled hours": "Εκτός προγραμματισμένων ωρών",
+        "Detect Anyway for an Hour": "Ανίχνευση για μία ώρα ούτως ή άλλως",
+        "Profile": "Προφίλ",
+        "Open Dashboard": "Άνοιγμα πίνακα",
+        "Hide Dock Icon": "Απόκρυψη εικονιδίου στο Dock",
+        "Quit": "Έξοδος",
+        "Mindful Touch (alerts snoozed, {minutes} min left)": "Mindful Touch (ειδοποιήσεις σε σίγαση, απομένουν {minutes} λεπ.)",
+        "Mindful Touch — quiet while Do Not Disturb is on": "Mindful Touch — σε σιγή όσο είναι ενεργό το «Μην ενοχλείτε»",
+        "Switched to {name}": "Αλλαγή σε {name}",
+        "Alerts resumed": "Οι ειδοποιήσεις επανήλθαν",
+        "Detection resumed": "Η ανίχνευση συνεχίζεται",
+        "Detection paused": "Η ανίχνευση σε παύση",
+        "Detection started": "Η ανίχνευση ξεκίνησε",
+        "Detection could not start": "Η ανίχνευση δεν μπόρεσε να ξεκινήσει",
+        "While Do Not Disturb was on": "Όσο ήταν ενεργό το «Μην ενοχλείτε»",
+        "1 touch noticed ({regions})": "1 άγγιγμα ({regions})",
+        "{count} touches noticed ({regions})": "{count} αγγίγματα ({regions})",
+        "On battery, detecting at a lower frame rate": "Με μπαταρία, ανίχνευση με χαμηλότερο ρυθμό καρέ",
+        "Plugged in, detecting at full speed": "Στο ρεύμα, ανίχνευση σε πλήρη ταχύτητα",
+        "Battery at {percent}%, detection suspended until you plug in": "Μπαταρία στο {percent}%, η ανίχνευση σταματά μέχρι να συνδέσετε φορτιστή",
+        "Mindful Touch is still running": "Το Mindful Touch εξακολουθεί να τρέχει",
+        "Detection continues in the background. Quit from the tray icon.": "Η ανίχνευση συνεχίζεται στο παρασκήνιο. Έξοδος από το εικονίδιο της γραμμής.",
+        "Goal reached": "Ο στόχος επιτεύχθηκε",
+        "Fewer than {count} touches today": "Λιγότερα από {count} αγγίγματα σήμερα",
+        "At least one {minutes}-minute streak": "Τουλάχιστον ένα διάστημα {minutes} λεπτών χωρίς άγγιγμα",
+        "{percent}% fewer touches than your baseline": "{percent}% λιγότερα αγγίγματα από την περίοδο αναφοράς σας",
+        "Achievement unlocked": "Νέο επίτευγμα",
+        "Hour of calm": "Μια ώρα ηρεμίας",
+        "An hour without touching your face": "Μια ώρα χωρίς να αγγίξετε το πρόσωπό σας",
+        "Week of awareness": "Μια εβδομάδα επίγνωσης",
+        "Mindful Touch ran on seven days in a row": "Το Mindful Touch λειτούργησε επτά συνεχόμενες ημέρες",
+        "Half as often": "Στο μισό",
+        "Half as many touches in a week as in the week before": "Τα μισά αγγίγματα σε μια εβδομάδα σε σχέση με την προηγούμενη",
+        "About Mindful Touch": "Σχετικά με το Mindful Touch",
+        "Restart Detection": "Επανεκκίνηση ανίχνευσης",
+        "Suspend Detection": "Αναστολή ανίχνευσης",
+        "Show Status Overlay": "Εμφάνιση ένδειξης κατάστασης",
+        "Show Streak Widget": "Εμφάνιση μικροεφαρμογής διαστήματος",
+        "Show Detector View": "Εμφάνιση προβολής ανιχνευτή",
+        "Flash Screen Edges on Alert": "Αναλαμπή άκρων οθόνης στις ειδοποιήσεις",
+        "Show Today's Count on Icon": "Εμφάνιση σημερινού αριθμού στο εικονίδιο",
+        "Note the App in Use with Each Touch": "Καταγραφή της εφαρμογής σε χρήση σε κάθε άγγιγμα",
+        "Run at Low Priority": "Εκτέλεση με χαμηλή προτεραιότητα",
+        "Keep Running When Closed": "Συνέχιση λειτουργίας μετά το κλείσιμο",
+        "Open at Login": "Άνοιγμα κατά τη σύνδεση",
+        "Start in Background at Login": "Εκκίνηση στο παρασκήνιο κατά τη σύνδεση",
+        "Camera": "Κάμερα",
+        "Camera {index} (not connected)": "Κάμερα {index} (μη συνδεδεμένη)",
+        "Custom Regions": "Προσαρμοσμένες περιοχές",
+        "Add {name}": "Προσθήκη: {name}",
+        "Remove {name}": "Αφαίρεση: {name}",
+        "Alert Sound": "Ήχος ειδοποίησης",
+        "System Alert": "Ήχος συστήματος",
+        "Choose File…": "Επιλογή αρχείου…",
+        "Custom: {name}": "Προσαρμοσμένος: {name}",
+        "Output Device": "Συσκευή εξόδου",
+        "System Default": "Προεπιλογή συστήματος",
+        "Choose Alert Sound": "Επιλογή ήχου ειδοποίησης",
+        "Export Settings…": "Εξαγωγή ρυθμίσεων…",
+        "Import Settings…": "Εισαγωγή ρυθμίσεων…",
+        "Export History…": "Εξαγωγή ιστορικού…",
+        "Weekly Report…": "Εβδομαδιαία αναφορά…",
+        "Back Up Data…": "Αντίγραφο ασφαλείας δεδομένων…",
+        "Restore Data…": "Επαναφορά δεδομένων…",
+        "Export for Apple Health / Google Fit…": "Εξαγωγή για Apple Health / Google Fit…",
+        "Reset to Defaults…": "Επαναφορά προεπιλογών…",
+        "Erase All Data…": "Διαγραφή όλων των δεδομένων…",
+        "Restore Backup": "Επαναφορά αντιγράφου ασφαλείας",
+        "No Backups": "Κανένα αντίγραφο ασφαλείας",
+        "Show Logs": "Εμφάνιση αρχείων καταγραφής",
+        "Reset to Defaults": "Επαναφορά προεπιλογών",
+        "Go back to the default settings? The current ones are backed up first.": "Επιστροφή στις προεπιλεγμένες ρυθμίσεις; Οι τρέχουσες αποθηκεύονται πρώτα σε αντίγραφο.",
+        "The current settings couldn't be backed up, so nothing was reset.": "Δεν ήταν δυνατή η δημιουργία αντιγράφου των τρεχουσών ρυθμίσεων, οπότε δεν έγινε επαναφορά.",
+        "Export Settings": "Εξαγωγή ρυθμίσεων",
+        "Include your alert history?": "Να συμπεριληφθεί το ιστορικό ειδοποιήσεων;",
+        "The settings could not be saved there.": "Δεν ήταν δυνατή η αποθήκευση των ρυθμίσεων εκεί.",
+        "Export History": "Εξαγωγή ιστορικού",
+        "Leave out when each touch happened? Times become seconds after the first one.": "Να παραλειφθεί πότε έγινε κάθε άγγιγμα; Οι χρόνοι γίνονται δευτερόλεπτα μετά το πρώτο.",
+        "The history could not be saved there.": "Δεν ήταν δυνατή η αποθήκευση του ιστορικού εκεί.",
+        "Export for Health": "Εξαγωγή για Υγεία",
+        "Export your completed breathing exercises and daily touch counts for Apple Health or Google Fit? They leave Mindful Touch in the file you save.": (
+            "Εξαγωγή των ολοκληρωμένων ασκήσεων αναπνοής και των ημερήσιων αγγιγμάτων για το Apple Health ή το Google Fit; Φεύγουν από το Mindful Touch στο αρχείο που θα αποθηκεύσετε."
+        ),
+        "Back Up Data": "Αντίγραφο ασφαλείας",
+        "The backup could not be saved there.": "Το αντίγραφο ασφαλείας δεν ήταν δυνατό να αποθηκευτεί εκεί.",
+        "Restore Data": "Επαναφορά δεδομένων",
+        "Replace your history and settings with the backup's?": "Αντικατάσταση του ιστορικού και των ρυθμίσεων με αυτά του αντιγράφου;",
+        "That file isn't a backup this version can restore, or it is damaged.": "Αυτό το αρχείο δεν είναι αντίγραφο που μπορεί να επαναφέρει αυτή η έκδοση ή είναι κατεστραμμένο.",
+        "Erase All Data": "Διαγραφή όλων των δεδομένων",
+        "Erase your touch history, logs, settings and backups from this computer, and quit? This can't be undone.": "Διαγραφή του ιστορικού αγγιγμάτων, των αρχείων καταγραφής, των ρυθμίσεων και των αντιγράφων ασφαλείας από αυτόν τον υπολογιστή και έξοδος; Δεν αναιρείται.",
+        "Import Settings": "Εισαγωγή ρυθμίσεων",
+        "That file isn't a settings file this version can read.": "Αυτό το αρχείο δεν είναι αρχείο ρυθμίσεων που μπορεί να διαβάσει αυτή η έκδοση.",
+        "Mindful Touch is already running": "Το Mindful Touch τρέχει ήδη",
+        "An earlier Mindful Touch (process {pid}) is still running and may be holding the camera.\n\nQuit it now?": (
+            "Ένα προηγούμενο Mindful Touch (διεργασία {pid}) τρέχει ακόμα και ίσως κρατά την κάμερα.\n\nΝα τερματιστεί τώρα;"
+        ),
+        "Could not start detection": "Η ανίχνευση δεν μπόρεσε να ξεκινήσει",
+        "Detection stopped": "Η ανίχνευση σταμάτησε",
+        "Detection stopped and could not be restarted automatically.": "Η ανίχνευση σταμάτησε και δεν ήταν δυνατή η αυτόματη επανεκκίνηση.",
+        "Check that no other app is using the camera, then start detection again.": "Βεβαιωθείτε ότι καμία άλλη εφαρμογή δεν χρησιμοποιεί την κάμερα και ξεκινήστε ξανά την ανίχνευση.",
+        "Detection crashed ({error}) and is restarting. The crash report is in {path}": (
+            "Η ανίχνευση κατέρρευσε ({error}) και επανεκκινείται. Η αναφορά σφάλματος βρίσκεται στο {path}"
+        ),
+        "The detection backend sent a message this version can't read.": "Η ανίχνευση έστειλε ένα μήνυμα που αυτή η έκδοση δεν μπορεί να διαβάσει.",
+        "Reinstalling Mindful Touch usually fixes this.": "Η επανεγκατάσταση του Mindful Touch συνήθως το διορθώνει.",
+        "Camera access needed": "Απαιτείται πρόσβαση στην κάμερα",
+        "Mindful Touch isn't allowed to use the camera.": "Το Mindful Touch δεν επιτρέπεται να χρησιμοποιεί την κάμερα.",
+        "Turn on Mindful Touch under Privacy & Security → Camera, then start detection again.": (
+            "Ενεργοποιήστε το Mindful Touch στο Απόρρητο και ασφάλεια → Κάμερα και ξεκινήστε ξανά την ανίχνευση."
+        ),
+        "Open System Settings": "Άνοιγμα Ρυθμίσεων συστήματος",
+        "Quit Mindful Touch?": "Έξοδος από το Mindful Touch;",
+        "Quitting ends the current session.": "Η έξοδος τερματίζει την τρέχουσα συνεδρία.",
+        "Session length: {duration}\nTouches: {touches}\nLongest streak without touching: {streak}": (
+            "Διάρκεια συνεδρίας: {duration}\nΑγγίγματα: {touches}\nΜεγαλύτερο διάστημα χωρίς άγγιγμα: {streak}"
+        ),
+        # Weekly report
+        "Weekly Report": "Εβδομαδιαία αναφορά",
+        "The report could not be saved there.": "Η αναφορά δεν ήταν δυνατό να αποθηκευτεί εκεί.",
+        "Mindful Touch weekly report": "Εβδομαδιαία αναφορά Mindful Touch",
+        "Week of {start} to {end}": "Εβδομάδα {start} έως {end}",
+        "Touches": "Αγγίγματα",
+        "Time touching": "Χρόνος αγγίγματος",
+        "Alerts": "Ειδοποιήσεις",
+        "{change} on the week before": "{change} σε σχέση με την προηγούμενη εβδομάδα",
+        "Touches per day": "Αγγίγματα ανά ημέρα",
+        "Regions": "Περιοχές",
+        "Region": "Περιοχή",
+        "Week before": "Προηγούμενη εβδομάδα",
+        "No touches recorded": "Δεν καταγράφηκαν αγγίγματα",
+        "Streaks": "Διαστήματα",
+        "Longest clean streak": "Μεγαλύτερο διάστημα χωρίς άγγιγμα",
+        "Busiest hours": "Ώρες με τα περισσότερα αγγίγματα",
+        "Work sessions": "Συνεδρίες εργασίας",
+        "Notes": "Σημειώσεις",
+        "Generated {time}": "Δημιουργήθηκε {time}",
+    },
+}
+
+_language = FALLBACK_LANGUAGE
+
+
+def set_locale(language: str) -> str:
+    """Translate into this language from now on ("" or one we don't have: the system's); returns the one in use"""
+    global _language
+    _language = language if language in ALERT_LANGUAGES else system_language()
+    return _language
+
+
+def locale() -> str:
+    return _language
+
+
+def tr(text: str, **values) -> str:
+    """The text in the current language, with placeholders filled in"""
+    translated = CATALOGS.get(_language, {}).get(text, text)
+    return translated.format(**values) if values else translated
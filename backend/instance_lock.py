@@ -0,0 +1,100 @@
+"""
+Instance lockfile for Mindful Touch
+Records our PID so a leftover process from a crashed or hung run can be found at launch
+"""
+
+import json
+import os
+import signal
+import subprocess
+import sys
+from pathlib import Path
+from typing import Optional
+
+LOCK_PATH = Path.home() / ".mindful-touch" / "app.pid"
+
+
+def _command_line(pid: int) -> Optional[str]:
+    """Command line of a running process, or None if it doesn't exist (Unix only)"""
+    try:
+        result = subprocess.run(["ps", "-p", str(pid), "-o", "command="], capture_output=True, text=True, timeout=2)
+    except Exception:
+        return None
+    return result.stdout.strip() or None
+
+
+def _windows_pid_alive(pid: int) -> bool:
+    import ctypes
+
+    PROCESS_QUERY_LIMITED_INFORMATION = 0x1000
+    STILL_ACTIVE = 259
+    kernel32 = ctypes.windll.kernel32
+    handle = kernel32.OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, False, pid)
+    if not handle:
+        return False
+    exit_code = ctypes.c_ulong()
+    kernel32.GetExitCodeProcess(handle, ctypes.byref(exit_code))
+    kernel32.CloseHandle(handle)
+    return exit_code.value == STILL_ACTIVE
+
+
+def _is_live_instance(lock: dict) -> bool:
+    """True if the PID in the lock is alive and still looks like Mindful Touch"""
+    pid = lock.get("pid")
+    if not isinstance(pid, int) or pid == os.getpid():
+        return False
+    if sys.platform == "win32":
+        return _windows_pid_alive(pid)
+
+    # Guard against PID reuse: the live process must run the same command
+    command = _command_line(pid)
+    return command is not None and command == lock.get("command")
+
+
+def _own_command() -> str:
+    if sys.platform == "win32":
+        return sys.executable
+    return _command_line(os.getpid()) or ""
+
+
+def acquire() -> Optional[int]:
+    """Take the lock; returns the PID of a still-running previous instance, if any
+
+    A lock left behind by a process that is gone is simply replaced.
+    """
+    previous = None
+    try:
+        if LOCK_PATH.exists():
+            lock = json.loads(LOCK_PATH.read_text())
+            if _is_live_instance(lock):
+                previous = lock["pid"]
+            else:
+                print(f"Removing stale instance lock (pid {lock.get('pid')})")
+    except Exception as e:
+        print(f"Could not read instance lock: {e}")
+
+    try:
+        LOCK_PATH.parent.mkdir(parents=True, exist_ok=True)
+        LOCK_PATH.write_text(json.dumps({"pid": os.getpid(), "command": _own_command()}))
+    except Exception as e:
+        print(f"Could not write instance lock: {e}")
+    return previous
+
+
+def release():
+    """Remove the lock if it is still ours"""
+    try:
+        if LOCK_PATH.exists() and json.loads(LOCK_PATH.read_text()).get("pid") == os.getpid():
+            LOCK_PATH.unlink()
+    except Exception as e:
+        print(f"Could not remove instance lock: {e}")
+
+
+def terminate(pid: int) -> bool:
+    """Ask a leftover instance to exit so it lets go of the camera"""
+    try:
+        os.kill(pid, signal.SIGTERM)
+        return True
+    except Exception as e:
+        print(f"Could not terminate process {pid}: {e}")
+        return False
@@ -0,0 +1,148 @@
+"""
+Log capture for Mindful Touch
+Tees stdout/stderr into a ring buffer for the live log console and a rotating log file
+"""
+
+import sys
+import threading
+import time
+from collections import deque
+from pathlib import Path
+
+MAX_LINES = 500
+
+LOG_PATH = Path.home() / ".mindful-touch" / "logs" / "backend.log"
+MAX_LOG_BYTES = 1024 * 1024  # Rotate once the file passes 1 MB
+LOG_BACKUPS = 3  # backend.log.1 … backend.log.3
+
+_lines = deque(maxlen=MAX_LINES)
+_listeners = []
+_lock = threading.Lock()
+_log_file = None
+_writing = True  # Off once stop_writing() is called
+
+
+class _TeeStream:
+    """Write-through stream wrapper that records every complete line"""
+
+    def __init__(self, stream, source):
+        self.stream = stream
+        self.source = source
+        self._partial = ""
+
+    def write(self, text):
+        # Windowed PyInstaller builds may have no real stdout/stderr
+        if self.stream is not None:
+            self.stream.write(text)
+
+        with _lock:
+            self._partial += text
+            *complete, self._partial = self._partial.split("\n")
+        for line in complete:
+            _record(self.source, line)
+        return len(text)
+
+    def flush(self):
+        if self.stream is not None:
+            self.stream.flush()
+
+    def isatty(self):
+        return self.stream is not None and self.stream.isatty()
+
+    def __getattr__(self, name):
+        # encoding, fileno, ... come from the wrapped stream
+        return getattr(self.stream, name)
+
+
+def _rotate():
+    """Shift backend.log -> backend.log.1 -> … and drop the oldest"""
+    global _log_file
+    if _log_file is not None:
+        _log_file.close()
+        _log_file = None
+    for index in range(LOG_BACKUPS, 0, -1):
+        older = LOG_PATH.with_name(f"{LOG_PATH.name}.{index}")
+        newer = LOG_PATH.with_name(f"{LOG_PATH.name}.{index - 1}") if index > 1 else LOG_PATH
+        if newer.exists():
+            newer.replace(older)
+
+
+def _write_to_file(entry):
+    """Append one line to the log file; called with _lock held"""
+    global _log_file
+    if not _writing:
+        return
+    try:
+        if _log_file is None:
+            LOG_PATH.parent.mkdir(parents=True, exist_ok=True)
+            _log_file = open(LOG_PATH, "a", encoding="utf-8")
+        stamp = time.strftime("%Y-%m-%d %H:%M:%S", time.localtime(entry["time"]))
+        _log_file.write(f"{stamp} [{entry['source']}] {entry['line']}\n")
+        _log_file.flush()
+        if _log_file.tell() >= MAX_LOG_BYTES:
+            _rotate()
+    except Exception:
+        # Never let a full disk or permissions problem break the app; the ring buffer still works
+        _log_file = None
+
+
+def _record(source, line):
+    entry = {"time": time.time(), "source": source, "line": line}
+    with _lock:
+        _lines.append(entry)
+        _write_to_file(entry)
+        listeners = list(_listeners)
+    for listener in listeners:
+        try:
+            listener(entry)
+        except Exception:
+            pass  # A broken listener must never take logging down with it
+
+
+def install():
+    """Start capturing stdout/stderr (safe to call more than once)"""
+    if not isinstance(sys.stdout, _TeeStream):
+        sys.stdout = _TeeStream(sys.stdout, "stdout")
+    if not isinstance(sys.stderr, _TeeStream):
+        sys.stderr = _TeeStream(sys.stderr, "stderr")
+
+
+def stop_writing():
+    """Close the log file for good (before erasing it); the ring buffer and listeners keep going"""
+    global _log_file, _writing
+    with _lock:
+        _writing = False
+        if _log_file is not None:
+            _log_file.close()
+            _log_file = None
+
+
+def get_log_path() -> Path:
+    """Where captured output is written (attach this to bug reports)"""
+    return LOG_PATH
+
+
+def get_backend_logs(limit=None) -> list:
+    """Return the most recent captured lines, oldest first"""
+    with _lock:
+        lines = list(_lines)
+    return lines[-limit:] if limit else lines
+
+
+def recent_stderr(limit=50) -> list:
+    """Text of the last stderr lines, oldest first"""
+    with _lock:
+        lines = [entry["line"] for entry in _lines if entry["source"] == "stderr"]
+    return lines[-limit:]
+
+
+def add_listener(callback):
+    """Call callback(entry) for every new line; may be called from any thread"""
+    with _lock:
+        _listeners.append(callback)
+
+
+def remove_listener(callback):
+    with _lock:
+        if callback in _listeners:
+            _listeners.remove(callback)
@@ -0,0 +1,1161 @@
+"""
+Detection backend manager for Mindful Touch
+Owns the camera thread, its supervisor and the options it runs with
+"""
+
+import os
+import queue
+import sys
+import threading
+import time
+import traceback
+from collections import deque
+from dataclasses import replace
+from typing import Dict, List, Optional
+
+import cv2
+import numpy as np
+from PyQt6.QtCore import QObject, QThread, QTimer, pyqtSignal
+
+from backend import logs
+from backend.channels import BoundedChannel, is_critical_detection
+from backend.detection.config import Config
+from backend.detection.multi_region_detector import MultiRegionDetector
+from backend.detection.options import BackendOptions
+from backend.metrics import ResourceMonitor
+from backend.protocol import (
+    ApplyOptions,
+    Command,
+    CommandResult,
+    GetStats,
+    Pause,
+    ProtocolError,
+    Resume,
+    SetAlertDelay,
+    SetFrameRate,
+    SetPriority,
+    SetRegions,
+    SetSensitivity,
+    StartPreview,
+    StopPreview,
+    Suspend,
+    Wake,
+    validate_detection,
+)
+from backend.startup_errors import StartupError, camera_open_error, from_exception
+
+# BackendOptions a running camera can take on without a restart, in the order they're applied
+# (custom regions before the regions watched, which may name them; max_fps is the battery saver's, not a setting)
+LIVE_OPTIONS = ["custom_regions", "enabled_regions", "alert_delay", "sensitivity", "low_priority", "log_level", "idle_shutdown_minutes"]
+
+
+class CameraThread(QThread):
+    """Thread for camera capture and detection"""
+
+    frames_waiting = pyqtSignal()  # The frames channel has something to drain
+    detections_waiting = pyqtSignal()  # The detections channel has something to drain
+    stopped_unexpectedly = pyqtSignal(str)
+    ready = pyqtSignal()
+    startup_failed = pyqtSignal(object)  # StartupError
+    command_result = pyqtSignal(object)  # CommandResult for every protocol Command applied
+
+    def __init__(self):
+        super().__init__()
+        self.running = False
+        self.detector = None
+        self.cap = None
+        self.is_stopping = False
+        self.started_at = None
+        self.last_heartbeat = None  # Time the last frame finished processing
+        self.frames_processed = 0
+        self.options = BackendOptions()
+        self.paused = False  # Camera stays open, frames are skipped
+        self.suspended = False  # Camera released, thread blocked on the command queue
+        self.last_error_type = None  # Exception class name of the last crash
+
+        # Preview frames for the UI; kept across restarts of this thread
+        self.preview_enabled = True
+        self.preview_fps = Config.PREVIEW_MAX_FPS
+        self.last_preview_time = 0.0
+
+        # Commands from the UI thread, applied between frames (see BackendManager.send_backend_command)
+        self.commands = queue.Queue()
+
+        # Output to the UI thread. Bounded, so a busy UI sees fewer frames instead of a growing backlog;
+        # the reader drains them when nudged by frames_waiting / detections_waiting
+        self.frames = BoundedChannel(Config.FRAME_CHANNEL_SIZE)
+        self.detections = BoundedChannel(Config.DETECTION_CHANNEL_SIZE, is_critical=is_critical_detection)
+
+        # A stop asked for while the model loads finishes when the thread exits, not by blocking the UI
+        self.stop_timer = QTimer()
+        self.stop_timer.setSingleShot(True)
+        self.stop_timer.timeout.connect(self._on_stop_timeout)
+        self.finished.connect(self._on_finished)
+        self.pending_start = None  # Options of a start asked for meanwhile, run once it has stopped
+
+    def start_detection(self, options=None):
+        """Start the thread; it loads the model and camera itself and emits ready (or startup_failed)
+
+        Without options the previous ones are reused, which is what restarts want.
+        """
+        if self.stop_timer.isActive():
+            self.pending_start = options or self.options
+            print("Previous run still loading the model, starting once it has stopped")
+            return True
+
+        # Prevent starting if already running or stopping
+        if self.running or self.is_stopping or self.isRunning():
+            print("Detection already running or stopping, ignoring start request")
+            return True  # Return True to not show error state in UI
+
+        try:
+            # Clean up any existing resources first
+            self._cleanup_resources()
+
+            if options is not None:
+                self.options = options
+
+            # Commands and output meant for a previous run don't carry over
+            self.commands = queue.Queue()
+            self.frames.drain()
+            self.detections.drain()
+            self.paused = False
+            self.suspended = False
+
+            # Set state and start thread
+            self.running = True
+            self.is_stopping = False
+            self.started_at = time.time()
+            self.last_heartbeat = None
+            self.last_error_type = None
+            self.start()
+            return True
+
+        except Exception as e:
+            print(f"Error starting detection: {e}")
+            self.running = False
+            self._cleanup_resources()
+            return False
+
+    def stop_detection(self, timeout=None, wait=False):
+        """Stop detection, escalating only if the thread ignores the stop request
+
+        While the model is still loading the thread is left to finish it and stops when it exits,
+        escalating after STARTUP_TIMEOUT, so the caller isn't blocked; wait blocks anyway (at quit).
+        """
+        if self.is_stopping:
+            self.pending_start = None
+            if wait and self.stop_timer.isActive():
+                self.stop_timer.stop()
+                self._wait_for_stop(Config.STARTUP_TIMEOUT)
+            else:
+                print("Already stopping, ignoring stop request")
+            return
+
+        # Prevent double stopping
+        if not self.running and not self.isRunning():
+            print("Detection not running, ignoring stop request")
+            return
+
+        if timeout is None:
+            timeout = Config.SHUTDOWN_TIMEOUT
+        if self.last_heartbeat is None:
+            # Still loading the model; killing MediaPipe mid-initialization is worse than waiting
+            timeout = max(timeout, Config.STARTUP_TIMEOUT)
+
+        try:
+            print("Stopping detection...")
+            self.is_stopping = True
+
+            # Ask the loop to finish the frame in flight and exit on its own
+            self.running = False
+            self.commands.put(Wake())  # Unblocks a suspended thread
+
+            if self.last_heartbeat is None and self.isRunning() and not wait:
+                print("Model still loading, detection stops once it has loaded")
+                self.stop_timer.start(int(timeout * 1000))
+                return
+            self._wait_for_stop(timeout)
+
+        except Exception as e:
+            print(f"Error stopping detection: {e}")
+            self.is_stopping = False
+
+    def _wait_for_stop(self, timeout: float):
+        if self.isRunning() and not self.wait(int(timeout * 1000)):
+            print(f"Thread did not stop within {timeout:.1f}s, releasing the camera")
+            self._force_stop()
+        self._finish_stop()
+
+    def _force_stop(self):
+        # Almost always stuck in a blocking camera read; releasing the device unblocks it
+        if self.cap:
+            self.cap.release()
+
+        if not self.wait(1000):
+            # Last resort: terminate() can leave MediaPipe in a bad state
+            print("Warning: Thread still running, forcing termination")
+            self.terminate()
+            self.wait(1000)
+
+    def _on_finished(self):
+        if self.stop_timer.isActive():
+            self.stop_timer.stop()
+            self.wait(1000)  # finished comes just before run() returns
+            self._finish_stop()
+
+    def _on_stop_timeout(self):
+        if self.is_stopping:
+            print(f"Thread did not stop within {Config.STARTUP_TIMEOUT:.1f}s of loading, releasing the camera")
+            self._force_stop()
+            self._finish_stop()
+
+    def _finish_stop(self):
+        self._cleanup_resources()
+        self.is_stopping = False
+        self.started_at = None
+        self.last_heartbeat = None
+        print("Detection stopped successfully")
+        if self.pending_start is not None:
+            options, self.pending_start = self.pending_start, None
+            self.start_detection(options)
+
+    def _cleanup_resources(self):
+        """Clean up camera and detector resources"""
+        try:
+            if self.cap:
+                self.cap.release()
+                self.cap = None
+
+            if self.detector:
+                self.detector.cleanup()
+                self.detector = None
+
+        except Exception as e:
+            print(f"Error during cleanup: {e}")
+
+    def _open_resources(self):
+        """Load MediaPipe and open the camera — slow, so it runs on this thread"""
+        Config.update_contact_duration(self.options.alert_delay)
+        Config.set_custom_regions(self.options.custom_regions)
+        Config.ACTIVE_REGIONS = list(self.options.enabled_regions)
+
+        try:
+            self.detector = MultiRegionDetector(sensitivity=self.options.sensitivity)
+        except Exception as e:
+            raise StartupError(StartupError.MODEL_MISSING, f"Could not load the detection model: {e}") from e
+
+        self.cap = cv2.VideoCapture(self.options.camera_index)
+        if not self.cap.isOpened():
+            raise camera_open_error(self.options.camera_index)
+
+    def _process_commands(self):
+        """Apply everything queued since the last frame"""
+        while True:
+            try:
+                command = self.commands.get_nowait()
+            except queue.Empty:
+                return
+            self._handle_command(command)
+
+    def _handle_command(self, command):
+        """Apply one command and answer it with a CommandResult"""
+        try:
+            value = self._apply_command(command)
+            result = CommandResult(command.id, command.name, ok=True, value=value)
+        except Exception as e:
+            print(f"Could not apply backend command {command}: {e}")
+            result = CommandResult(getattr(command, "id", 0), type(command).__name__, ok=False, error=str(e))
+        if not isinstance(command, Wake):
+            self.command_result.emit(result)
+
+    def _apply_command(self, command: Command):
+        if isinstance(command, SetRegions):
+            Config.ACTIVE_REGIONS = [r for r in command.regions if r in Config.all_regions()]
+        elif isinstance(command, SetAlertDelay):
+            Config.update_contact_duration(float(command.value))
+        elif isinstance(command, SetSensitivity):
+            if self.detector:
+                self.detector.sensitivity = float(command.value)
+        elif isinstance(command, SetPriority):
+            self.options.low_priority = bool(command.low)
+            self._apply_priority()
+        elif isinstance(command, SetFrameRate):
+            self.options.max_fps = max(0.0, float(command.max_fps))
+        elif isinstance(command, ApplyOptions):
+            for name, value in command.changes.items():
+                self._apply_option(name, value)
+            return {"applied": sorted(command.changes)}
+        elif isinstance(command, StartPreview):
+            self.preview_enabled = True
+            self.preview_fps = max(1.0, float(command.max_fps))
+        elif isinstance(command, StopPreview):
+            self.preview_enabled = False
+        elif isinstance(command, Pause):
+            self.paused = True
+        elif isinstance(command, Suspend):
+            self.suspended = True
+        elif isinstance(command, Resume):
+            self.paused = False
+            self.suspended = False
+        elif isinstance(command, GetStats):
+            return {
+                "frames_processed": self.frames_processed,
+                "dropped": self.dropped_counts(),
+                "paused": self.paused,
+                "suspended": self.suspended,
+                "active_regions": list(Config.ACTIVE_REGIONS),
+            }
+        elif not isinstance(command, Wake):
+            raise ValueError(f"Unknown backend command: {command!r}")
+        return None
+
+    def _apply_option(self, name: str, value):
+        if name not in LIVE_OPTIONS:
+            raise ValueError(f"{name} can't change while running")
+        setattr(self.options, name, value)
+        if name == "custom_regions":
+            Config.set_custom_regions(value)
+        elif name == "enabled_regions":
+            Config.ACTIVE_REGIONS = [r for r in value if r in Config.all_regions()]
+        elif name == "alert_delay":
+            Config.update_contact_duration(float(value))
+        elif name == "sensitivity" and self.detector:
+            self.detector.sensitivity = float(value)
+        elif name == "low_priority":
+            self._apply_priority()
+
+    def _emit_preview(self, frame):
+        """Send a downscaled copy of the annotated frame, capped at preview_fps"""
+        now = time.monotonic()
+        if not self.preview_enabled or now - self.last_preview_time < 1.0 / self.preview_fps:
+            return
+        self.last_preview_time = now
+
+        height, width = frame.shape[:2]
+        if width > Config.PREVIEW_MAX_WIDTH:
+            scale = Config.PREVIEW_MAX_WIDTH / width
+            frame = cv2.resize(frame, (Config.PREVIEW_MAX_WIDTH, int(height * scale)), interpolation=cv2.INTER_AREA)
+        if self.frames.put(frame):
+            self.frames_waiting.emit()
+
+    def _emit_detection(self, data):
+        if self.detections.put(data):
+            self.detections_waiting.emit()
+
+    def dropped_counts(self) -> Dict[str, int]:
+        """Frames and detections discarded because the UI fell behind"""
+        return {"frames": self.frames.dropped, "detections": self.detections.dropped}
+
+    def _apply_priority(self):
+        """Lower (or restore) the scheduling priority of this thread; must run on it"""
+        low = self.options.low_priority
+        self.setPriority(QThread.Priority.LowPriority if low else QThread.Priority.NormalPriority)
+
+        # Linux ignores Qt thread priorities under the default scheduler, but honors a per-thread nice value
+        if sys.platform.startswith("linux"):
+            try:
+                os.setpriority(os.PRIO_PROCESS, threading.get_native_id(), Config.LOW_PRIORITY_NICE if low else 0)
+            except OSError as e:
+                # Raising priority back needs privileges; the thread just stays niced until restarted
+                print(f"Could not change camera thread priority: {e}")
+
+    def _wait_while_suspended(self):
+        """Let go of the camera and block until resumed or stopped — no frames, no CPU"""
+        print("Detection suspended, camera released")
+        if self.cap:
+            self.cap.release()
+            self.cap = None
+
+        while self.suspended and self.running:
+            self._handle_command(self.commands.get())
+
+        if self.running:
+            self.cap = cv2.VideoCapture(self.options.camera_index)
+            if not self.cap.isOpened():
+                raise camera_open_error(self.options.camera_index)
+            self.last_heartbeat = time.time()  # Don't count the suspension as a stall
+            print("Detection resumed")
+
+    def run(self):
+        if self.options.low_priority:
+            self._apply_priority()
+
+        try:
+            self._open_resources()
+        except Exception as e:
+            print(f"Error starting detection: {e}")
+            if self.running:
+                self.startup_failed.emit(from_exception(e))
+            return
+
+        failed_reads = 0
+        last_contact = []
+        try:
+            while self.running and self.cap and self.cap.isOpened():
+                self._process_commands()
+                if self.suspended:
+                    self._wait_while_suspended()
+                    continue
+                if self.paused:
+                    # Still alive and listening; just not looking
+                    self.last_heartbeat = time.time()
+                    self.msleep(100)
+                    continue
+
+                frame_started = time.monotonic()
+                ret, frame = self.cap.read()
+                if not ret:
+                    failed_reads += 1
+                    if failed_reads >= Config.MAX_FRAME_FAILURES:
+                        if self.last_heartbeat is None:
+                            # Opened but never delivered a frame: typically held by another app
+                            if self.running:
+                                self.startup_failed.emit(
+                                    StartupError(StartupError.CAMERA_BUSY, f"Camera {self.options.camera_index} opened but sent no frames")
+                                )
+                            return
+                        raise RuntimeError(f"Camera returned no frames {failed_reads} times in a row")
+                    self.msleep(50)
+                    continue
+                failed_reads = 0
+
+                if self.detector:
+                    annotated_frame, detection_data = self.detector.process_frame(frame)
+                    self._emit_preview(annotated_frame)
+                    self._emit_detection(detection_data)
+
+                    if self.options.log_level == "debug":
+                        contact = detection_data["regions_with_contact"]
+                        if contact != last_contact:
+                            print(f"[debug] Contact regions: {contact or 'none'}")
+                            last_contact = contact
+
+                # The first processed frame is the readiness signal
+                if self.last_heartbeat is None:
+                    self.ready.emit()
+                self.last_heartbeat = time.time()
+                self.frames_processed += 1
+
+                if self.options.max_fps > 0:
+                    # Throttled (e.g. on battery): idle away the rest of this frame's time slot
+                    spare = 1.0 / self.options.max_fps - (time.monotonic() - frame_started)
+                    if spare > 0:
+                        self.msleep(int(spare * 1000))
+
+            # Leaving the loop while still supposed to run means the camera went away
+            if self.running:
+                self.last_error_type = "CameraClosed"
+                self.stopped_unexpectedly.emit("Camera closed unexpectedly")
+
+        except Exception as e:
+            print(f"Camera thread crashed: {e}")
+            traceback.print_exc()
+            self.last_error_type = type(e).__name__
+            if self.running:
+                self.stopped_unexpectedly.emit(str(e))
+
+
+class BackendSupervisor(QObject):
+    """Restarts the camera thread with exponential backoff after unexpected exits"""
+
+    backend_restarted = pyqtSignal(int)
+    restart_failed = pyqtSignal(str)
+    status_changed = pyqtSignal(str)
+    backend_metrics = pyqtSignal(dict)
+
+    def __init__(self, camera_thread, parent=None):
+        super().__init__(parent)
+        self.camera_thread = camera_thread
+        self.restart_attempts = 0
+        self.last_start_time = 0
+        self.last_error = ""
+
+        self.restart_timer = QTimer(self)
+        self.restart_timer.setSingleShot(True)
+        self.restart_timer.timeout.connect(self._restart)
+
+        self.restarting = False  # True between a restart attempt and its ready/startup_failed
+        self.camera_thread.stopped_unexpectedly.connect(self._on_unexpected_exit)
+        self.camera_thread.ready.connect(self._on_ready)
+        self.camera_thread.startup_failed.connect(self._on_restart_failed)
+
+        # Periodic health check against the thread's heartbeat
+        self.state = "stopped"
+        self.health_timer = QTimer(self)
+        self.health_timer.timeout.connect(self._check_health)
+        self.health_timer.start(int(Config.HEARTBEAT_INTERVAL * 1000))
+
+        # Resource sampling, emitted as backend_metrics while the thread runs
+        self.resource_monitor = ResourceMonitor()
+        self.last_metrics = {}
+        self._last_metrics_sample = (time.monotonic(), 0)
+        self.metrics_timer = QTimer(self)
+        self.metrics_timer.timeout.connect(self._sample_metrics)
+        self.metrics_timer.start(int(Config.METRICS_INTERVAL * 1000))
+
+    def backend_status(self):
+        """Snapshot of the camera thread's health for the UI"""
+        thread = self.camera_thread
+        alive = thread.running and thread.isRunning()
+        now = time.time()
+
+        if not alive:
+            state = "stopped"
+        elif thread.suspended:
+            state = "suspended"
+        elif thread.paused:
+            state = "paused"
+        elif thread.last_heartbeat is None:
+            state = "starting"
+        elif now - thread.last_heartbeat > Config.HEARTBEAT_TIMEOUT:
+            state = "unresponsive"
+        else:
+            state = "healthy"
+
+        return {
+            "state": state,
+            "alive": alive,
+            "pid": os.getpid(),
+            "uptime": now - thread.started_at if alive and thread.started_at else 0,
+            "last_heartbeat": thread.last_heartbeat,
+            "restart_attempts": self.restart_attempts,
+            "dropped": thread.dropped_counts(),
+        }
+
+    def get_backend_metrics(self):
+        """Most recent CPU/memory/frame-rate sample"""
+        return dict(self.last_metrics)
+
+    def _sample_metrics(self):
+        now = time.monotonic()
+        frames = self.camera_thread.frames_processed
+        last_time, last_frames = self._last_metrics_sample
+        self._last_metrics_sample = (now, frames)
+
+        # Always sample so the CPU window stays aligned with the FPS window
+        metrics = self.resource_monitor.sample()
+        if not (self.camera_thread.running and self.camera_thread.isRunning()):
+            return
+
+        metrics["fps"] = round(max(0, frames - last_frames) / (now - last_time), 1) if now > last_time else 0.0
+        dropped = self.camera_thread.dropped_counts()
+        metrics["dropped_frames"] = dropped["frames"]
+        metrics["dropped_detections"] = dropped["detections"]
+        self.last_metrics = metrics
+        self.backend_metrics.emit(metrics)
+
+    def _check_health(self):
+        state = self.backend_status()["state"]
+        if state != self.state:
+            self.state = state
+            self.status_changed.emit(state)
+
+    def mark_started(self):
+        """Record a (user-initiated) start so uptime can reset the backoff"""
+        self.last_start_time = time.time()
+        self.restart_attempts = 0
+
+    def cancel(self):
+        """Drop any pending restart, e.g. when the user stops detection"""
+        self.restart_timer.stop()
+        self.restart_attempts = 0
+        self.restarting = False
+
+    def _on_unexpected_exit(self, reason):
+        """Schedule a restart after the camera thread died on its own"""
+        self.last_error = reason
+
+        # A thread that ran for a good while before failing starts a fresh backoff
+        if time.time() - self.last_start_time >= Config.RESTART_STABLE_TIME:
+            self.restart_attempts = 0
+
+        self._schedule_restart()
+
+    def _schedule_restart(self):
+        if self.restart_attempts >= Config.MAX_RESTART_ATTEMPTS:
+            print(f"Giving up after {self.restart_attempts} restart attempts: {self.last_error}")
+            self.restart_failed.emit(self.last_error)
+            return
+
+        delay = min(Config.RESTART_BASE_DELAY * (2**self.restart_attempts), Config.RESTART_MAX_DELAY)
+        self.restart_attempts += 1
+        print(f"Detection stopped unexpectedly ({self.last_error}), restarting in {delay:.0f}s (attempt {self.restart_attempts})")
+        self.restart_timer.start(int(delay * 1000))
+
+    def _restart(self):
+        """Tear down what is left of the dead thread and start a fresh one"""
+        self.camera_thread.stop_detection()
+        self.last_start_time = time.time()
+
+        self.restarting = True
+        if not self.camera_thread.start_detection():
+            self._on_restart_failed(StartupError(StartupError.UNKNOWN, "Could not restart the camera thread"))
+
+    def _on_ready(self):
+        if self.restarting:
+            self.restarting = False
+            print(f"Detection restarted (attempt {self.restart_attempts})")
+            self.backend_restarted.emit(self.restart_attempts)
+
+    def _on_restart_failed(self, reason):
+        if self.restarting:
+            self.restarting = False
+            self.last_error = str(reason)
+            self._schedule_restart()
+
+
+PRIMARY_INSTANCE = "main"
+
+# Event classes the UI can subscribe to, see BackendManager.subscribe_events()
+EVENT_TOPICS = ["detections", "preview", "metrics"]
+
+
+def crash_report(thread: CameraThread, reason: str) -> dict:
+    """What we know about a camera thread that died: error, uptime and recent stderr output"""
+    return {
+        "time": time.time(),
+        "reason": reason,
+        "error_type": thread.last_error_type or "Unknown",
+        "uptime": time.time() - thread.started_at if thread.started_at else None,
+        "frames_processed": thread.frames_processed,
+        "stderr": logs.recent_stderr(Config.CRASH_LOG_LINES),
+    }
+
+
+def merge_detections(latest: Dict[str, dict], source: str) -> dict:
+    """Combine the newest detection dict from every camera into one
+
+    Ongoing state (contact, active alerts) is OR-ed across cameras. One-shot
+    events (alert sounds, mindful stops) are taken only from the camera that just
+    produced a frame, so they aren't repeated with every other camera's frames.
+    """
+    fresh = latest[source]
+    region_details = {}
+    for data in latest.values():
+        for region, details in data.get("region_details", {}).items():
+            merged = region_details.setdefault(
+                region, {"contacts": [], "alert_active": False, "should_play_sound": False, "mindful_stop_detected": False, "contact_duration": 0}
+            )
+            merged["contacts"] = merged["contacts"] + list(details.get("contacts", []))
+            merged["alert_active"] = merged["alert_active"] or details.get("alert_active", False)
+            merged["contact_duration"] = max(merged["contact_duration"], details.get("contact_duration", 0))
+
+    for region, details in fresh.get("region_details", {}).items():
+        region_details[region]["should_play_sound"] = details.get("should_play_sound", False)
+        region_details[region]["mindful_stop_detected"] = details.get("mindful_stop_detected", False)
+
+    return {
+        "hands_detected": any(data.get("hands_detected") for data in latest.values()),
+        "face_detected": any(data.get("face_detected") for data in latest.values()),
+        "contact_points": sum(len(details["contacts"]) for details in region_details.values()),
+        "active_regions": list(fresh.get("active_regions", [])),
+        "regions_with_contact": [region for region, details in region_details.items() if details["contacts"]],
+        "alerts_active": [region for region, details in region_details.items() if details["should_play_sound"]],
+        "mindful_stops_detected": [region for region, details in region_details.items() if details["mindful_stop_detected"]],
+        "region_details": region_details,
+        "sources": sorted(latest),
+    }
+
+
+class BackendManager(QObject):
+    """Single owner of detection backend state, created once by the main window
+
+    The UI talks to the backend only through this object, so everything about the
+    running thread (options, status, restart history) lives in one place.
+
+    The primary camera drives the UI (feed, readiness, errors). Extra cameras run
+    as secondary instances whose detections are merged into the primary stream.
+
+    Nothing runs until detection is requested. Lifecycle:
+    stopped -> starting -> running <-> suspended -> dormant (after the idle
+    period) -> starting again on resume(); stop() returns to stopped from anywhere.
+    """
+
+    STOPPED = "stopped"
+    STARTING = "starting"
+    RUNNING = "running"
+    SUSPENDED = "suspended"
+    DORMANT = "dormant"  # Threads shut down while suspended; resume() starts them again
+
+    frame_ready = pyqtSignal(np.ndarray)
+    detection_data = pyqtSignal(dict)
+    ready = pyqtSignal()
+    startup_failed = pyqtSignal(object)  # StartupError
+    stopped_unexpectedly = pyqtSignal(str)
+    backend_crashed = pyqtSignal(dict)  # See crash_report()
+    restarted = pyqtSignal(int)
+    restart_failed = pyqtSignal(str)
+    status_changed = pyqtSignal(str)
+    backend_metrics = pyqtSignal(dict)
+    state_changed = pyqtSignal(str)  # Lifecycle state, see above
+    command_result = pyqtSignal(object)  # Every CommandResult, e.g. the answer to GetStats
+    command_failed = pyqtSignal(object)  # CommandResult of a failed or timed-out command
+    protocol_error = pyqtSignal(dict)  # A malformed backend message: instance, error and the offending payload
+    options_applied = pyqtSignal(list)  # Option names every camera confirmed taking on (see apply_options)
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.camera_thread = CameraThread()
+        self.supervisor = BackendSupervisor(self.camera_thread, self)
+        self.restart_count = 0  # Automatic restarts since the manager was created
+        self.last_crash = None  # crash_report() of the last unexpected stop
+        self.state = self.STOPPED
+        self.paused = False  # pause() was sent and not yet undone by resume()
+
+        # Extra cameras: instance id -> (thread, supervisor)
+        self.secondary_instances = {}
+        self.latest_detections = {}
+        self.dormant_secondaries = {}  # instance id -> camera index to bring back on resume
+
+        self.subscriptions = set(EVENT_TOPICS)
+
+        # Commands waiting for an answer: id -> [command, cameras yet to answer, deadline]
+        # Commands waiting for a starting (or restarting) camera: instance id -> FIFO of commands
+        self.startup_buffers = {}
+        self.pending_commands = {}
+        self.command_timer = QTimer(self)
+        self.command_timer.timeout.connect(self._expire_commands)
+
+        # Shuts the threads down after a long suspension to free the model's memory
+        self.idle_timer = QTimer(self)
+        self.idle_timer.setSingleShot(True)
+        self.idle_timer.timeout.connect(self._on_idle_timeout)
+
+        # Fails the start if the camera thread never reports ready
+        self.startup_timer = QTimer(self)
+        self.startup_timer.setSingleShot(True)
+        self.startup_timer.timeout.connect(self._on_startup_timeout)
+
+        self.camera_thread.frames_waiting.connect(self._on_frames_waiting)
+        self.camera_thread.detections_waiting.connect(lambda: self._drain_detections(PRIMARY_INSTANCE, self.camera_thread))
+        self.camera_thread.ready.connect(self._on_ready)
+        self.camera_thread.startup_failed.connect(self._on_startup_failed)
+        self.camera_thread.stopped_unexpectedly.connect(self._on_primary_stopped)
+        self.camera_thread.command_result.connect(self._on_command_result)
+        self.camera_thread.started.connect(self._on_thread_started)
+        self.supervisor.backend_restarted.connect(self._on_restarted)
+        self.supervisor.restart_failed.connect(self.restart_failed.emit)
+        self.supervisor.status_changed.connect(self.status_changed.emit)
+        self.supervisor.backend_metrics.connect(self._on_metrics)
+
+    @property
+    def options(self) -> BackendOptions:
+        """Options the thread is (or will next be) running with"""
+        return self.camera_thread.options
+
+    @property
+    def is_running(self):
+        return self.camera_thread.running and self.camera_thread.isRunning()
+
+    @property
+    def suspended(self):
+        """True from suspend() until resume() or stop(), including while dormant"""
+        return self.state in (self.SUSPENDED, self.DORMANT)
+
+    def _set_state(self, state):
+        if state != self.state:
+            print(f"Backend {self.state} -> {state}")
+            self.state = state
+            self.state_changed.emit(state)
+
+    def configure(self, options: BackendOptions):
+        """Adopt options without starting, so state like the region map is right before the first start"""
+        self.camera_thread.options = options
+        Config.update_contact_duration(options.alert_delay)
+        Config.set_custom_regions(options.custom_regions)
+        Config.ACTIVE_REGIONS = list(options.enabled_regions)
+
+    def start(self, options: BackendOptions) -> bool:
+        """Start detection; ready or startup_failed follows asynchronously"""
+        if not self.camera_thread.start_detection(options):
+            return False
+        self.supervisor.mark_started()
+        self.startup_timer.start(int(Config.STARTUP_TIMEOUT * 1000))
+        self._set_state(self.STARTING)
+        return True
+
+    def start_instance(self, instance_id: str, options: BackendOptions) -> bool:
+        """Start an extra camera whose detections are merged into the primary stream"""
+        if instance_id == PRIMARY_INSTANCE:
+            return self.start(options)
+
+        if instance_id not in self.secondary_instances:
+            thread = CameraThread()
+            thread.preview_enabled = False  # Only the primary camera is shown
+            supervisor = BackendSupervisor(thread, self)
+            thread.detections_waiting.connect(lambda: self._drain_detections(instance_id, thread))
+            thread.startup_failed.connect(lambda reason: self._on_secondary_failed(instance_id, reason))
+            thread.stopped_unexpectedly.connect(lambda reason: self.latest_detections.pop(instance_id, None))
+            thread.command_result.connect(self._on_command_result)
+            thread.ready.connect(lambda: self._flush_startup_buffer(instance_id))
+            supervisor.restart_failed.connect(lambda reason: self._on_secondary_failed(instance_id, reason))
+            supervisor.backend_restarted.connect(lambda attempt: self._resync(thread))
+            self.secondary_instances[instance_id] = (thread, supervisor)
+
+        thread, supervisor = self.secondary_instances[instance_id]
+        if not thread.start_detection(options):
+            return False
+        supervisor.mark_started()
+        print(f"Started extra camera {instance_id} (index {options.camera_index})")
+        return True
+
+    def stop_instance(self, instance_id: str, wait: bool = False):
+        if instance_id == PRIMARY_INSTANCE:
+            self.startup_timer.stop()
+            thread, supervisor = self.camera_thread, self.supervisor
+        elif instance_id in self.secondary_instances:
+            thread, supervisor = self.secondary_instances[instance_id]
+        else:
+            return
+
+        supervisor.cancel()
+        if thread.running or thread.isRunning():
+            thread.stop_detection(wait=wait)
+        self.latest_detections.pop(instance_id, None)
+        self.startup_buffers.pop(instance_id, None)
+
+    def stop(self, wait: bool = False):
+        """Stop every camera and drop any pending automatic restart; wait also waits out a model still loading"""
+        self._stop_threads(wait)
+        self.dormant_secondaries = {}
+        self._set_state(self.STOPPED)
+
+    def _stop_threads(self, wait: bool = False):
+        for instance_id in [PRIMARY_INSTANCE, *self.secondary_instances]:
+            self.stop_instance(instance_id, wait)
+        self.idle_timer.stop()
+        self.pending_commands = {}  # Nobody is left to answer them
+        self.startup_buffers = {}
+        self.paused = False
+
+    def _running_secondaries(self):
+        """instance id -> camera index of every extra camera that is running"""
+        return {instance_id: thread.options.camera_index for instance_id, (thread, _) in self.secondary_instances.items() if thread.running}
+
+    def restart(self, options: BackendOptions) -> bool:
+        """Stop and start again with new options; extra cameras keep their own index"""
+        running_secondaries = self._running_secondaries()
+        self.stop()
+        if not self.start(options):
+            return False
+        for instance_id, camera_index in running_secondaries.items():
+            self.start_instance(instance_id, replace(options, camera_index=camera_index))
+        return True
+
+    def _threads(self):
+        for _, thread in self._instances():
+            yield thread
+
+    def _instances(self):
+        yield PRIMARY_INSTANCE, self.camera_thread
+        for instance_id, (thread, _) in self.secondary_instances.items():
+            yield instance_id, thread
+
+    def send_backend_command(self, command: Command, instance_id: Optional[str] = None) -> bool:
+        """Send a protocol command to every camera (or just one); returns False if none is running
+
+        A camera still loading its model, or waiting to be restarted, gets the command
+        once it reports ready, in the order commands were sent. Each camera answers
+        with a CommandResult carrying the command's id; commands not answered within
+        their timeout after delivery fail with command_failed.
+        """
+        sent = False
+        for target, thread in self._instances():
+            if instance_id not in (None, target):
+                continue
+            if self._is_ready(thread):
+                self._deliver(thread, command)
+            elif thread.running:
+                self.startup_buffers.setdefault(target, deque()).append(command)
+            else:
+                continue
+            sent = True
+        return sent
+
+    @staticmethod
+    def _is_ready(thread):
+        """Running and past its first frame, so commands are picked up promptly"""
+        return thread.running and thread.isRunning() and thread.last_heartbeat is not None
+
+    def _deliver(self, thread, command):
+        thread.commands.put(command)
+        deadline = time.monotonic() + command.timeout
+        entry = self.pending_commands.get(command.id)
+        if entry:
+            entry[1] += 1
+            entry[2] = max(entry[2], deadline)
+        else:
+            self.pending_commands[command.id] = [command, 1, deadline]
+        if not self.command_timer.isActive():
+            self.command_timer.start(int(Config.COMMAND_TIMEOUT * 1000 / 2))
+
+    def _flush_startup_buffer(self, instance_id):
+        """The camera is ready: deliver what queued up while it started, oldest first"""
+        buffered = self.startup_buffers.pop(instance_id, None)
+        if not buffered:
+            return
+        thread = dict(self._instances())[instance_id]
+        now = time.monotonic()
+        for command in buffered:
+            if now - command.sent_at > command.buffer_timeout:
+                print(f"Backend command {command.name} #{command.id} expired before camera {instance_id} was ready")
+                self.command_failed.emit(CommandResult(command.id, command.name, ok=False, error="expired before the camera was ready"))
+            else:
+                self._deliver(thread, command)
+
+    def _on_command_result(self, result: CommandResult):
+        if not isinstance(result, CommandResult):
+            self._on_protocol_error("unknown", ProtocolError(f"command result is {type(result).__name__}", result))
+            return
+        self.command_result.emit(result)
+        if result.id not in self.pending_commands:
+            return  # Already timed out, or answered by the other cameras
+        entry = self.pending_commands.pop(result.id)
+        if not result.ok:
+            self.command_failed.emit(result)
+        elif entry[1] > 1:
+            entry[1] -= 1
+            self.pending_commands[result.id] = entry
+        elif isinstance(entry[0], ApplyOptions):
+            self.options_applied.emit(sorted(entry[0].changes))
+
+    def _expire_commands(self):
+        """Fail every command that sat unanswered for longer than its timeout"""
+        now = time.monotonic()
+        for command_id, (command, _, deadline) in list(self.pending_commands.items()):
+            if now > deadline:
+                del self.pending_commands[command_id]
+                print(f"Backend command {command.name} #{command_id} timed out")
+                self.command_failed.emit(CommandResult(command_id, command.name, ok=False, error="timed out"))
+        if not self.pending_commands:
+            self.command_timer.stop()
+
+    def _update_options(self, **changes):
+        """Apply an option change to every camera, so one that restarts later comes back with it"""
+        for thread in self._threads():
+            for name, value in changes.items():
+                setattr(thread.options, name, value)
+
+    def _resync(self, thread):
+        """Replay the current configuration and paused/suspended state into a restarted camera thread"""
+        options = self.options
+        for command in (SetRegions(list(options.enabled_regions)), SetAlertDelay(options.alert_delay), SetSensitivity(options.sensitivity)):
+            thread.commands.put(command)
+        if self.state == self.SUSPENDED:
+            thread.commands.put(Suspend())
+        elif self.paused:
+            thread.commands.put(Pause())
+
+    def apply_options(self, options: BackendOptions) -> List[str]:
+        """Send the live options that differ from the current ones as one command; returns their names
+
+        Running cameras answer with an ack (options_applied, or command_failed); with none
+        running, the options are simply used at the next start. Changing anything else,
+        like the camera, still needs a restart.
+        """
+        changes = {}
+        for name in LIVE_OPTIONS:
+            value = getattr(options, name)
+            if value != getattr(self.options, name):
+                changes[name] = list(value) if isinstance(value, list) else value
+        if not changes:
+            return []
+        self._update_options(**changes)
+        if not self.send_backend_command(ApplyOptions(changes)):
+            Config.update_contact_duration(self.options.alert_delay)
+            Config.set_custom_regions(self.options.custom_regions)
+            Config.ACTIVE_REGIONS = list(self.options.enabled_regions)
+        return sorted(changes)
+
+    def set_regions(self, regions):
+        """Change watched regions live, or for the next start if idle"""
+        self._update_options(enabled_regions=list(regions))
+        if not self.send_backend_command(SetRegions(list(regions))):
+            Config.ACTIVE_REGIONS = list(regions)
+
+    def toggle_region(self, region: str, enabled: bool) -> Dict[str, bool]:
+        """Watch or ignore one region; returns the resulting region map"""
+        if region in Config.all_regions():
+            current = self.options.enabled_regions
+            self.set_regions([r for r in Config.all_regions() if (r == region and enabled) or (r != region and r in current)])
+        return self.region_map()
+
+    def region_map(self) -> Dict[str, bool]:
+        """Every available region and whether it is watched, in display order"""
+        return {region: region in self.options.enabled_regions for region in Config.all_regions()}
+
+    def set_alert_delay(self, duration: float):
+        self._update_options(alert_delay=duration)
+        if not self.send_backend_command(SetAlertDelay(duration)):
+            Config.update_contact_duration(duration)
+
+    def set_sensitivity(self, sensitivity: float):
+        self._update_options(sensitivity=sensitivity)
+        self.send_backend_command(SetSensitivity(sensitivity))
+
+    def set_low_priority(self, low: bool):
+        """Run the camera threads below normal CPU priority so games and calls stay smooth"""
+        self._update_options(low_priority=low)
+        self.send_backend_command(SetPriority(low))
+
+    def set_frame_rate(self, max_fps: float):
+        """Cap how many frames per second the cameras process (0 = uncapped), e.g. to save battery"""
+        self._update_options(max_fps=max_fps)
+        self.send_backend_command(SetFrameRate(max_fps))
+
+    def start_preview(self, max_fps: float = Config.PREVIEW_MAX_FPS):
+        """Stream preview frames from the primary camera; only it feeds the UI"""
+        self.camera_thread.preview_enabled = True
+        self.camera_thread.preview_fps = max_fps
+        self.send_backend_command(StartPreview(max_fps), PRIMARY_INSTANCE)
+
+    def stop_preview(self):
+        """Stop preview frames, e.g. while the feed is hidden; detection carries on"""
+        self.camera_thread.preview_enabled = False
+        self.send_backend_command(StopPreview(), PRIMARY_INSTANCE)
+
+    def subscribe_events(self, topics) -> List[str]:
+        """Replace the event topics delivered to the UI; returns the topics now subscribed
+
+        detections: per-frame detection dicts. preview: camera frames, stopped at
+        the source while unsubscribed. metrics: fps/CPU/memory samples.
+        """
+        unknown = [topic for topic in topics if topic not in EVENT_TOPICS]
+        if unknown:
+            print(f"Ignoring unknown event topics: {unknown}")
+        wanted = {topic for topic in topics if topic in EVENT_TOPICS}
+
+        if "preview" in wanted and "preview" not in self.subscriptions:
+            self.start_preview()
+        elif "preview" not in wanted and "preview" in self.subscriptions:
+            self.stop_preview()
+
+        self.subscriptions = wanted
+        return [topic for topic in EVENT_TOPICS if topic in wanted]
+
+    def pause(self):
+        self.paused = self.send_backend_command(Pause())
+        return self.paused
+
+    def suspend(self):
+        """Release the camera and park the threads until resume(); unlike pause() this uses no CPU"""
+        if self.state in (self.STARTING, self.RUNNING) and self.send_backend_command(Suspend()):
+            self._set_state(self.SUSPENDED)
+            if self.options.idle_shutdown_minutes > 0:
+                self.idle_timer.start(int(self.options.idle_shutdown_minutes * 60 * 1000))
+        return self.suspended
+
+    def resume(self):
+        """Undo suspend(); a dormant backend is started again from scratch"""
+        self.idle_timer.stop()
+        if self.state == self.DORMANT:
+            secondaries, self.dormant_secondaries = self.dormant_secondaries, {}
+            if not self.start(self.options):
+                return False
+            for instance_id, camera_index in secondaries.items():
+                self.start_instance(instance_id, replace(self.options, camera_index=camera_index))
+            return True
+
+        if self.state == self.SUSPENDED:
+            self._set_state(self.RUNNING)
+        self.paused = False
+        return self.send_backend_command(Resume())
+
+    def request_stats(self) -> bool:
+        """Ask every camera for its counters; answers arrive through command_result"""
+        return self.send_backend_command(GetStats())
+
+    def _on_idle_timeout(self):
+        """Suspended for the whole idle period: shut the threads down until resume()"""
+        if self.state != self.SUSPENDED:
+            return
+        print(f"Suspended for {self.options.idle_shutdown_minutes:g} minutes, shutting detection down")
+        self.dormant_secondaries = self._running_secondaries()
+        self._stop_threads()
+        self._set_state(self.DORMANT)
+
+    def status(self, instance_id: str = PRIMARY_INSTANCE) -> dict:
+        if instance_id in self.secondary_instances:
+            return self.secondary_instances[instance_id][1].backend_status()
+        return self.supervisor.backend_status()
+
+    def metrics(self) -> dict:
+        return self.supervisor.get_backend_metrics()
+
+    def _on_frames_waiting(self):
+        """Only the newest frame is worth painting"""
+        frames = self.camera_thread.frames.drain()
+        if frames:
+            self.frame_ready.emit(frames[-1])
+
+    def _drain_detections(self, instance_id, thread):
+        for data in thread.detections.drain():
+            try:
+                validate_detection(data)
+            except ProtocolError as e:
+                self._on_protocol_error(instance_id, e)
+                continue
+            self._on_detection(instance_id, data)
+
+    def _on_protocol_error(self, instance_id, error: ProtocolError):
+        """Report a message we can't trust instead of acting on it"""
+        print(f"Protocol error from camera {instance_id}: {error}")
+        self.protocol_error.emit({"instance": instance_id, "error": str(error), "payload": error.payload})
+
+    def _on_detection(self, instance_id, data):
+        """Forward detections, merged across cameras when more than one is running"""
+        self.latest_detections[instance_id] = data
+        if "detections" not in self.subscriptions:
+            return
+        if len(self.latest_detections) == 1:
+            self.detection_data.emit(data)
+        else:
+            self.detection_data.emit(merge_detections(self.latest_detections, instance_id))
+
+    def _on_primary_stopped(self, reason):
+        self.latest_detections.pop(PRIMARY_INSTANCE, None)
+        self.last_crash = crash_report(self.camera_thread, reason)
+        self.backend_crashed.emit(self.last_crash)
+        self.stopped_unexpectedly.emit(reason)
+
+    def _on_secondary_failed(self, instance_id, reason):
+        """An extra camera failing never takes the primary down with it"""
+        thread, supervisor = self.secondary_instances[instance_id]
+        if supervisor.restarting:
+            return
+        print(f"Extra camera {instance_id} stopped: {reason}")
+        self.stop_instance(instance_id)
+
+    def _on_metrics(self, metrics):
+        if "metrics" in self.subscriptions:
+            self.backend_metrics.emit(metrics)
+
+    def _on_ready(self):
+        self.startup_timer.stop()
+        self._flush_startup_buffer(PRIMARY_INSTANCE)
+        if self.state == self.STARTING:
+            self._set_state(self.RUNNING)
+        self.ready.emit()
+
+    def _on_startup_failed(self, reason):
+        # Failed automatic restarts are the supervisor's business, not the UI's
+        if not self.supervisor.restarting:
+            self.startup_timer.stop()
+            self.startup_failed.emit(reason)
+
+    def _on_thread_started(self):
+        # A start made while the previous run was still loading only begins now
+        if self.state == self.STARTING:
+            self.startup_timer.start(int(Config.STARTUP_TIMEOUT * 1000))
+
+    def _on_startup_timeout(self):
+        if self.camera_thread.running and self.camera_thread.last_heartbeat is None:
+            self.startup_failed.emit(
+                StartupError(StartupError.TIMEOUT, f"The camera did not deliver a frame within {Config.STARTUP_TIMEOUT:.0f} seconds.")
+            )
+
+    def _on_restarted(self, attempt):
+        self.restart_count += 1
+        self._resync(self.camera_thread)
+        self.restarted.emit(attempt)
@@ -0,0 +1,45 @@
+"""
+Resource usage sampling for Mindful Touch
+Process CPU and memory using only the standard library
+"""
+
+import os
+import sys
+import time
+
+
+def memory_mb():
+    """Resident memory of this process in MB, or None if the platform can't tell us"""
+    try:
+        if sys.platform.startswith("linux"):
+            with open("/proc/self/statm") as f:
+                resident_pages = int(f.read().split()[1])
+            return resident_pages * os.sysconf("SC_PAGE_SIZE") / (1024 * 1024)
+
+        import resource
+
+        # ru_maxrss is the peak, in bytes on macOS — close enough for a steady-state app
+        return resource.getrusage(resource.RUSAGE_SELF).ru_maxrss / (1024 * 1024)
+    except Exception:
+        return None
+
+
+class ResourceMonitor:
+    """CPU percentage between consecutive samples, plus current memory"""
+
+    def __init__(self):
+        self._last_wall = time.monotonic()
+        self._last_cpu = time.process_time()
+
+    def sample(self) -> dict:
+        wall = time.monotonic()
+        cpu = time.process_time()
+        elapsed = wall - self._last_wall
+        cpu_percent = 100.0 * (cpu - self._last_cpu) / elapsed if elapsed > 0 else 0.0
+        self._last_wall, self._last_cpu = wall, cpu
+
+        memory = memory_mb()
+        return {
+            "cpu_percent": round(cpu_percent, 1),
+            "memory_mb": round(memory, 1) if memory is not None else None,
+        }
@@ -0,0 +1,27 @@
+"""
+First-run setup for Mindful Touch
+The steps a new user goes through before detection may start on its own, remembered across launches
+"""
+
+from typing import List, Optional
+
+CAMERA_PERMISSION = "camera_permission"  # The camera may be used (always true outside macOS)
+CALIBRATION = "calibration"  # The camera saw a face and a hand together, so it's placed well enough
+FIRST_SESSION = "first_session"  # A detection session was started and stopped
+STEPS = [CAMERA_PERMISSION, CALIBRATION, FIRST_SESSION]
+
+
+def next_step(completed: List[str]) -> Optional[str]:
+    """The first step not done yet, or None once setup is finished"""
+    return next((step for step in STEPS if step not in completed), None)
+
+
+def state(completed: List[str]) -> dict:
+    return {"steps": {step: step in completed for step in STEPS}, "next": next_step(completed), "finished": next_step(completed) is None}
+
+
+def complete(completed: List[str], step: str) -> List[str]:
+    """The completed steps with this one added, in STEPS order; raises ValueError for an unknown step"""
+    if step not in STEPS:
+        raise ValueError(f"Unknown onboarding step: {step}")
+    return [known for known in STEPS if known in completed or known == step]
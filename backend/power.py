@@ -0,0 +1,101 @@
+"""
+Power status for Mindful Touch
+Battery charge and AC state, read from sysfs, pmset or GetSystemPowerStatus
+"""
+
+import ctypes
+import re
+import subprocess
+import sys
+from pathlib import Path
+from typing import Optional
+
+from PyQt6.QtCore import QObject, QTimer, pyqtSignal
+
+from backend.detection.config import Config
+
+POWER_SUPPLY_PATH = Path("/sys/class/power_supply")
+
+
+def _linux_power() -> Optional[dict]:
+    batteries = [
+        supply for supply in POWER_SUPPLY_PATH.glob("*") if (supply / "type").exists() and (supply / "type").read_text().strip() == "Battery"
+    ]
+    if not batteries:
+        return None  # Desktop
+    battery = batteries[0]
+    percent = float((battery / "capacity").read_text().strip())
+    status = (battery / "status").read_text().strip()
+    return {"on_battery": status == "Discharging", "percent": percent}
+
+
+def _macos_power() -> Optional[dict]:
+    output = subprocess.run(["pmset", "-g", "batt"], capture_output=True, text=True, timeout=2).stdout
+    match = re.search(r"(\d+)%", output)
+    if not match:
+        return None
+    return {"on_battery": "'Battery Power'" in output, "percent": float(match.group(1))}
+
+
+class _SystemPowerStatus(ctypes.Structure):
+    _fields_ = [
+        ("ACLineStatus", ctypes.c_ubyte),
+        ("BatteryFlag", ctypes.c_ubyte),
+        ("BatteryLifePercent", ctypes.c_ubyte),
+        ("SystemStatusFlag", ctypes.c_ubyte),
+        ("BatteryLifeTime", ctypes.c_ulong),
+        ("BatteryFullLifeTime", ctypes.c_ulong),
+    ]
+
+
+def _windows_power() -> Optional[dict]:
+    NO_BATTERY = 128
+    UNKNOWN_PERCENT = 255
+    status = _SystemPowerStatus()
+    if not ctypes.windll.kernel32.GetSystemPowerStatus(ctypes.byref(status)) or status.BatteryFlag & NO_BATTERY:
+        return None
+    percent = None if status.BatteryLifePercent == UNKNOWN_PERCENT else float(status.BatteryLifePercent)
+    return {"on_battery": status.ACLineStatus == 0, "percent": percent}
+
+
+def get_power_status() -> Optional[dict]:
+    """{"on_battery": bool, "percent": float or None}, or None without a battery (or a way to read it)"""
+    try:
+        if sys.platform == "darwin":
+            return _macos_power()
+        if sys.platform == "win32":
+            return _windows_power()
+        return _linux_power()
+    except Exception:
+        return None
+
+
+class PowerMonitor(QObject):
+    """Polls the battery and reports when it changes"""
+
+    power_changed = pyqtSignal(dict)  # See get_power_status()
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.status = None
+
+        self.timer = QTimer(self)
+        self.timer.timeout.connect(self.refresh)
+
+    def start(self):
+        self.refresh()
+        self.timer.start(int(Config.POWER_POLL_INTERVAL * 1000))
+
+    def stop(self):
+        self.timer.stop()
+
+    def refresh(self):
+        status = get_power_status()
+        if status is None:
+            if self.status is None:
+                # No battery at all: nothing to watch
+                self.timer.stop()
+            return
+        if status != self.status:
+            self.status = status
+            self.power_changed.emit(status)
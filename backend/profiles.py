@@ -0,0 +1,35 @@
+"""
+Settings profiles for Mindful Touch
+Named sets of detection and alert settings ("Deep work", "Evening", "Demo") to switch between in one go
+"""
+
+from typing import Optional
+
+# What a profile holds; everything else (camera, startup, window behavior) is shared by all of them
+PROFILE_KEYS = [
+    "active_regions",
+    "custom_regions",
+    "sensitivity",
+    "alert_delay",
+    "alert_min_interval",
+    "alert_max_per_hour",
+    "escalation_window_minutes",
+    "escalation_levels",
+    "region_alerts",
+    "alert_sound",
+    "alert_volume",
+    "edge_flash",
+    "quiet_hours",
+]
+MAX_NAME_LENGTH = 40
+
+
+def clean_name(name: str) -> Optional[str]:
+    """The name trimmed, or None if it's empty or too long"""
+    name = " ".join(str(name).split())
+    return name if 0 < len(name) <= MAX_NAME_LENGTH else None
+
+
+def snapshot(settings: dict) -> dict:
+    """The profile-held part of the settings"""
+    return {key: settings[key] for key in PROFILE_KEYS}
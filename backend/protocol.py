@@ -0,0 +1,165 @@
+"""
+Backend command protocol for Mindful Touch
+Typed commands sent to the camera thread, the results it sends back, and the detection message schema
+"""
+
+import itertools
+import time
+from dataclasses import dataclass, field
+from typing import ClassVar, Dict, List, Optional
+
+from backend.detection.config import Config
+
+# Bumped whenever the detection message changes shape
+PROTOCOL_VERSION = 1
+
+_ids = itertools.count(1)
+
+
+@dataclass
+class Command:
+    """Base for everything queued to the camera thread; id correlates the CommandResult"""
+
+    timeout: ClassVar[float] = Config.COMMAND_TIMEOUT  # Seconds after delivery until an unanswered command fails
+    buffer_timeout: ClassVar[float] = Config.COMMAND_BUFFER_TIMEOUT  # Seconds it may wait for a starting camera
+
+    id: int = field(default_factory=lambda: next(_ids), init=False)
+    sent_at: float = field(default_factory=time.monotonic, init=False)
+
+    @property
+    def name(self) -> str:
+        return type(self).__name__
+
+
+@dataclass
+class SetRegions(Command):
+    regions: List[str]
+
+
+@dataclass
+class SetAlertDelay(Command):
+    value: float
+
+
+@dataclass
+class SetSensitivity(Command):
+    value: float
+
+
+@dataclass
+class SetPriority(Command):
+    low: bool
+
+
+@dataclass
+class SetFrameRate(Command):
+    """Process at most max_fps frames per second; 0 lifts the cap"""
+
+    max_fps: float
+
+
+@dataclass
+class ApplyOptions(Command):
+    """Several BackendOptions fields at once (see manager.LIVE_OPTIONS); the result's value lists those applied"""
+
+    changes: Dict[str, object]
+
+
+@dataclass
+class StartPreview(Command):
+    """Send annotated preview frames to the UI, at most max_fps per second"""
+
+    max_fps: float = Config.PREVIEW_MAX_FPS
+
+
+@dataclass
+class StopPreview(Command):
+    """Keep detecting but stop sending preview frames"""
+
+
+@dataclass
+class Pause(Command):
+    """Skip frames but keep the camera open"""
+
+
+@dataclass
+class Suspend(Command):
+    """Release the camera and block until Resume"""
+
+
+@dataclass
+class Resume(Command):
+    """Undo Pause or Suspend"""
+
+
+@dataclass
+class Wake(Command):
+    """Unblock a suspended thread so it notices it was stopped"""
+
+
+@dataclass
+class GetStats(Command):
+    """Ask the thread for its counters; they come back in CommandResult.value"""
+
+
+@dataclass
+class CommandResult:
+    id: int
+    command: str
+    ok: bool
+    value: Optional[dict] = None
+    error: Optional[str] = None
+
+
+# Expected type of every field of a detection message, and of each region_details entry
+DETECTION_SCHEMA = {
+    "protocol_version": int,
+    "hands_detected": bool,
+    "face_detected": bool,
+    "contact_points": int,
+    "active_regions": list,
+    "regions_with_contact": list,
+    "alerts_active": list,
+    "mindful_stops_detected": list,
+    "region_details": dict,
+}
+REGION_DETAILS_SCHEMA = {
+    "contacts": list,
+    "alert_active": bool,
+    "should_play_sound": bool,
+    "mindful_stop_detected": bool,
+    "contact_duration": (int, float),
+}
+
+
+class ProtocolError(ValueError):
+    """A backend message that doesn't match the schema; payload is the offending message"""
+
+    def __init__(self, message: str, payload=None):
+        super().__init__(message)
+        self.payload = payload
+
+
+def _check_fields(data: dict, schema: dict, where: str):
+    for key, expected in schema.items():
+        if key not in data:
+            raise ProtocolError(f"{where}: missing field {key!r}", data)
+        # bool is an int subclass; don't let True pass as a count
+        if not isinstance(data[key], expected) or (expected is int and isinstance(data[key], bool)):
+            raise ProtocolError(f"{where}: field {key!r} is {type(data[key]).__name__}", data)
+
+
+def validate_detection(data) -> dict:
+    """Check a detection message from the camera thread; raises ProtocolError, returns data unchanged"""
+    if not isinstance(data, dict):
+        raise ProtocolError(f"detection is {type(data).__name__}, not dict", data)
+    version = data.get("protocol_version")
+    if version != PROTOCOL_VERSION:
+        raise ProtocolError(f"detection has protocol version {version!r}, expected {PROTOCOL_VERSION}", data)
+
+    _check_fields(data, DETECTION_SCHEMA, "detection")
+    for region, details in data["region_details"].items():
+        if not isinstance(details, dict):
+            raise ProtocolError(f"region_details[{region!r}] is {type(details).__name__}", data)
+        _check_fields(details, REGION_DETAILS_SCHEMA, f"region_details[{region!r}]")
+    return data
@@ -0,0 +1,53 @@
+"""
+Schedules for Mindful Touch
+Daily time windows in local time, e.g. quiet hours from 21:00 to 08:00 on weeknights
+"""
+
+from datetime import datetime, time
+from typing import List, Optional
+
+ALL_DAYS = [0, 1, 2, 3, 4, 5, 6]  # Monday = 0, as datetime.weekday()
+
+
+def parse_time(value: str) -> Optional[time]:
+    """'21:00' -> time(21, 0); None if it isn't HH:MM"""
+    try:
+        hours, minutes = value.split(":")
+        return time(int(hours), int(minutes))
+    except (AttributeError, ValueError):
+        return None
+
+
+def validate_window(window: dict) -> Optional[dict]:
+    """A clean {"start", "end", "days"} window, or None if it can't be understood"""
+    if not isinstance(window, dict):
+        return None
+    start, end = parse_time(window.get("start")), parse_time(window.get("end"))
+    if start is None or end is None or start == end:
+        return None
+    days = window.get("days", ALL_DAYS)
+    if not isinstance(days, list) or not days or any(day not in ALL_DAYS for day in days):
+        return None
+    return {"start": start.strftime("%H:%M"), "end": end.strftime("%H:%M"), "days": sorted(set(days))}
+
+
+def in_window(window: dict, now: datetime) -> bool:
+    """Whether now falls in the window; one that crosses midnight belongs to the day it starts on"""
+    start, end = parse_time(window["start"]), parse_time(window["end"])
+    days = window.get("days", ALL_DAYS)
+    current = now.time()
+    if start < end:
+        return now.weekday() in days and start <= current < end
+    # Overnight: the evening part today, or the morning part of a window that started yesterday
+    if current >= start:
+        return now.weekday() in days
+    return current < end and (now.weekday() - 1) % 7 in days
+
+
+def active_window(windows: List[dict], now: Optional[datetime] = None) -> Optional[dict]:
+    """The first window now falls in, or None"""
+    now = datetime.now() if now is None else now
+    for window in windows:
+        if validate_window(window) and in_window(window, now):
+            return window
+    return None
@@ -0,0 +1,197 @@
+"""
+Session monitor for Mindful Touch
+Notices screen lock/unlock, sleep/wake and input idleness so detection can step aside while nobody is there
+"""
+
+import ctypes
+import ctypes.util
+import os
+import re
+import subprocess
+import sys
+import time
+from typing import Optional
+
+from PyQt6.QtCore import QObject, QTimer, pyqtSignal
+
+from backend.detection.config import Config
+
+
+def _macos_screen_locked() -> Optional[bool]:
+    core_graphics = ctypes.cdll.LoadLibrary(ctypes.util.find_library("CoreGraphics"))
+    core_foundation = ctypes.cdll.LoadLibrary(ctypes.util.find_library("CoreFoundation"))
+    core_graphics.CGSessionCopyCurrentDictionary.restype = ctypes.c_void_p
+    core_foundation.CFStringCreateWithCString.restype = ctypes.c_void_p
+    core_foundation.CFStringCreateWithCString.argtypes = [ctypes.c_void_p, ctypes.c_char_p, ctypes.c_uint32]
+    core_foundation.CFDictionaryGetValue.restype = ctypes.c_void_p
+    core_foundation.CFDictionaryGetValue.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    core_foundation.CFBooleanGetValue.restype = ctypes.c_bool
+    core_foundation.CFBooleanGetValue.argtypes = [ctypes.c_void_p]
+    core_foundation.CFRelease.argtypes = [ctypes.c_void_p]
+
+    session = core_graphics.CGSessionCopyCurrentDictionary()
+    if not session:
+        return None  # No window server session, e.g. over ssh
+    key = core_foundation.CFStringCreateWithCString(None, b"CGSSessionScreenIsLocked", 0x08000100)  # UTF-8
+    try:
+        value = core_foundation.CFDictionaryGetValue(session, key)
+        # The key is only present while locked
+        return bool(value) and core_foundation.CFBooleanGetValue(value)
+    finally:
+        core_foundation.CFRelease(key)
+        core_foundation.CFRelease(session)
+
+
+def _windows_screen_locked() -> Optional[bool]:
+    DESKTOP_SWITCHDESKTOP = 0x0100
+    user32 = ctypes.windll.user32
+    # The secure desktop shown while locked can't be opened by a user process
+    desktop = user32.OpenInputDesktop(0, False, DESKTOP_SWITCHDESKTOP)
+    if not desktop:
+        return True
+    user32.CloseDesktop(desktop)
+    return False
+
+
+def _linux_screen_locked() -> Optional[bool]:
+    session = os.environ.get("XDG_SESSION_ID", "self")
+    result = subprocess.run(
+        ["loginctl", "show-session", session, "-p", "LockedHint", "--value"], capture_output=True, text=True, timeout=2
+    )
+    value = result.stdout.strip()
+    return value == "yes" if value in ("yes", "no") else None
+
+
+def _macos_idle_seconds() -> Optional[float]:
+    core_graphics = ctypes.cdll.LoadLibrary(ctypes.util.find_library("CoreGraphics"))
+    core_graphics.CGEventSourceSecondsSinceLastEventType.restype = ctypes.c_double
+    core_graphics.CGEventSourceSecondsSinceLastEventType.argtypes = [ctypes.c_int, ctypes.c_uint32]
+    HID_SYSTEM_STATE = 1
+    ANY_INPUT_EVENT = 0xFFFFFFFF
+    return core_graphics.CGEventSourceSecondsSinceLastEventType(HID_SYSTEM_STATE, ANY_INPUT_EVENT)
+
+
+class _LastInputInfo(ctypes.Structure):
+    _fields_ = [("cbSize", ctypes.c_uint), ("dwTime", ctypes.c_uint)]
+
+
+def _windows_idle_seconds() -> Optional[float]:
+    info = _LastInputInfo(ctypes.sizeof(_LastInputInfo), 0)
+    if not ctypes.windll.user32.GetLastInputInfo(ctypes.byref(info)):
+        return None
+    # Both are milliseconds since boot, wrapping at 2**32
+    return ((ctypes.windll.kernel32.GetTickCount() - info.dwTime) & 0xFFFFFFFF) / 1000.0
+
+
+def _linux_idle_seconds() -> Optional[float]:
+    # GNOME (X11 and Wayland) first, then xprintidle for other X11 desktops
+    try:
+        result = subprocess.run(
+            [
+                "gdbus",
+                "call",
+                "--session",
+                "--dest",
+                "org.gnome.Mutter.IdleMonitor",
+                "--object-path",
+                "/org/gnome/Mutter/IdleMonitor/Core",
+                "--method",
+                "org.gnome.Mutter.IdleMonitor.GetIdletime",
+            ],
+            capture_output=True,
+            text=True,
+            timeout=2,
+        )
+        match = re.search(r"(\d+)", result.stdout)
+        if result.returncode == 0 and match:
+            return int(match.group(1)) / 1000.0
+    except OSError:
+        pass
+    result = subprocess.run(["xprintidle"], capture_output=True, text=True, timeout=2)
+    return int(result.stdout.strip()) / 1000.0 if result.returncode == 0 else None
+
+
+def idle_seconds() -> Optional[float]:
+    """Seconds since the last keyboard or mouse input; None if this platform or session can't tell"""
+    try:
+        if sys.platform == "darwin":
+            return _macos_idle_seconds()
+        if sys.platform == "win32":
+            return _windows_idle_seconds()
+        return _linux_idle_seconds()
+    except Exception:
+        return None
+
+
+def is_screen_locked() -> Optional[bool]:
+    """True while the session is locked; None if this platform or session can't tell"""
+    try:
+        if sys.platform == "darwin":
+            return _macos_screen_locked()
+        if sys.platform == "win32":
+            return _windows_screen_locked()
+        return _linux_screen_locked()
+    except Exception:
+        return None
+
+
+class SessionMonitor(QObject):
+    """Polls the lock state and watches for clock jumps that mean the machine slept"""
+
+    locked_changed = pyqtSignal(bool)
+    woke_from_sleep = pyqtSignal(float)  # Seconds asleep
+    idle_changed = pyqtSignal(bool)  # No input for idle_threshold seconds (True), or input again (False)
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.locked = False
+        self.supported = True  # Cleared once the platform can't report the lock state
+        self.idle = False
+        self.idle_threshold = 0.0  # Seconds without input that count as away (0 = don't watch)
+        self.idle_supported = True
+        self._last_wall = time.time()
+        self._last_monotonic = time.monotonic()
+
+        self.timer = QTimer(self)
+        self.timer.timeout.connect(self._poll)
+
+    def start(self):
+        self._last_wall, self._last_monotonic = time.time(), time.monotonic()
+        self.timer.start(int(Config.SESSION_POLL_INTERVAL * 1000))
+
+    def stop(self):
+        self.timer.stop()
+
+    def _poll(self):
+        # The monotonic clock stops during sleep, the wall clock doesn't
+        wall, monotonic = time.time(), time.monotonic()
+        asleep = (wall - self._last_wall) - (monotonic - self._last_monotonic)
+        self._last_wall, self._last_monotonic = wall, monotonic
+        if asleep > Config.SLEEP_DETECTION_GAP:
+            print(f"Woke from sleep after {asleep:.0f}s")
+            self.woke_from_sleep.emit(asleep)
+
+        self._check_idle()
+
+        if not self.supported:
+            return
+        locked = is_screen_locked()
+        if locked is None:
+            self.supported = False
+            print("Screen lock state unavailable, not pausing on lock")
+        elif locked != self.locked:
+            self.locked = locked
+            self.locked_changed.emit(locked)
+
+    def _check_idle(self):
+        if not self.idle_threshold or not self.idle_supported:
+            return
+        seconds = idle_seconds()
+        if seconds is None:
+            self.idle_supported = False
+            print("Input idle time unavailable, not pausing when away")
+            return
+        idle = seconds >= self.idle_threshold
+        if idle != self.idle:
+            self.idle = idle
+            self.idle_changed.emit(idle)
@@ -0,0 +1,52 @@
+"""
+Settings file watcher for Mindful Touch
+Notices when settings.json is edited outside the app (by hand, or by a dotfile sync tool) so it can be reloaded
+"""
+
+from pathlib import Path
+
+from PyQt6.QtCore import QFileSystemWatcher, QObject, QTimer, pyqtSignal
+
+from backend.detection import settings_store
+
+SETTLE_MS = 300  # Editors and sync tools often write in several steps; reload once they're done
+
+
+class SettingsWatcher(QObject):
+    """Emits changed once the file has settled after a change, including our own saves (reloading those finds nothing new)
+
+    The folder is watched too: saving by replacing the file (as we and most editors do)
+    leaves the watcher holding the old file, so it's picked up again when it reappears.
+    """
+
+    changed = pyqtSignal()
+
+    def __init__(self, path: Path = None, parent=None):
+        super().__init__(parent)
+        self.path = path or settings_store.SETTINGS_PATH
+        self.watcher = QFileSystemWatcher(self)
+        self.watcher.fileChanged.connect(self._on_change)
+        self.watcher.directoryChanged.connect(self._on_change)
+
+        self.settle_timer = QTimer(self)
+        self.settle_timer.setSingleShot(True)
+        self.settle_timer.timeout.connect(self.changed.emit)
+
+    def start(self):
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        self.watcher.addPath(str(self.path.parent))
+        self._watch_file()
+
+    def stop(self):
+        self.settle_timer.stop()
+        paths = self.watcher.files() + self.watcher.directories()
+        if paths:
+            self.watcher.removePaths(paths)
+
+    def _watch_file(self):
+        if self.path.exists() and str(self.path) not in self.watcher.files():
+            self.watcher.addPath(str(self.path))
+
+    def _on_change(self, _path):
+        self._watch_file()
+        self.settle_timer.start(SETTLE_MS)
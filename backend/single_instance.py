@@ -0,0 +1,73 @@
+"""
+Single-instance guard for Mindful Touch
+A local socket the first instance listens on; later launches hand it their arguments and exit
+"""
+
+import getpass
+import json
+from typing import List
+
+from PyQt6.QtCore import QObject, pyqtSignal
+from PyQt6.QtNetwork import QLocalServer, QLocalSocket
+
+CONNECT_TIMEOUT_MS = 500
+
+
+def server_name() -> str:
+    """Per user, so two people logged in on one machine each get their own instance"""
+    try:
+        user = getpass.getuser()
+    except Exception:
+        user = "user"
+    return f"mindful-touch-{user}"
+
+
+def forward(args: List[str]) -> bool:
+    """Send our arguments to a running instance; True if one took them (so we should exit)"""
+    socket = QLocalSocket()
+    socket.connectToServer(server_name())
+    if not socket.waitForConnected(CONNECT_TIMEOUT_MS):
+        return False
+    socket.write(json.dumps(args).encode() + b"\n")
+    socket.waitForBytesWritten(CONNECT_TIMEOUT_MS)
+    socket.disconnectFromServer()
+    return True
+
+
+class InstanceServer(QObject):
+    """Listens for later launches; each one arrives as message_received(args)"""
+
+    message_received = pyqtSignal(list)
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.server = QLocalServer(self)
+        self.server.newConnection.connect(self._on_new_connection)
+
+    def listen(self) -> bool:
+        if not self.server.listen(server_name()):
+            # A socket file left by a crashed instance makes listen() fail; only then is it safe to remove
+            QLocalServer.removeServer(server_name())
+            if not self.server.listen(server_name()):
+                print(f"Could not listen for other instances: {self.server.errorString()}")
+                return False
+        return True
+
+    def _on_new_connection(self):
+        socket = self.server.nextPendingConnection()
+        if socket is None:
+            return
+        socket.readyRead.connect(lambda: self._read(socket))
+        socket.disconnected.connect(socket.deleteLater)
+        self._read(socket)  # The message may have arrived with the connection
+
+    def _read(self, socket):
+        if not socket.canReadLine():
+            return
+        try:
+            args = json.loads(bytes(socket.readLine()).decode())
+        except ValueError as e:
+            print(f"Ignoring malformed message from another instance: {e}")
+            return
+        if isinstance(args, list):
+            self.message_received.emit([str(arg) for arg in args])
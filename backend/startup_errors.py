@@ -0,0 +1,61 @@
+"""
+Startup errors for Mindful Touch
+Typed reasons why detection could not start, each with a hint the user can act on
+"""
+
+import os
+import sys
+
+
+class StartupError(Exception):
+    """Detection failed to start; kind says why, str() is the short message"""
+
+    MODEL_MISSING = "model_missing"
+    CAMERA_NOT_FOUND = "camera_not_found"
+    CAMERA_PERMISSION = "camera_permission"
+    CAMERA_BUSY = "camera_busy"
+    TIMEOUT = "timeout"
+    UNKNOWN = "unknown"
+
+    HINTS = {
+        MODEL_MISSING: "The detection model could not be loaded. Reinstalling Mindful Touch usually fixes this.",
+        CAMERA_NOT_FOUND: "Check that a camera is connected, or pick another camera index in the settings.",
+        CAMERA_PERMISSION: "Allow camera access for Mindful Touch in your system's privacy settings, then try again.",
+        CAMERA_BUSY: "Another app seems to be using the camera. Close it, then try again.",
+        TIMEOUT: "The camera is taking too long to start. Unplug and reconnect it, or close other apps using it.",
+        UNKNOWN: "Check that no other app is using the camera, then try again.",
+    }
+
+    def __init__(self, kind: str, message: str):
+        super().__init__(message)
+        self.kind = kind
+
+    @property
+    def hint(self) -> str:
+        return self.HINTS.get(self.kind, self.HINTS[self.UNKNOWN])
+
+
+def camera_open_error(index: int) -> StartupError:
+    """Best guess at why cv2.VideoCapture(index) did not open"""
+    if sys.platform.startswith("linux"):
+        device = f"/dev/video{index}"
+        if not os.path.exists(device):
+            return StartupError(StartupError.CAMERA_NOT_FOUND, f"No camera found at {device}")
+        if not os.access(device, os.R_OK | os.W_OK):
+            return StartupError(StartupError.CAMERA_PERMISSION, f"No permission to open {device}")
+        return StartupError(StartupError.CAMERA_BUSY, f"Camera {index} is in use by another app")
+
+    if sys.platform == "darwin":
+        # macOS refuses to open the camera without telling us whether it was the permission prompt
+        return StartupError(StartupError.CAMERA_PERMISSION, f"Could not open camera {index} (camera access may be blocked)")
+
+    return StartupError(StartupError.CAMERA_NOT_FOUND, f"Could not open camera {index}")
+
+
+def from_exception(error: Exception) -> StartupError:
+    """Wrap anything raised while starting up"""
+    if isinstance(error, StartupError):
+        return error
+    if isinstance(error, PermissionError):
+        return StartupError(StartupError.CAMERA_PERMISSION, str(error))
+    return StartupError(StartupError.UNKNOWN, str(error))
@@ -0,0 +1,9 @@
+"""
+Detection history for Mindful Touch
+Every touch episode, kept in a local SQLite database for the dashboard's statistics
+"""
+
+from . import summary
+from .events import EventStore
+
+__all__ = ["EventStore", "summary"]
@@ -0,0 +1,111 @@
+"""
+Achievements for Mindful Touch
+Milestones worth celebrating (a first hour-long streak, a week of use, halving the touches), unlocked for good once reached
+"""
+
+import sqlite3
+import time
+from datetime import date, datetime, timedelta
+from typing import List, Optional
+
+from PyQt6.QtCore import QObject, pyqtSignal
+
+from .events import EventStore
+from .streaks import StreakTracker
+from .trends import daily_counts
+
+HOUR_STREAK = "hour_streak"
+WEEK_OF_USE = "week_of_use"
+HALVED_WEEK = "halved_week"
+
+# Id -> (title, description), in the order they're listed
+ACHIEVEMENTS = {
+    HOUR_STREAK: ("Hour of calm", "An hour without touching your face"),
+    WEEK_OF_USE: ("Week of awareness", "Mindful Touch ran on seven days in a row"),
+    HALVED_WEEK: ("Half as often", "Half as many touches in a week as in the week before"),
+}
+USAGE_DAYS = 7  # Days in a row for WEEK_OF_USE
+HALVED_MIN_TOUCHES = 10  # The week before needs at least this many touches for HALVED_WEEK to count
+
+
+class AchievementTracker(QObject):
+    """Checks the locked achievements against the history; unlocked carries {"id", "title", "description", "unlocked_at"} once for each"""
+
+    unlocked = pyqtSignal(dict)
+
+    def __init__(self, store: EventStore, streaks: StreakTracker, parent=None):
+        super().__init__(parent)
+        self.store = store
+        self.streaks = streaks
+        self.unlocked_at = self._load()
+
+    def _load(self) -> dict:
+        if self.store.db is None:
+            return {}
+        try:
+            return dict(self.store.db.execute("SELECT id, unlocked_at FROM achievements").fetchall())
+        except sqlite3.Error as e:
+            print(f"Could not read the achievements: {e}")
+            return {}
+
+    def refresh(self):
+        """Re-read what's unlocked, after the history was replaced"""
+        self.unlocked_at = self._load()
+
+    def achievements(self) -> List[dict]:
+        """Every achievement, unlocked_at None for those still to reach"""
+        return [
+            {"id": key, "title": title, "description": description, "unlocked_at": self.unlocked_at.get(key)}
+            for key, (title, description) in ACHIEVEMENTS.items()
+        ]
+
+    def check(self, _=None, now: Optional[float] = None) -> List[str]:
+        """Unlock whatever has been reached by now; returns the ids newly unlocked"""
+        if self.store.db is None:
+            return []
+        now = time.time() if now is None else now
+        today = datetime.fromtimestamp(now).date()
+        tests = {
+            HOUR_STREAK: lambda: self.streaks.status(now)["best"] >= 3600,
+            WEEK_OF_USE: lambda: self._used_days(today),
+            HALVED_WEEK: lambda: self._halved(today),
+        }
+        new = []
+        for key in ACHIEVEMENTS:
+            try:
+                reached = key not in self.unlocked_at and tests[key]()
+                if reached:
+                    with self.store.db:
+                        self.store.db.execute("INSERT INTO achievements (id, unlocked_at) VALUES (?, ?)", (key, now))
+            except sqlite3.Error as e:
+                print(f"Could not check achievement {key}: {e}")
+                continue
+            if reached:
+                self.unlocked_at[key] = now
+                new.append(key)
+                title, description = ACHIEVEMENTS[key]
+                self.unlocked.emit({"id": key, "title": title, "description": description, "unlocked_at": now})
+        return new
+
+    def _used_days(self, today: date) -> bool:
+        """Detection ran (a streak was recorded or a touch noticed, rolled up or not) on each of the USAGE_DAYS days up to today"""
+        first = today - timedelta(days=USAGE_DAYS - 1)
+        since = datetime(first.year, first.month, first.day).timestamp()
+        days = {
+            row[0]
+            for row in self.store.db.execute(
+                "SELECT date(started_at, 'unixepoch', 'localtime') FROM streaks WHERE started_at >= ? "
+                "UNION SELECT date(started_at, 'unixepoch', 'localtime') FROM events WHERE started_at >= ? "
+                "UNION SELECT day FROM daily_totals WHERE day >= ?",
+                (since, since, first.isoformat()),
+            )
+        }
+        if self.streaks.started is not None:
+            days.add(today.isoformat())
+        return all((first + timedelta(days=offset)).isoformat() in days for offset in range(USAGE_DAYS))
+
+    def _halved(self, today: date) -> bool:
+        """The last 7 days up to today had at most half the touches of the 7 before"""
+        counts = daily_counts(self.store, today - timedelta(days=13), 14)
+        before, after = sum(counts[:7]), sum(counts[7:])
+        return before >= HALVED_MIN_TOUCHES and after * 2 <= before
@@ -0,0 +1,87 @@
+"""
+Annotations for Mindful Touch
+Notes on a stretch of time ("stressful deadline", "forgot to take a break") with tags, kept next to the touch events they explain
+"""
+
+import sqlite3
+from typing import Iterable, List, Optional
+
+from .events import EventStore
+
+
+def clean_tags(tags: Iterable[str]) -> List[str]:
+    """Tags stripped and lowercased (commas becoming spaces), without blanks or repeats, sorted"""
+    return sorted({str(tag).replace(",", " ").strip().lower() for tag in tags} - {""})
+
+
+def add(store: EventStore, start: float, end: float, text: str, tags: Iterable[str] = ()) -> Optional[int]:
+    """Note text (and tags) on [start, end); returns its id, None if it couldn't be recorded
+
+    Raises ValueError for an empty text or a range that ends before it starts.
+    """
+    text = text.strip()
+    if not text:
+        raise ValueError("An annotation needs some text")
+    if end < start:
+        raise ValueError(f"The annotation ends ({end}) before it starts ({start})")
+    if store.db is None:
+        return None
+    try:
+        with store.db:
+            annotation_id = store.db.execute(
+                "INSERT INTO annotations (started_at, ended_at, text) VALUES (?, ?, ?)", (start, end, text)
+            ).lastrowid
+            store.db.executemany(
+                "INSERT INTO annotation_tags (annotation_id, tag) VALUES (?, ?)", [(annotation_id, tag) for tag in clean_tags(tags)]
+            )
+    except sqlite3.Error as e:
+        print(f"Could not record the annotation: {e}")
+        return None
+    return annotation_id
+
+
+def remove(store: EventStore, annotation_id: int) -> bool:
+    """Delete an annotation; returns whether there was one"""
+    if store.db is None:
+        return False
+    try:
+        with store.db:
+            return store.db.execute("DELETE FROM annotations WHERE id = ?", (annotation_id,)).rowcount > 0
+    except sqlite3.Error as e:
+        print(f"Could not delete the annotation: {e}")
+        return False
+
+
+def query(store: EventStore, since: Optional[float] = None, until: Optional[float] = None, tags: Optional[Iterable[str]] = None) -> List[dict]:
+    """Annotations {"id", "start", "end", "text", "tags"} overlapping [since, until) (None = open-ended), oldest first
+
+    tags keeps those with any of the given tags.
+    """
+    if store.db is None:
+        return []
+    clauses, params = [], []
+    if since is not None:
+        clauses.append("ended_at >= ?")
+        params.append(since)
+    if until is not None:
+        clauses.append("started_at < ?")
+        params.append(until)
+    if tags is not None:
+        tags = clean_tags(tags)
+        clauses.append(f"id IN (SELECT annotation_id FROM annotation_tags WHERE tag IN ({', '.join('?' * len(tags))}))")
+        params.extend(tags)
+    sql = (
+        "SELECT id, started_at, ended_at, text, (SELECT group_concat(tag) FROM annotation_tags WHERE annotation_id = annotations.id) "
+        "FROM annotations"
+    )
+    if clauses:
+        sql += " WHERE " + " AND ".join(clauses)
+    try:
+        rows = store.db.execute(sql + " ORDER BY started_at, id", params).fetchall()
+    except sqlite3.Error as e:
+        print(f"Could not read the annotations: {e}")
+        return []
+    return [
+        {"id": annotation_id, "start": start, "end": end, "text": text, "tags": sorted(tags.split(",")) if tags else []}
+        for annotation_id, start, end, text, tags in rows
+    ]
@@ -0,0 +1,129 @@
+"""
+Data backups for Mindful Touch
+The detection history (snapshotted with SQLite's backup API, not copied while open) and the settings in one zip, verified before restoring
+"""
+
+import hashlib
+import json
+import os
+import sqlite3
+import tempfile
+import zipfile
+from datetime import datetime
+from pathlib import Path
+from typing import List
+
+from .events import SCHEMA_VERSION, EventStore, migrate
+
+BACKUP_FORMAT = "mindful-touch-backup"  # Marks the manifest, with BACKUP_VERSION
+BACKUP_VERSION = 1
+MANIFEST = "manifest.json"
+HISTORY_NAME = "history.db"
+
+
+def _digest(data: bytes) -> str:
+    return hashlib.sha256(data).hexdigest()
+
+
+def backup(store: EventStore, path, files: List[Path]) -> List[str]:
+    """Write the history and these files (those that exist) to a zip at path; returns the names in it
+
+    Raises OSError if it can't be written and sqlite3.Error if the history can't be read.
+    """
+    if store.db is None:
+        raise sqlite3.DatabaseError("the detection history isn't open")
+    path = Path(path)
+    with tempfile.TemporaryDirectory() as folder:
+        snapshot = Path(folder) / HISTORY_NAME
+        target = sqlite3.connect(str(snapshot))
+        try:
+            store.db.backup(target)
+        finally:
+            target.close()
+        contents = {HISTORY_NAME: snapshot.read_bytes()}
+        contents.update((file.name, file.read_bytes()) for file in files if file.exists())
+
+    manifest = {
+        "format": BACKUP_FORMAT,
+        "version": BACKUP_VERSION,
+        "created": datetime.now().astimezone().isoformat(timespec="seconds"),
+        "schema_version": SCHEMA_VERSION,
+        "files": {name: _digest(data) for name, data in contents.items()},
+    }
+    # Written next to it first, so a failed backup never replaces a good one
+    temporary = path.with_name(path.name + ".tmp")
+    with zipfile.ZipFile(temporary, "w", zipfile.ZIP_DEFLATED) as archive:
+        archive.writestr(MANIFEST, json.dumps(manifest, indent=2))
+        for name, data in contents.items():
+            archive.writestr(name, data)
+    os.replace(temporary, path)
+    return sorted(contents)
+
+
+def _read(path: Path) -> dict:
+    """The verified contents of a backup, by name; raises OSError or ValueError"""
+    try:
+        with zipfile.ZipFile(path) as archive:
+            manifest = json.loads(archive.read(MANIFEST))
+            if not isinstance(manifest, dict) or manifest.get("format") != BACKUP_FORMAT:
+                raise ValueError("not a Mindful Touch backup")
+            if not isinstance(manifest.get("version"), int) or manifest["version"] > BACKUP_VERSION:
+                raise ValueError(f"made by a newer version of Mindful Touch (format {manifest.get('version')})")
+            files = manifest.get("files")
+            if not isinstance(files, dict) or HISTORY_NAME not in files:
+                raise ValueError("no history in the backup")
+            contents = {name: archive.read(name) for name in files}
+    except (KeyError, zipfile.BadZipFile, json.JSONDecodeError) as e:
+        raise ValueError(f"damaged backup: {e}") from e
+    damaged = [name for name, data in contents.items() if _digest(data) != files[name]]
+    if damaged:
+        raise ValueError(f"damaged backup: {', '.join(damaged)} changed since it was made")
+    return contents
+
+
+def restore(store: EventStore, path, files: List[Path]) -> List[str]:
+    """Put a backup's history into the store and its copies of these files (matched by name) back; returns the names restored
+
+    Nothing is touched unless the whole backup checks out: every file against the manifest's
+    checksum, the history with SQLite's integrity check. An older history is migrated.
+    Raises OSError if it can't be read or written, ValueError if it isn't a backup this version
+    can restore (or is damaged), and sqlite3.Error if the history can't be replaced.
+    """
+    if store.db is None:
+        raise sqlite3.DatabaseError("the detection history isn't open")
+    contents = _read(Path(path))
+    with tempfile.TemporaryDirectory() as folder:
+        snapshot = Path(folder) / HISTORY_NAME
+        snapshot.write_bytes(contents[HISTORY_NAME])
+        source = sqlite3.connect(str(snapshot))
+        try:
+            try:
+                check = source.execute("PRAGMA integrity_check").fetchone()[0]
+                version = source.execute("PRAGMA user_version").fetchone()[0]
+            except sqlite3.DatabaseError as e:
+                raise ValueError(f"damaged history in the backup: {e}") from e
+            if check != "ok":
+                raise ValueError(f"damaged history in the backup: {check}")
+            if version > SCHEMA_VERSION:
+                raise ValueError(f"history is schema version {version}, newer than this version's {SCHEMA_VERSION}")
+            for name in contents:
+                if name.endswith(".json"):
+                    try:
+                        json.loads(contents[name])
+                    except ValueError as e:
+                        raise ValueError(f"damaged {name} in the backup: {e}") from e
+
+            source.backup(store.db)
+        finally:
+            source.close()
+    migrate(store.db)
+
+    restored = [HISTORY_NAME]
+    for file in files:
+        if file.name in contents and file.name != HISTORY_NAME:
+            file.parent.mkdir(parents=True, exist_ok=True)
+            temporary = file.with_name(file.name + ".tmp")
+            temporary.write_bytes(contents[file.name])
+            os.replace(temporary, file)
+            restored.append(file.name)
+    return restored
@@ -0,0 +1,75 @@
+"""
+Baseline period for Mindful Touch
+The first days of use (or any N days) as the yardstick, so later days read as "-37% vs your first week"
+"""
+
+import sqlite3
+import time
+from datetime import date, datetime, timedelta
+from typing import Optional
+
+from .events import EventStore
+from .summary import _change, _totals
+
+
+def set_period(store: EventStore, days: int, start: Optional[date] = None) -> Optional[dict]:
+    """Make the days days from start (by default the day of the first recorded touch, or today) the baseline; returns period()
+
+    0 days clears it.
+    """
+    if store.db is None:
+        return None
+    days = max(0, int(days))
+    try:
+        with store.db:
+            store.db.execute("DELETE FROM baseline")
+            if days:
+                if start is None:
+                    first = store.db.execute("SELECT MIN(started_at) FROM events").fetchone()[0]
+                    start = date.today() if first is None else datetime.fromtimestamp(first).date()
+                since = datetime(start.year, start.month, start.day).timestamp()
+                store.db.execute("INSERT INTO baseline (id, started_at, days) VALUES (1, ?, ?)", (since, days))
+    except sqlite3.Error as e:
+        print(f"Could not set the baseline: {e}")
+        return None
+    return period(store)
+
+
+def period(store: EventStore, now: Optional[float] = None) -> Optional[dict]:
+    """{"start", "end", "days", "complete"} of the baseline, None if there's none"""
+    if store.db is None:
+        return None
+    try:
+        row = store.db.execute("SELECT started_at, days FROM baseline").fetchone()
+    except sqlite3.Error as e:
+        print(f"Could not read the baseline: {e}")
+        return None
+    if row is None:
+        return None
+    start, days = row
+    first = datetime.fromtimestamp(start)
+    end = (first + timedelta(days=days)).timestamp()  # Local midnight, whatever the clock changes on the way
+    return {"start": start, "end": end, "days": days, "complete": (time.time() if now is None else now) >= end}
+
+
+def daily_average(store: EventStore, now: Optional[float] = None) -> Optional[dict]:
+    """{"touches", "total_duration", "alerts"} per day over the baseline, once it's complete (None before, or without one)"""
+    baseline = period(store, now)
+    if baseline is None or not baseline["complete"]:
+        return None
+    totals = _totals(store, baseline["start"], baseline["end"])
+    return {key: value / baseline["days"] for key, value in totals.items()}
+
+
+def compare(store: EventStore, totals: dict, since: float, until: float, now: Optional[float] = None) -> Optional[dict]:
+    """How totals gathered over [since, until) compare with the baseline, per day: {"touches": -0.37, ...}
+
+    A period still in progress counts the days so far (fractions included); None without a
+    complete baseline or before the period began.
+    """
+    now = time.time() if now is None else now
+    average = daily_average(store, now)
+    days = (min(until, now) - since) / 86400
+    if average is None or days <= 0:
+        return None
+    return {key: _change(totals[key] / days, average[key]) for key in average}
@@ -0,0 +1,287 @@
+"""
+Touch event store for Mindful Touch
+Every touch episode in ~/.mindful-touch/history.db, with the schema created and migrated when it's opened
+"""
+
+import sqlite3
+from pathlib import Path
+from typing import Iterable, List, Optional
+
+HISTORY_PATH = Path.home() / ".mindful-touch" / "history.db"
+
+# A new database is created at version 1 with this
+SCHEMA = """
+    CREATE TABLE sessions (
+        id INTEGER PRIMARY KEY,
+        label TEXT NOT NULL,
+        started_at REAL NOT NULL,
+        ended_at REAL
+    );
+    CREATE TABLE events (
+        id INTEGER PRIMARY KEY,
+        started_at REAL NOT NULL,
+        ended_at REAL NOT NULL,
+        duration REAL NOT NULL,
+        confidence REAL NOT NULL,
+        peak_contacts INTEGER NOT NULL,
+        alerted INTEGER NOT NULL,
+        alerted_at REAL,
+        response TEXT,
+        session_id INTEGER REFERENCES sessions (id),
+        app TEXT
+    );
+    CREATE TABLE event_regions (
+        event_id INTEGER NOT NULL REFERENCES events (id) ON DELETE CASCADE,
+        region TEXT NOT NULL,
+        PRIMARY KEY (event_id, region)
+    );
+    CREATE INDEX events_by_start ON events (started_at);
+    CREATE INDEX events_by_session ON events (session_id);
+    CREATE INDEX event_regions_by_region ON event_regions (region);
+
+    CREATE TABLE streaks (
+        started_at REAL NOT NULL,
+        ended_at REAL NOT NULL,
+        duration REAL NOT NULL
+    );
+    CREATE INDEX streaks_by_duration ON streaks (duration);
+
+    CREATE TABLE daily_totals (
+        day TEXT NOT NULL,
+        region TEXT NOT NULL,
+        touches INTEGER NOT NULL,
+        duration REAL NOT NULL,
+        alerts INTEGER NOT NULL,
+        PRIMARY KEY (day, region)
+    );
+
+    CREATE TABLE goal_outcomes (
+        day TEXT NOT NULL,
+        kind TEXT NOT NULL,
+        target REAL NOT NULL,
+        value REAL NOT NULL,
+        achieved INTEGER,
+        PRIMARY KEY (day, kind, target)
+    );
+
+    CREATE TABLE achievements (
+        id TEXT PRIMARY KEY,
+        unlocked_at REAL NOT NULL
+    );
+
+    CREATE TABLE annotations (
+        id INTEGER PRIMARY KEY,
+        started_at REAL NOT NULL,
+        ended_at REAL NOT NULL,
+        text TEXT NOT NULL
+    );
+    CREATE TABLE annotation_tags (
+        annotation_id INTEGER NOT NULL REFERENCES annotations (id) ON DELETE CASCADE,
+        tag TEXT NOT NULL,
+        PRIMARY KEY (annotation_id, tag)
+    );
+    CREATE INDEX annotations_by_start ON annotations (started_at);
+
+    CREATE TABLE interventions (
+        started_at REAL NOT NULL,
+        ended_at REAL NOT NULL,
+        kind TEXT NOT NULL
+    );
+    CREATE INDEX interventions_by_start ON interventions (started_at);
+
+    CREATE TABLE baseline (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        started_at REAL NOT NULL,
+        days INTEGER NOT NULL
+    );
+"""
+
+# One script per schema version after that: a database at version N gets the scripts after the (N-1)th. Only ever append.
+MIGRATIONS: List[str] = []
+SCHEMA_VERSION = 1 + len(MIGRATIONS)
+
+EVENT_COLUMNS = "id, started_at, ended_at, duration, confidence, peak_contacts, alerted, alerted_at, response, session_id, app"
+
+
+def migrate(db: sqlite3.Connection) -> int:
+    """Bring the schema up to SCHEMA_VERSION, one transaction per version; returns the version it was at"""
+    version = db.execute("PRAGMA user_version").fetchone()[0]
+    if version > SCHEMA_VERSION:
+        raise sqlite3.DatabaseError(f"history is schema version {version}, newer than this version's {SCHEMA_VERSION}")
+    for number, script in enumerate([SCHEMA, *MIGRATIONS][version:], version + 1):
+        try:
+            db.executescript(f"BEGIN; {script} PRAGMA user_version = {number}; COMMIT;")
+        except sqlite3.Error:
+            if db.in_transaction:
+                db.rollback()
+            raise
+    return version
+
+
+class EventStore:
+    """Touch episodes on disk, as DetectionBridge.touch_episode reports them
+
+    An event: {"id", "start", "end", "duration", "regions", "confidence", "peak_contacts",
+    "alerted", "alerted_at", "response", "session_id", "app"}, app being the application in the
+    foreground at the time (None unless record_foreground_app is on). If the database can't be
+    opened, recording and queries do nothing.
+    """
+
+    def __init__(self, path: Path = HISTORY_PATH):
+        self.path = path
+        self.pending_responses = []  # (alert time, response) for episodes still in progress
+        self.session_id = None  # The work session (see sessions.py) new events belong to
+        self.db = self._open()
+
+    def _open(self) -> Optional[sqlite3.Connection]:
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            db = sqlite3.connect(str(self.path))
+            db.execute("PRAGMA foreign_keys = ON")
+            version = migrate(db)
+        except (OSError, sqlite3.Error) as e:
+            print(f"Could not open the detection history, not recording it: {e}")
+            return None
+        if version < SCHEMA_VERSION:
+            print(f"Detection history schema {version} -> {SCHEMA_VERSION}")
+        return db
+
+    def close(self):
+        if self.db is not None:
+            self.db.close()
+            self.db = None
+
+    def add(self, episode: dict) -> Optional[int]:
+        """Record a finished episode; returns its id"""
+        if self.db is None:
+            return None
+        response = self._take_response(episode["start"], episode["end"])
+        row = (
+            episode["start"],
+            episode["end"],
+            episode["duration"],
+            episode.get("confidence", 1.0),
+            episode.get("peak_contacts", 0),
+            int(episode["alerted"]),
+            episode.get("alerted_at"),
+            response,
+            self.session_id,
+            episode.get("app"),
+        )
+        try:
+            with self.db:
+                cursor = self.db.execute(
+                    "INSERT INTO events (started_at, ended_at, duration, confidence, peak_contacts, alerted, alerted_at, response, session_id, app) "
+                    "VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    row,
+                )
+                regions = [(cursor.lastrowid, region) for region in episode["regions"]]
+                self.db.executemany("INSERT INTO event_regions (event_id, region) VALUES (?, ?)", regions)
+        except sqlite3.Error as e:
+            print(f"Could not record touch episode: {e}")
+            return None
+        return cursor.lastrowid
+
+    def respond(self, response: str, alert_time: Optional[float]):
+        """The user's response to the alert that fired at alert_time; the first response to an episode counts"""
+        if self.db is None or alert_time is None:
+            return
+        try:
+            with self.db:
+                updated = self.db.execute(
+                    "UPDATE events SET response = ? WHERE response IS NULL AND id = "
+                    "(SELECT id FROM events WHERE started_at <= ? AND ended_at >= ? AND alerted ORDER BY started_at DESC LIMIT 1)",
+                    (response, alert_time, alert_time),
+                ).rowcount
+        except sqlite3.Error as e:
+            print(f"Could not record the response to an alert: {e}")
+            return
+        if not updated and not self._recorded(alert_time):
+            # The touch is still going on: attach the response once it ends
+            self.pending_responses.append((alert_time, response))
+
+    def _recorded(self, alert_time: float) -> bool:
+        row = self.db.execute("SELECT 1 FROM events WHERE started_at <= ? AND ended_at >= ?", (alert_time, alert_time)).fetchone()
+        return row is not None
+
+    def _take_response(self, start: float, end: float) -> Optional[str]:
+        """The first pending response to an alert during [start, end]; older ones are dropped, they'll never match"""
+        matching = [response for alert_time, response in self.pending_responses if start <= alert_time <= end]
+        self.pending_responses = [(alert_time, response) for alert_time, response in self.pending_responses if alert_time > end]
+        return matching[0] if matching else None
+
+    def query(
+        self,
+        since: Optional[float] = None,
+        until: Optional[float] = None,
+        regions: Optional[Iterable[str]] = None,
+        alerted: Optional[bool] = None,
+        responses: Optional[Iterable[Optional[str]]] = None,
+        session_id: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[dict]:
+        """Events that started in [since, until) (Unix times, None = open-ended), oldest first
+
+        regions keeps those touching any of the given regions, alerted those that did (or didn't)
+        alert, responses those with one of the given responses (None standing for no response),
+        session_id those of one work session, and limit only the most recent ones.
+        """
+        if self.db is None:
+            return []
+        clauses, params = [], []
+        if since is not None:
+            clauses.append("started_at >= ?")
+            params.append(since)
+        if until is not None:
+            clauses.append("started_at < ?")
+            params.append(until)
+        if regions is not None:
+            regions = list(regions)
+            clauses.append(f"id IN (SELECT event_id FROM event_regions WHERE region IN ({', '.join('?' * len(regions))}))")
+            params.extend(regions)
+        if alerted is not None:
+            clauses.append("alerted = ?")
+            params.append(int(alerted))
+        if responses is not None:
+            responses = list(responses)
+            given = [response for response in responses if response is not None]
+            matches = [f"response IN ({', '.join('?' * len(given))})"] if given else []
+            if None in responses:
+                matches.append("response IS NULL")
+            clauses.append(f"({' OR '.join(matches)})" if matches else "0")
+            params.extend(given)
+        if session_id is not None:
+            clauses.append("session_id = ?")
+            params.append(session_id)
+
+        sql = f"SELECT {EVENT_COLUMNS}, (SELECT group_concat(region) FROM event_regions WHERE event_id = events.id) FROM events"
+        if clauses:
+            sql += " WHERE " + " AND ".join(clauses)
+        sql += " ORDER BY started_at DESC, id DESC"
+        if limit is not None:
+            sql += " LIMIT ?"
+            params.append(int(limit))
+        try:
+            rows = self.db.execute(sql, params).fetchall()
+        except sqlite3.Error as e:
+            print(f"Could not read the detection history: {e}")
+            return []
+        return [_event(row) for row in reversed(rows)]
+
+
+def _event(row) -> dict:
+    event_id, start, end, duration, confidence, peak_contacts, alerted, alerted_at, response, session_id, app, regions = row
+    return {
+        "id": event_id,
+        "start": start,
+        "end": end,
+        "duration": duration,
+        "regions": sorted(regions.split(",")) if regions else [],
+        "confidence": confidence,
+        "peak_contacts": peak_contacts,
+        "alerted": bool(alerted),
+        "alerted_at": alerted_at,
+        "response": response,
+        "session_id": session_id,
+        "app": app,
+    }
@@ -0,0 +1,60 @@
+"""
+Detection history export for Mindful Touch
+The raw touch events as CSV or JSON, for spreadsheets or to share with a therapist
+"""
+
+import csv
+import json
+from datetime import datetime
+from pathlib import Path
+from typing import Optional
+
+from .events import EventStore
+
+FORMATS = ["csv", "json"]
+EXPORT_FORMAT = "mindful-touch-events"  # Marks a JSON export, with EXPORT_VERSION
+EXPORT_VERSION = 1
+
+# Only ever add to the end, so spreadsheets built on an export keep working
+COLUMNS = ["start", "end", "duration", "regions", "confidence", "peak_contacts", "alerted", "response", "app"]
+
+
+def _row(event: dict, origin: Optional[float]) -> dict:
+    """An event as exported; with an origin, start and end are seconds after it instead of local ISO 8601 times"""
+    if origin is None:
+        start, end = (datetime.fromtimestamp(event[key]).astimezone().isoformat(timespec="seconds") for key in ("start", "end"))
+    else:
+        start, end = round(event["start"] - origin, 3), round(event["end"] - origin, 3)
+    row = {key: event[key] for key in COLUMNS}
+    row.update(start=start, end=end, duration=round(event["duration"], 3))
+    return row
+
+
+def export_events(store: EventStore, fmt: str, path, since: Optional[float] = None, until: Optional[float] = None, relative: bool = False) -> int:
+    """Write the events that started in [since, until) to path; returns how many
+
+    relative leaves out when things happened: times become seconds after the first event
+    and the export date is omitted. In CSV, regions are separated by ";".
+    Raises ValueError for an unknown format and OSError if the file can't be written.
+    """
+    if fmt not in FORMATS:
+        raise ValueError(f"Unknown export format {fmt!r}, expected one of {', '.join(FORMATS)}")
+    events = store.query(since, until)
+    origin = events[0]["start"] if relative and events else None
+    rows = [_row(event, origin) for event in events]
+
+    path = Path(path)
+    if fmt == "json":
+        export = {"format": EXPORT_FORMAT, "version": EXPORT_VERSION, "relative_times": relative}
+        if not relative:
+            export["exported"] = datetime.now().astimezone().isoformat(timespec="seconds")
+        export.update(columns=COLUMNS, events=rows)
+        path.write_text(json.dumps(export, indent=2))
+    else:
+        with path.open("w", newline="") as out:
+            writer = csv.DictWriter(out, fieldnames=COLUMNS)
+            writer.writeheader()
+            for row in rows:
+                blanks = {key: row[key] or "" for key in ("response", "app")}
+                writer.writerow({**row, **blanks, "regions": ";".join(row["regions"]), "alerted": int(row["alerted"])})
+    return len(rows)
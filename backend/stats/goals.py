@@ -0,0 +1,178 @@
+"""
+Daily goals for Mindful Touch
+"Fewer than 20 touches today", "at least one 60-minute streak": checked as touches and streaks come in, with each day's outcome kept in the history
+"""
+
+import sqlite3
+import time
+from datetime import datetime
+from typing import List, Optional
+
+from PyQt6.QtCore import QObject, pyqtSignal
+
+from . import baseline
+from .events import EventStore
+from .streaks import StreakTracker
+
+MAX_TOUCHES = "max_touches"  # Fewer than target touch episodes today; only achieved once the day is over
+STREAK_MINUTES = "streak_minutes"  # At least one streak of target minutes today
+BASELINE_PERCENT = "baseline_percent"  # Target percent fewer touches today than the baseline's daily average; decided like MAX_TOUCHES
+KINDS = [MAX_TOUCHES, STREAK_MINUTES, BASELINE_PERCENT]
+
+# A touch goal's outcome, given MAX_TOUCHES and BASELINE_PERCENT: fewer touches than the target, or at least the percent fewer
+STAYED_UNDER = "((kind = ? AND value < target) OR (kind = ? AND value >= target))"
+
+# What a goal's progress says so far
+IN_PROGRESS = "in_progress"
+ACHIEVED = "achieved"
+MISSED = "missed"
+
+
+def clean(goal) -> Optional[dict]:
+    """A valid {"kind", "target"} goal, or None"""
+    if not isinstance(goal, dict) or goal.get("kind") not in KINDS:
+        return None
+    target = goal.get("target")
+    if isinstance(target, bool) or not isinstance(target, (int, float)) or target <= 0:
+        return None
+    if goal["kind"] == BASELINE_PERCENT and target > 100:
+        return None
+    return {"kind": goal["kind"], "target": target}
+
+
+def valid_goals(goals: list) -> List[dict]:
+    """The goals that clean() accepts, cleaned"""
+    return [goal for goal in (clean(goal) for goal in goals) if goal]
+
+
+class GoalTracker(QObject):
+    """Evaluates the day's goals against the history and the streak in progress
+
+    A goal's progress: {"kind", "target", "value", "status"}, value being today's touches,
+    longest streak in minutes or percent fewer touches than the baseline (None until there's a
+    complete baseline). progress carries all of them whenever a value changes, achieved a goal's
+    progress once it is reached (a touch goal when its day ends with the app running).
+    """
+
+    progress = pyqtSignal(list)
+    achieved = pyqtSignal(dict)
+
+    def __init__(self, store: EventStore, streaks: StreakTracker, parent=None):
+        super().__init__(parent)
+        self.store = store
+        self.streaks = streaks
+        self.goals = []
+        self.day = None  # The day last evaluated, ISO format
+        self.last = []  # Its progress as last emitted
+
+    def configure(self, goals: list, now: Optional[float] = None):
+        self.goals = valid_goals(goals)
+        self.evaluate(now=now)
+
+    def evaluate(self, _=None, now: Optional[float] = None) -> List[dict]:
+        """Today's progress on every goal, recorded and announced if it changed"""
+        now = time.time() if now is None else now
+        midnight = datetime.fromtimestamp(now).replace(hour=0, minute=0, second=0, microsecond=0)
+        day = midnight.date().isoformat()
+        if day != self.day:
+            self._finish_days(day)
+            self.day, self.last = day, []
+
+        touches, streak = self._today(midnight.timestamp(), now)
+        average = baseline.daily_average(self.store, now) if any(goal["kind"] == BASELINE_PERCENT for goal in self.goals) else None
+        progress = []
+        for goal in self.goals:
+            if goal["kind"] == MAX_TOUCHES:
+                value, status = touches, MISSED if touches >= goal["target"] else IN_PROGRESS
+            elif goal["kind"] == BASELINE_PERCENT:
+                # Only goes down as the day's touches come in
+                value = round((1 - touches / average["touches"]) * 100, 1) if average and average["touches"] else None
+                status = MISSED if value is not None and value < goal["target"] else IN_PROGRESS
+            else:
+                value = round(streak / 60, 1)
+                status = ACHIEVED if value >= goal["target"] else IN_PROGRESS
+            progress.append({**goal, "value": value, "status": status})
+
+        if progress != self.last:
+            reached_before = {(entry["kind"], entry["target"]) for entry in self.last if entry["status"] == ACHIEVED}
+            self.last = progress
+            self._record(day, progress)
+            self.progress.emit(progress)
+            for entry in progress:
+                if entry["status"] == ACHIEVED and (entry["kind"], entry["target"]) not in reached_before:
+                    self.achieved.emit(entry)
+        return progress
+
+    def _today(self, midnight: float, now: float):
+        """(touch episodes since midnight, longest streak since midnight in seconds)"""
+        touches, streak = 0, 0.0
+        if self.store.db is not None:
+            try:
+                touches = self.store.db.execute("SELECT COUNT(*) FROM events WHERE started_at >= ?", (midnight,)).fetchone()[0]
+                streak = self.store.db.execute(
+                    "SELECT COALESCE(MAX(ended_at - MAX(started_at, ?)), 0) FROM streaks WHERE ended_at > ?", (midnight, midnight)
+                ).fetchone()[0]
+            except sqlite3.Error as e:
+                print(f"Could not read today's history for the goals: {e}")
+        if self.streaks.started is not None:
+            streak = max(streak, now - max(self.streaks.started, midnight))
+        return touches, streak
+
+    def _record(self, day: str, progress: List[dict]):
+        if self.store.db is None:
+            return
+        # A touch goal is only decided when the day is over
+        rows = [
+            (day, entry["kind"], entry["target"], entry["value"], {ACHIEVED: 1, MISSED: 0}.get(entry["status"]))
+            for entry in progress
+            if entry["value"] is not None
+        ]
+        try:
+            with self.store.db:
+                self.store.db.executemany(
+                    "INSERT INTO goal_outcomes (day, kind, target, value, achieved) VALUES (?, ?, ?, ?, ?) "
+                    "ON CONFLICT (day, kind, target) DO UPDATE SET value = excluded.value, achieved = excluded.achieved",
+                    rows,
+                )
+        except sqlite3.Error as e:
+            print(f"Could not record goal progress: {e}")
+
+    def _finish_days(self, today: str):
+        """Settle the goals of the days before today: touch goals that stayed under target were achieved, the rest missed
+
+        Those of the day just evaluated (not of days the app wasn't running at the end of) are announced.
+        """
+        if self.store.db is None:
+            return
+        rows = []
+        try:
+            with self.store.db:
+                if self.day is not None and self.day < today:
+                    rows = self.store.db.execute(
+                        f"SELECT kind, target, value FROM goal_outcomes WHERE day = ? AND achieved IS NULL AND {STAYED_UNDER}",
+                        (self.day, MAX_TOUCHES, BASELINE_PERCENT),
+                    ).fetchall()
+                self.store.db.execute(
+                    f"UPDATE goal_outcomes SET achieved = {STAYED_UNDER} WHERE day < ? AND achieved IS NULL", (MAX_TOUCHES, BASELINE_PERCENT, today)
+                )
+        except sqlite3.Error as e:
+            print(f"Could not settle the goals: {e}")
+            return
+        for kind, target, value in rows:
+            self.achieved.emit({"kind": kind, "target": target, "value": value, "status": ACHIEVED})
+
+    def outcomes(self, since_day: Optional[str] = None) -> List[dict]:
+        """Recorded daily outcomes {"day", "kind", "target", "value", "achieved"} (achieved None while undecided), oldest first"""
+        if self.store.db is None:
+            return []
+        try:
+            rows = self.store.db.execute(
+                "SELECT day, kind, target, value, achieved FROM goal_outcomes WHERE day >= ? ORDER BY day, kind, target", (since_day or "",)
+            ).fetchall()
+        except sqlite3.Error as e:
+            print(f"Could not read the goal outcomes: {e}")
+            return []
+        return [
+            {"day": day, "kind": kind, "target": target, "value": value, "achieved": None if achieved is None else bool(achieved)}
+            for day, kind, target, value, achieved in rows
+        ]
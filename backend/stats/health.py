@@ -0,0 +1,120 @@
+"""
+Health app export for Mindful Touch
+Completed breathing exercises as mindful sessions, and daily touch summaries, in files Apple Health and Google Fit importers take
+"""
+
+import json
+import sqlite3
+import time
+from datetime import datetime
+from pathlib import Path
+from typing import List, Optional
+
+from .events import EventStore
+from .retention import TOTAL
+
+BREATHING = "breathing"  # The only intervention so far: a breathing exercise run to the end
+
+HEALTHKIT = "healthkit"  # A file of HealthKit samples for an iPhone companion or Shortcut to add to Apple Health
+GOOGLE_FIT = "google_fit"  # A Google Fit REST dataset (users.dataSources.datasets patch body)
+TARGETS = [HEALTHKIT, GOOGLE_FIT]
+EXPORT_FORMAT = "mindful-touch-health"  # Marks a HEALTHKIT file, with EXPORT_VERSION
+EXPORT_VERSION = 1
+
+MINDFUL_SESSION = "HKCategoryTypeIdentifierMindfulSession"
+FIT_ACTIVITY_SEGMENT = "com.google.activity.segment"
+FIT_MEDITATION = 45  # Google Fit's activity type for meditation
+FIT_DATA_SOURCE = f"raw:{FIT_ACTIVITY_SEGMENT}:mindful-touch:{BREATHING}"
+
+
+def record_intervention(store: EventStore, kind: str, duration: float, now: Optional[float] = None):
+    """Note an intervention that just ended after duration seconds"""
+    if store.db is None:
+        return
+    now = time.time() if now is None else now
+    try:
+        with store.db:
+            store.db.execute("INSERT INTO interventions (started_at, ended_at, kind) VALUES (?, ?, ?)", (now - duration, now, kind))
+    except sqlite3.Error as e:
+        print(f"Could not record the {kind} exercise: {e}")
+
+
+def interventions(store: EventStore, since: Optional[float] = None) -> List[dict]:
+    """{"start", "end", "kind"} of the interventions from since on, oldest first"""
+    if store.db is None:
+        return []
+    try:
+        rows = store.db.execute(
+            "SELECT started_at, ended_at, kind FROM interventions WHERE started_at >= ? ORDER BY started_at", (since or 0,)
+        ).fetchall()
+    except sqlite3.Error as e:
+        print(f"Could not read the interventions: {e}")
+        return []
+    return [{"start": start, "end": end, "kind": kind} for start, end, kind in rows]
+
+
+def daily_summaries(store: EventStore, since: Optional[float] = None) -> List[dict]:
+    """{"date", "touches", "total_duration", "alerts"} of each local day from since on with touches, rolled-up days included"""
+    if store.db is None:
+        return []
+    try:
+        rows = store.db.execute(
+            "SELECT day, SUM(touches), SUM(duration), SUM(alerts) FROM ("
+            "SELECT date(started_at, 'unixepoch', 'localtime') AS day, COUNT(*) AS touches, SUM(duration) AS duration, SUM(alerted) AS alerts "
+            "FROM events WHERE started_at >= ? GROUP BY day "
+            "UNION ALL SELECT day, touches, duration, alerts FROM daily_totals WHERE region = ? AND day >= date(?, 'unixepoch', 'localtime')"
+            ") GROUP BY day ORDER BY day",
+            (since or 0, TOTAL, since or 0),
+        ).fetchall()
+    except sqlite3.Error as e:
+        print(f"Could not summarize the detection history: {e}")
+        return []
+    return [{"date": day, "touches": touches, "total_duration": duration, "alerts": alerts} for day, touches, duration, alerts in rows]
+
+
+def _iso(timestamp: float) -> str:
+    return datetime.fromtimestamp(timestamp).astimezone().isoformat(timespec="seconds")
+
+
+def _nanos(timestamp: float) -> int:
+    return int(round(timestamp * 1e9))
+
+
+def export_health(store: EventStore, target: str, path, since: Optional[float] = None, now: Optional[float] = None) -> int:
+    """Write the interventions (and, for HEALTHKIT, the daily summaries) from since on to path; returns how many entries
+
+    Raises ValueError for an unknown target and OSError if the file can't be written.
+    """
+    if target not in TARGETS:
+        raise ValueError(f"Unknown health export target {target!r}, expected one of {', '.join(TARGETS)}")
+    sessions = interventions(store, since)
+    if target == HEALTHKIT:
+        summaries = daily_summaries(store, since)
+        export = {
+            "format": EXPORT_FORMAT,
+            "version": EXPORT_VERSION,
+            "exported": _iso(time.time() if now is None else now),
+            "samples": [
+                {"type": MINDFUL_SESSION, "start": _iso(entry["start"]), "end": _iso(entry["end"]), "metadata": {"kind": entry["kind"]}}
+                for entry in sessions
+            ],
+            # Health has no touch count type: an importer keeps these as its own summary, or leaves them
+            "daily_summaries": summaries,
+        }
+        count = len(sessions) + len(summaries)
+    else:
+        points = [
+            {
+                "startTimeNanos": _nanos(entry["start"]),
+                "endTimeNanos": _nanos(entry["end"]),
+                "dataTypeName": FIT_ACTIVITY_SEGMENT,
+                "value": [{"intVal": FIT_MEDITATION}],
+            }
+            for entry in sessions
+        ]
+        first = min([entry["start"] for entry in sessions], default=since or 0)
+        last = max([entry["end"] for entry in sessions], default=first)
+        export = {"dataSourceId": FIT_DATA_SOURCE, "minStartTimeNs": _nanos(first), "maxEndTimeNs": _nanos(last), "point": points}
+        count = len(points)
+    Path(path).write_text(json.dumps(export, indent=2))
+    return count
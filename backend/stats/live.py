@@ -0,0 +1,41 @@
+"""
+Live stats for Mindful Touch
+The numbers a live dashboard shows, worked out together so one stats_tick carries all of them
+"""
+
+import sqlite3
+import time
+from datetime import datetime
+from typing import Optional
+
+from . import sessions
+from .events import EventStore
+from .streaks import StreakTracker
+
+
+def touches_since(store: EventStore, since: float) -> int:
+    if store.db is None:
+        return 0
+    try:
+        return store.db.execute("SELECT COUNT(*) FROM events WHERE started_at >= ?", (since,)).fetchone()[0]
+    except sqlite3.Error as e:
+        print(f"Could not count touches: {e}")
+        return 0
+
+
+def snapshot(store: EventStore, streaks: StreakTracker, now: Optional[float] = None) -> dict:
+    """{"time", "touches_this_hour", "touches_today", "streak", "work_session"}
+
+    this hour and today go by the clock (since :00, since midnight); streak is
+    StreakTracker.status() and work_session the summary of the session in progress, or None.
+    """
+    now = time.time() if now is None else now
+    hour = datetime.fromtimestamp(now).replace(minute=0, second=0, microsecond=0)
+    session_id = store.session_id
+    return {
+        "time": now,
+        "touches_this_hour": touches_since(store, hour.timestamp()),
+        "touches_today": touches_since(store, hour.replace(hour=0).timestamp()),
+        "streak": streaks.status(now),
+        "work_session": None if session_id is None else sessions.summary(store, session_id, now),
+    }
@@ -0,0 +1,153 @@
+"""
+Weekly reports for Mindful Touch
+A week of the detection history and its notes as a self-contained HTML page (print it to PDF from the browser) to share with a clinician
+"""
+
+import sqlite3
+import time
+from datetime import date, datetime, timedelta
+from html import escape
+from pathlib import Path
+from typing import Optional
+
+from backend.i18n import tr
+
+from . import sessions
+from .events import EventStore
+from .summary import summarize
+from .trends import daily_counts
+
+STYLE = """
+body { font-family: -apple-system, "Segoe UI", sans-serif; color: #222; max-width: 46em; margin: 2em auto; padding: 0 1em; }
+h1 { font-size: 1.5em; margin-bottom: 0; }
+h2 { font-size: 1.1em; margin-top: 1.8em; border-bottom: 1px solid #ddd; }
+.totals { display: flex; gap: 2em; }
+.totals strong { display: block; font-size: 1.6em; }
+.totals span, .muted { color: #777; font-size: 0.9rem; font-weight: normal; }
+table { border-collapse: collapse; width: 100%; }
+td, th { text-align: left; padding: 0.25em 0.5em; border-bottom: 1px solid #eee; }
+td.bar { width: 60%; }
+td.bar div { background: #4a90d9; height: 0.9em; border-radius: 2px; }
+* { -webkit-print-color-adjust: exact; print-color-adjust: exact; }
+@page { size: A4; margin: 15mm; }
+@media print { body { margin: 0; max-width: none; } }
+"""
+
+
+def week_start(day: Optional[date] = None) -> date:
+    """The Monday of the week holding day (today by default)"""
+    day = day or date.today()
+    return day - timedelta(days=day.weekday())
+
+
+def _duration(seconds: float) -> str:
+    seconds = int(round(seconds))
+    if seconds >= 3600:
+        return f"{seconds // 3600}h {seconds % 3600 // 60:02d}m"
+    if seconds >= 60:
+        return f"{seconds // 60}m {seconds % 60:02d}s"
+    return f"{seconds}s"
+
+
+def _change(change: Optional[float]) -> str:
+    if change is None:
+        return ""
+    return tr("{change} on the week before", change=f"{change:+.0%}")
+
+
+def _bars(rows) -> str:
+    """rows of (label, value, shown) as a table of bars scaled to the largest value"""
+    largest = max([value for _, value, _ in rows] + [1])
+    cells = "".join(
+        f'<tr><td>{escape(label)}</td><td class="bar"><div style="width: {value / largest:.0%}"></div></td><td>{escape(shown)}</td></tr>'
+        for label, value, shown in rows
+    )
+    return f"<table>{cells}</table>"
+
+
+def render(store: EventStore, week: Optional[date] = None, now: Optional[float] = None) -> str:
+    """The report of the week holding week (this week by default) as an HTML page"""
+    first = week_start(week)
+    monday = datetime(first.year, first.month, first.day).timestamp()
+    sunday = first + timedelta(days=6)
+    current = summarize(store, "week", now=monday)
+    before = summarize(store, "week", now=monday - 86400)
+    days = daily_counts(store, first, 7)
+
+    longest = 0.0
+    if store.db is not None:
+        try:
+            longest = store.db.execute(
+                "SELECT COALESCE(MAX(duration), 0) FROM streaks WHERE started_at >= ? AND started_at < ?", (monday, current["end"])
+            ).fetchone()[0]
+        except sqlite3.Error as e:
+            print(f"Could not read the streaks: {e}")
+    week_sessions = [session for session in sessions.list_sessions(store) if monday <= session["start"] < current["end"]]
+
+    parts = [
+        f"<h1>{escape(tr('Mindful Touch weekly report'))}</h1>",
+        f'<p class="muted">{escape(tr("Week of {start} to {end}", start=first.isoformat(), end=sunday.isoformat()))}</p>',
+        '<div class="totals">',
+    ]
+    for key, label, shown in (
+        ("touches", tr("Touches"), str(current["touches"])),
+        ("total_duration", tr("Time touching"), _duration(current["total_duration"])),
+        ("alerts", tr("Alerts"), str(current["alerts"])),
+    ):
+        parts.append(f"<p>{escape(label)}<strong>{escape(shown)}</strong><span>{escape(_change(current['change'][key]))}</span></p>")
+    parts.append("</div>")
+
+    parts.append(f"<h2>{escape(tr('Touches per day'))}</h2>")
+    parts.append(_bars([((first + timedelta(days=index)).isoformat(), count, str(count)) for index, count in enumerate(days)]))
+
+    parts.append(f"<h2>{escape(tr('Regions'))}</h2>")
+    if current["regions"]:
+        header = "".join(f"<th>{escape(text)}</th>" for text in (tr("Region"), tr("Touches"), tr("Time touching"), tr("Week before")))
+        rows = "".join(
+            f"<tr><td>{escape(region)}</td><td>{totals['touches']}</td><td>{escape(_duration(totals['duration']))}</td>"
+            f"<td>{before['regions'].get(region, {}).get('touches', 0)}</td></tr>"
+            for region, totals in current["regions"].items()
+        )
+        parts.append(f"<table><tr>{header}</tr>{rows}</table>")
+    else:
+        parts.append(f'<p class="muted">{escape(tr("No touches recorded"))}</p>')
+
+    parts.append(f"<h2>{escape(tr('Streaks'))}</h2>")
+    parts.append(f"<p>{escape(tr('Longest clean streak'))}: {escape(_duration(longest))}</p>")
+    if current["busiest_hours"]:
+        hours = ", ".join(f"{hour:02d}:00 ({touches})" for hour, touches in current["busiest_hours"])
+        parts.append(f"<p>{escape(tr('Busiest hours'))}: {escape(hours)}</p>")
+
+    if week_sessions:
+        parts.append(f"<h2>{escape(tr('Work sessions'))}</h2>")
+        rows = []
+        for session in reversed(week_sessions):
+            details = sessions.summary(store, session["id"], now) or {}
+            started = datetime.fromtimestamp(session["start"])
+            rows.append(
+                f"<tr><td>{started:%Y-%m-%d %H:%M}</td><td>{escape(session['label'])}</td>"
+                f"<td>{escape(_duration(details.get('duration', 0)))}</td><td>{details.get('touches', 0)}</td></tr>"
+            )
+        parts.append(f"<table>{''.join(rows)}</table>")
+
+    if current["annotations"]:
+        parts.append(f"<h2>{escape(tr('Notes'))}</h2>")
+        rows = []
+        for note in current["annotations"]:
+            start, end = datetime.fromtimestamp(note["start"]), datetime.fromtimestamp(note["end"])
+            when = f"{start:%Y-%m-%d %H:%M}" + (f"–{end:%H:%M}" if end.date() == start.date() else f" – {end:%Y-%m-%d %H:%M}")
+            rows.append(f"<tr><td>{when}</td><td>{escape(note['text'])}</td><td>{escape(', '.join(note['tags']))}</td></tr>")
+        parts.append(f"<table>{''.join(rows)}</table>")
+
+    generated = datetime.fromtimestamp(time.time() if now is None else now)
+    parts.append(f'<p class="muted">{escape(tr("Generated {time}", time=f"{generated:%Y-%m-%d %H:%M}"))}</p>')
+    title = escape(tr("Mindful Touch weekly report"))
+    body = "\n".join(parts)
+    return f'<!DOCTYPE html>\n<html><head><meta charset="utf-8"><title>{title}</title><style>{STYLE}</style></head><body>\n{body}\n</body></html>\n'
+
+
+def generate_report(store: EventStore, path, week: Optional[date] = None, now: Optional[float] = None) -> Path:
+    """Write the week's report to path; raises OSError if the file can't be written"""
+    path = Path(path)
+    path.write_text(render(store, week, now), encoding="utf-8")
+    return path
@@ -0,0 +1,70 @@
+"""
+Detection history retention for Mindful Touch
+Rolls touch events past the history_days setting up into daily totals, and reports how much space the history takes
+"""
+
+import sqlite3
+import time
+from datetime import datetime, timedelta
+from typing import Optional
+
+from .events import EventStore
+
+TOTAL = ""  # The daily_totals region of a day's totals over all regions (an episode on two regions counts once)
+
+
+def cutoff(keep_days: int, now: Optional[float] = None) -> float:
+    """Local midnight keep_days days ago: events before it are rolled up, so whole days go at once"""
+    today = datetime.fromtimestamp(time.time() if now is None else now).replace(hour=0, minute=0, second=0, microsecond=0)
+    return (today - timedelta(days=keep_days)).timestamp()
+
+
+def prune(store: EventStore, keep_days: int, now: Optional[float] = None) -> int:
+    """Add the events from before cutoff() to their days' totals and delete them; returns how many went (0 with keep_days 0)"""
+    if keep_days <= 0 or store.db is None:
+        return 0
+    before = cutoff(keep_days, now)
+    day = "date(started_at, 'unixepoch', 'localtime')"
+    add = (
+        "ON CONFLICT (day, region) DO UPDATE SET "
+        "touches = touches + excluded.touches, duration = duration + excluded.duration, alerts = alerts + excluded.alerts"
+    )
+    try:
+        with store.db:
+            store.db.execute(
+                "INSERT INTO daily_totals (day, region, touches, duration, alerts) "
+                f"SELECT {day} AS day, ?, COUNT(*), SUM(duration), SUM(alerted) FROM events WHERE started_at < ? GROUP BY day {add}",
+                (TOTAL, before),
+            )
+            store.db.execute(
+                "INSERT INTO daily_totals (day, region, touches, duration, alerts) "
+                f"SELECT {day} AS day, region, COUNT(*), SUM(duration), SUM(alerted) FROM events JOIN event_regions ON events.id = event_regions.event_id "
+                f"WHERE started_at < ? GROUP BY day, region {add}",
+                (before,),
+            )
+            pruned = store.db.execute("DELETE FROM events WHERE started_at < ?", (before,)).rowcount
+    except sqlite3.Error as e:
+        print(f"Could not prune the detection history: {e}")
+        return 0
+    if pruned:
+        print(f"Rolled {pruned} touch events from before {datetime.fromtimestamp(before):%Y-%m-%d} up into daily totals")
+    return pruned
+
+
+def storage_usage(store: EventStore) -> dict:
+    """{"bytes", "events", "oldest_event", "rolled_up_days", "oldest_day"}: the database's size on disk and what it holds"""
+    usage = {"bytes": 0, "events": 0, "oldest_event": None, "rolled_up_days": 0, "oldest_day": None}
+    for path in (store.path, store.path.with_name(store.path.name + "-journal"), store.path.with_name(store.path.name + "-wal")):
+        try:
+            usage["bytes"] += path.stat().st_size
+        except OSError:
+            continue
+    if store.db is None:
+        return usage
+    try:
+        usage["events"], usage["oldest_event"] = store.db.execute("SELECT COUNT(*), MIN(started_at) FROM events").fetchone()
+        rolled_up = store.db.execute("SELECT COUNT(*), MIN(day) FROM daily_totals WHERE region = ?", (TOTAL,)).fetchone()
+        usage["rolled_up_days"], usage["oldest_day"] = rolled_up
+    except sqlite3.Error as e:
+        print(f"Could not read the detection history: {e}")
+    return usage
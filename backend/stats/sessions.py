@@ -0,0 +1,100 @@
+"""
+Work sessions for Mindful Touch
+Labeled blocks of time the user brackets themselves; touch events recorded meanwhile belong to them
+"""
+
+import sqlite3
+import time
+from typing import List, Optional
+
+from .events import EventStore
+
+
+def start(store: EventStore, label: str, now: Optional[float] = None) -> Optional[int]:
+    """Begin a session (ending the one in progress); returns its id"""
+    if store.db is None:
+        return None
+    now = time.time() if now is None else now
+    end(store, now)
+    try:
+        with store.db:
+            store.session_id = store.db.execute("INSERT INTO sessions (label, started_at) VALUES (?, ?)", (label.strip(), now)).lastrowid
+    except sqlite3.Error as e:
+        print(f"Could not start the session: {e}")
+        return None
+    return store.session_id
+
+
+def end(store: EventStore, now: Optional[float] = None) -> Optional[int]:
+    """End the session in progress; returns its id (None if there was none)"""
+    session_id, store.session_id = store.session_id, None
+    if session_id is None or store.db is None:
+        return None
+    try:
+        with store.db:
+            store.db.execute("UPDATE sessions SET ended_at = ? WHERE id = ?", (time.time() if now is None else now, session_id))
+    except sqlite3.Error as e:
+        print(f"Could not end the session: {e}")
+    return session_id
+
+
+def close_abandoned(store: EventStore):
+    """Sessions left open by a crash end with their last event (or where they began)"""
+    if store.db is None:
+        return
+    try:
+        with store.db:
+            store.db.execute(
+                "UPDATE sessions SET ended_at = COALESCE((SELECT MAX(ended_at) FROM events WHERE session_id = sessions.id), started_at) "
+                "WHERE ended_at IS NULL"
+            )
+    except sqlite3.Error as e:
+        print(f"Could not close abandoned sessions: {e}")
+
+
+def list_sessions(store: EventStore) -> List[dict]:
+    """{"id", "label", "start", "end"} of every session, the latest first; end is None for the one in progress"""
+    if store.db is None:
+        return []
+    try:
+        rows = store.db.execute("SELECT id, label, started_at, ended_at FROM sessions ORDER BY started_at DESC").fetchall()
+    except sqlite3.Error as e:
+        print(f"Could not read the sessions: {e}")
+        return []
+    return [{"id": session_id, "label": label, "start": started, "end": ended} for session_id, label, started, ended in rows]
+
+
+def summary(store: EventStore, session_id: int, now: Optional[float] = None) -> Optional[dict]:
+    """{"id", "label", "start", "end", "duration", "touches", "alerts", "average_response", "longest_streak"}, None if there's no such session
+
+    average_response is how long the hand stayed after an alert, on average (None without
+    alerts); longest_streak the longest stretch of the session without an alert.
+    """
+    if store.db is None:
+        return None
+    try:
+        row = store.db.execute("SELECT label, started_at, ended_at FROM sessions WHERE id = ?", (session_id,)).fetchone()
+    except sqlite3.Error as e:
+        print(f"Could not read the session: {e}")
+        return None
+    if row is None:
+        return None
+    label, started, ended = row
+    until = (time.time() if now is None else now) if ended is None else ended
+    events = store.query(session_id=session_id)
+
+    alerted = [event for event in events if event["alerted"]]
+    responses = [event["end"] - event["alerted_at"] for event in alerted if event["alerted_at"] is not None]
+    # Streaks run between alerts, and from the start and to the end of the session
+    marks = [started] + [event["alerted_at"] or event["start"] for event in alerted] + [until]
+    return {
+        "id": session_id,
+        "label": label,
+        "start": started,
+        "end": ended,
+        "duration": until - started,
+        "touches": len(events),
+        "alerts": len(alerted),
+        "average_response": sum(responses) / len(responses) if responses else None,
+        "longest_streak": max(later - earlier for earlier, later in zip(marks, marks[1:])),
+    }
@@ -0,0 +1,89 @@
+"""
+Clean streaks for Mindful Touch
+How long it has been since the last touch while detecting, and the best streaks so far, kept in the history database
+"""
+
+import sqlite3
+import time
+from typing import Optional
+
+from PyQt6.QtCore import QObject, QTimer, pyqtSignal
+
+from backend.detection.config import Config
+
+from .events import EventStore
+
+
+class StreakTracker(QObject):
+    """The streak in progress runs from the start of detection (or the last touch) to the next touch or the end of detection
+
+    Finished streaks go into the history's streaks table. While detecting, updated carries
+    {"current", "best", "best_at"} once every Config.STREAK_UPDATE_INTERVAL seconds, and
+    whenever a streak starts or ends; current is None while not detecting.
+    """
+
+    updated = pyqtSignal(dict)
+
+    def __init__(self, store: EventStore, parent=None):
+        super().__init__(parent)
+        self.store = store
+        self.started = None  # When the streak in progress began
+        self.best, self.best_at = self._load_best()
+
+        self.timer = QTimer(self)
+        self.timer.timeout.connect(lambda: self.updated.emit(self.status()))
+
+    def _load_best(self):
+        """The longest recorded streak: (seconds, when it began), (0, None) if there's none"""
+        if self.store.db is None:
+            return 0.0, None
+        try:
+            row = self.store.db.execute("SELECT duration, started_at FROM streaks ORDER BY duration DESC LIMIT 1").fetchone()
+        except sqlite3.Error as e:
+            print(f"Could not read the best streak: {e}")
+            return 0.0, None
+        return row if row else (0.0, None)
+
+    def refresh(self):
+        """Re-read the best streak, after the history was replaced"""
+        self.best, self.best_at = self._load_best()
+
+    def start(self, now: Optional[float] = None):
+        self.started = time.time() if now is None else now
+        self.timer.start(int(Config.STREAK_UPDATE_INTERVAL * 1000))
+        self.updated.emit(self.status(self.started))
+
+    def touched(self, now: Optional[float] = None):
+        """A touch ended the streak; the next one starts right away"""
+        if self.started is None:
+            return
+        now = time.time() if now is None else now
+        self._finish(now)
+        self.started = now
+        self.updated.emit(self.status(now))
+
+    def stop(self, now: Optional[float] = None):
+        if self.started is None:
+            return
+        self.timer.stop()
+        self._finish(time.time() if now is None else now)
+        self.started = None
+        self.updated.emit(self.status())
+
+    def status(self, now: Optional[float] = None) -> dict:
+        current = None if self.started is None else (time.time() if now is None else now) - self.started
+        if current is not None and current > self.best:
+            return {"current": current, "best": current, "best_at": self.started}
+        return {"current": current, "best": self.best, "best_at": self.best_at}
+
+    def _finish(self, now: float):
+        duration = now - self.started
+        if duration > self.best:
+            self.best, self.best_at = duration, self.started
+        if self.store.db is None:
+            return
+        try:
+            with self.store.db:
+                self.store.db.execute("INSERT INTO streaks (started_at, ended_at, duration) VALUES (?, ?, ?)", (self.started, now, duration))
+        except sqlite3.Error as e:
+            print(f"Could not record the streak: {e}")
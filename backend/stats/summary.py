@@ -0,0 +1,132 @@
+"""
+Detection history summaries for Mindful Touch
+Touch counts, durations, regions and busiest hours per day, week or month, and the hour-by-region heatmap, worked out in SQL
+"""
+
+import sqlite3
+import time
+from datetime import datetime, timedelta
+from typing import Optional, Tuple
+
+from . import annotations
+from .events import EventStore
+from .retention import TOTAL
+
+PERIODS = ["day", "week", "month"]
+BUSIEST_HOURS = 3  # Hours of the day listed in a summary, busiest first
+
+
+def period_bounds(period: str, now: Optional[float] = None) -> Tuple[float, float, float]:
+    """(start, end, previous start) of the calendar day, week (from Monday) or month holding now, in local time"""
+    if period not in PERIODS:
+        raise ValueError(f"Unknown period {period!r}, expected one of {', '.join(PERIODS)}")
+    today = datetime.fromtimestamp(time.time() if now is None else now).replace(hour=0, minute=0, second=0, microsecond=0)
+    if period == "day":
+        start, end, previous = today, today + timedelta(days=1), today - timedelta(days=1)
+    elif period == "week":
+        start = today - timedelta(days=today.weekday())
+        end, previous = start + timedelta(days=7), start - timedelta(days=7)
+    else:
+        start = today.replace(day=1)
+        end = (start + timedelta(days=32)).replace(day=1)
+        previous = (start - timedelta(days=1)).replace(day=1)
+    return start.timestamp(), end.timestamp(), previous.timestamp()
+
+
+# The days of daily_totals (rolled up by retention.prune()) within [since, until), both local midnights
+ROLLED_UP_DAYS = "day >= date(?, 'unixepoch', 'localtime') AND day < date(?, 'unixepoch', 'localtime')"
+
+
+def _totals(store: EventStore, since: float, until: float) -> dict:
+    touches, duration, alerts = 0, 0.0, 0
+    if store.db is not None:
+        try:
+            touches, duration, alerts = store.db.execute(
+                "SELECT COUNT(*), COALESCE(SUM(duration), 0), COALESCE(SUM(alerted), 0) FROM events WHERE started_at >= ? AND started_at < ?",
+                (since, until),
+            ).fetchone()
+            rolled_up = store.db.execute(
+                "SELECT COALESCE(SUM(touches), 0), COALESCE(SUM(duration), 0), COALESCE(SUM(alerts), 0) FROM daily_totals "
+                f"WHERE region = ? AND {ROLLED_UP_DAYS}",
+                (TOTAL, since, until),
+            ).fetchone()
+            touches, duration, alerts = touches + rolled_up[0], duration + rolled_up[1], alerts + rolled_up[2]
+        except sqlite3.Error as e:
+            print(f"Could not summarize the detection history: {e}")
+    return {"touches": touches, "total_duration": duration, "alerts": alerts}
+
+
+def _change(current: float, previous: float) -> Optional[float]:
+    """Relative change (0.25 = a quarter more), None when there's nothing to compare with"""
+    return (current - previous) / previous if previous else None
+
+
+def summarize(store: EventStore, period: str, now: Optional[float] = None) -> dict:
+    """The period so far: {"period", "start", "end", "touches", "total_duration", "alerts", "regions",
+    "busiest_hours", "apps", "previous", "change", "annotations"}
+
+    regions maps each region to its touches and duration, busiest first (a touch on two regions
+    counts for both); busiest_hours lists [hour, touches] and apps maps the apps in the foreground
+    (when recorded, see record_foreground_app) to their touches and duration, busiest first, both
+    from the events not rolled up yet;
+    previous holds the totals of the whole period before, and change how this one compares with it;
+    annotations lists the notes overlapping the period.
+    """
+    start, end, previous_start = period_bounds(period, now)
+    result = {"period": period, "start": start, "end": end, **_totals(store, start, end)}
+    result.update(regions={}, busiest_hours=[], apps={}, previous=_totals(store, previous_start, start))
+    if store.db is not None:
+        try:
+            rows = store.db.execute(
+                "SELECT region, SUM(touches), SUM(duration) FROM ("
+                "SELECT region, COUNT(*) AS touches, SUM(duration) AS duration FROM event_regions JOIN events ON events.id = event_regions.event_id "
+                "WHERE started_at >= ? AND started_at < ? GROUP BY region "
+                f"UNION ALL SELECT region, touches, duration FROM daily_totals WHERE region != ? AND {ROLLED_UP_DAYS}"
+                ") GROUP BY region ORDER BY SUM(touches) DESC, region",
+                (start, end, TOTAL, start, end),
+            ).fetchall()
+            result["regions"] = {region: {"touches": touches, "duration": duration} for region, touches, duration in rows}
+            rows = store.db.execute(
+                "SELECT CAST(strftime('%H', started_at, 'unixepoch', 'localtime') AS INTEGER) AS hour, COUNT(*) FROM events "
+                "WHERE started_at >= ? AND started_at < ? GROUP BY hour ORDER BY COUNT(*) DESC, hour LIMIT ?",
+                (start, end, BUSIEST_HOURS),
+            ).fetchall()
+            result["busiest_hours"] = [list(row) for row in rows]
+            rows = store.db.execute(
+                "SELECT app, COUNT(*), SUM(duration) FROM events WHERE started_at >= ? AND started_at < ? AND app IS NOT NULL "
+                "GROUP BY app ORDER BY COUNT(*) DESC, app",
+                (start, end),
+            ).fetchall()
+            result["apps"] = {app: {"touches": touches, "duration": duration} for app, touches, duration in rows}
+        except sqlite3.Error as e:
+            print(f"Could not summarize the detection history: {e}")
+    result["change"] = {key: _change(result[key], result["previous"][key]) for key in ("touches", "total_duration", "alerts")}
+    result["annotations"] = annotations.query(store, start, end)
+    return result
+
+
+
+def heatmap(store: EventStore, since: Optional[float] = None, until: Optional[float] = None) -> dict:
+    """Touches by region and local hour of day for events that started in [since, until) (None = open-ended)
+
+    {"counts": {region: [touches at 0h, 1h, ... 23h]}, "peak": {"region", "hour", "touches"} or None}.
+    Events already rolled up into daily totals have no hour, so they're left out.
+    """
+    result = {"counts": {}, "peak": None}
+    if store.db is None:
+        return result
+    try:
+        rows = store.db.execute(
+            "SELECT region, CAST(strftime('%H', started_at, 'unixepoch', 'localtime') AS INTEGER) AS hour, COUNT(*) "
+            "FROM event_regions JOIN events ON events.id = event_regions.event_id "
+            "WHERE started_at >= COALESCE(?, started_at) AND started_at < COALESCE(?, started_at + 1) GROUP BY region, hour ORDER BY region",
+            (since, until),
+        ).fetchall()
+    except sqlite3.Error as e:
+        print(f"Could not summarize the detection history: {e}")
+        return result
+    for region, hour, touches in rows:
+        result["counts"].setdefault(region, [0] * 24)[hour] = touches
+        if result["peak"] is None or touches > result["peak"]["touches"]:
+            result["peak"] = {"region": region, "hour": hour, "touches": touches}
+    return result
@@ -0,0 +1,84 @@
+"""
+Detection history trends for Mindful Touch
+Daily touch counts with a moving average, week-over-week change and a fitted slope: is it getting better?
+"""
+
+import sqlite3
+import time
+from datetime import date, datetime, timedelta
+from typing import List, Optional
+
+from . import annotations
+from .events import EventStore
+from .retention import TOTAL
+
+MOVING_AVERAGE_DAYS = 7  # Trailing days averaged for each day's moving average
+
+
+def daily_counts(store: EventStore, first: date, days: int) -> List[int]:
+    """Touches on each local day from first on, rolled-up days included"""
+    counts = [0] * days
+    if store.db is None or days <= 0:
+        return counts
+    until = first + timedelta(days=days)
+    try:
+        rows = store.db.execute(
+            "SELECT day, SUM(touches) FROM ("
+            "SELECT date(started_at, 'unixepoch', 'localtime') AS day, COUNT(*) AS touches FROM events "
+            "WHERE started_at >= ? AND started_at < ? GROUP BY day "
+            "UNION ALL SELECT day, touches FROM daily_totals WHERE region = ? AND day >= ? AND day < ?"
+            ") GROUP BY day",
+            (_midnight(first), _midnight(until), TOTAL, first.isoformat(), until.isoformat()),
+        ).fetchall()
+    except sqlite3.Error as e:
+        print(f"Could not read the detection history: {e}")
+        return counts
+    for day, touches in rows:
+        index = (date.fromisoformat(day) - first).days
+        if 0 <= index < days:
+            counts[index] = touches
+    return counts
+
+
+def _midnight(day: date) -> float:
+    return datetime(day.year, day.month, day.day).timestamp()
+
+
+def _slope(values: List[float]) -> Optional[float]:
+    """Least-squares slope of values against their index (per day), None with fewer than two"""
+    n = len(values)
+    if n < 2:
+        return None
+    mean_x, mean_y = (n - 1) / 2, sum(values) / n
+    spread = sum((x - mean_x) ** 2 for x in range(n))
+    return sum((x - mean_x) * (y - mean_y) for x, y in enumerate(values)) / spread
+
+
+def trends(store: EventStore, window_days: int = 28, now: Optional[float] = None) -> dict:
+    """The last window_days days up to today: {"days", "counts", "moving_average", "week_over_week", "slope", "annotations"}
+
+    days are ISO dates; moving_average the trailing MOVING_AVERAGE_DAYS-day average of each
+    (over fewer days at the start); week_over_week the change of the last 7 days against the
+    7 before (0.25 = a quarter more, None if those had none); slope the fitted touches per day
+    gained (negative: improving); annotations the notes within the window, to explain the spikes.
+    """
+    window_days = max(1, int(window_days))
+    today = datetime.fromtimestamp(time.time() if now is None else now).date()
+    first = today - timedelta(days=window_days - 1)
+    counts = daily_counts(store, first, window_days)
+
+    averages = []
+    for index in range(window_days):
+        trailing = counts[max(0, index - MOVING_AVERAGE_DAYS + 1) : index + 1]
+        averages.append(sum(trailing) / len(trailing))
+    # The fortnight is counted on its own so short windows still get a comparison
+    fortnight = daily_counts(store, today - timedelta(days=13), 14)
+    this_week, last_week = sum(fortnight[7:]), sum(fortnight[:7])
+    return {
+        "days": [(first + timedelta(days=index)).isoformat() for index in range(window_days)],
+        "counts": counts,
+        "moving_average": averages,
+        "week_over_week": (this_week - last_week) / last_week if last_week else None,
+        "slope": _slope(counts),
+        "annotations": annotations.query(store, _midnight(first), _midnight(today + timedelta(days=1))),
+    }
@@ -0,0 +1,122 @@
+"""
+Alert text templates for Mindful Touch
+User-editable alert wording with {region}, {count_today}, {streak} placeholders, and defaults per language
+"""
+
+import locale
+import string
+from typing import Dict, List, Optional
+
+PLACEHOLDERS = ["region", "count_today", "streak", "session_touches"]
+FALLBACK_LANGUAGE = "en"
+
+DEFAULTS = {
+    "en": {
+        "notify_title": "Mindful Touch",
+        "notify_body": "Hands away from your {region}",
+        "toast_title": "Touch noticed",
+        "toast_detail": "{region} · {count_today} today",
+        "speech": "Hands away from your face",
+        "regions": {"scalp": "scalp", "eyebrows": "eyebrows", "eyes": "eyes", "mouth": "mouth", "beard": "beard"},
+        "and": "and",
+    },
+    "de": {
+        "notify_title": "Mindful Touch",
+        "notify_body": "Hände weg von: {region}",
+        "toast_title": "Berührung bemerkt",
+        "toast_detail": "{region} · heute {count_today}",
+        "speech": "Hände weg vom Gesicht",
+        "regions": {"scalp": "Kopfhaut", "eyebrows": "Augenbrauen", "eyes": "Augen", "mouth": "Mund", "beard": "Bart"},
+        "and": "und",
+    },
+    "es": {
+        "notify_title": "Mindful Touch",
+        "notify_body": "Aparta las manos: {region}",
+        "toast_title": "Contacto detectado",
+        "toast_detail": "{region} · {count_today} hoy",
+        "speech": "Aparta las manos de la cara",
+        "regions": {"scalp": "cuero cabelludo", "eyebrows": "cejas", "eyes": "ojos", "mouth": "boca", "beard": "barba"},
+        "and": "y",
+    },
+    "fr": {
+        "notify_title": "Mindful Touch",
+        "notify_body": "Éloignez vos mains : {region}",
+        "toast_title": "Contact détecté",
+        "toast_detail": "{region} · {count_today} aujourd'hui",
+        "speech": "Éloignez vos mains du visage",
+        "regions": {"scalp": "cuir chevelu", "eyebrows": "sourcils", "eyes": "yeux", "mouth": "bouche", "beard": "barbe"},
+        "and": "et",
+    },
+    "el": {
+        "notify_title": "Mindful Touch",
+        "notify_body": "Τα χέρια μακριά: {region}",
+        "toast_title": "Εντοπίστηκε άγγιγμα",
+        "toast_detail": "{region} · {count_today} σήμερα",
+        "speech": "Τα χέρια μακριά από το πρόσωπο",
+        "regions": {"scalp": "τριχωτό κεφαλής", "eyebrows": "φρύδια", "eyes": "μάτια", "mouth": "στόμα", "beard": "γένια"},
+        "and": "και",
+    },
+}
+TEMPLATE_KEYS = ["notify_title", "notify_body", "toast_title", "toast_detail", "speech"]
+
+
+def system_language() -> str:
+    """Two-letter code of the user's locale, if we have defaults for it"""
+    try:
+        code = (locale.getlocale()[0] or "")[:2].lower()
+    except ValueError:
+        code = ""
+    return code if code in DEFAULTS else FALLBACK_LANGUAGE
+
+
+def validate(template: str) -> Optional[str]:
+    """None if the template only uses known placeholders, else what's wrong with it"""
+    try:
+        fields = [name for _, name, _, _ in string.Formatter().parse(template) if name is not None]
+    except ValueError as e:
+        return str(e)
+    unknown = [name for name in fields if name not in PLACEHOLDERS]
+    return f"unknown placeholder {{{unknown[0]}}}" if unknown else None
+
+
+class _Missing(dict):
+    def __missing__(self, key):
+        return "{" + key + "}"
+
+
+def render(template: str, values: dict) -> str:
+    """Fill in placeholders; a template that can't be formatted is shown as written rather than raising"""
+    try:
+        return template.format_map(_Missing(values))
+    except (ValueError, IndexError, AttributeError):
+        return template
+
+
+class AlertTemplates:
+    """The configured wording, rendered for a particular alert"""
+
+    def __init__(self, language: str = "", overrides: Optional[Dict[str, str]] = None):
+        self.language = language if language in DEFAULTS else system_language()
+        self.overrides = {key: value for key, value in (overrides or {}).items() if key in TEMPLATE_KEYS}
+
+    @classmethod
+    def from_settings(cls, settings: dict) -> "AlertTemplates":
+        return cls(settings["language"], settings["alert_templates"])
+
+    def template(self, key: str) -> str:
+        return self.overrides.get(key) or DEFAULTS[self.language][key]
+
+    def region_names(self, regions: List[str]) -> str:
+        """'eyes' / 'eyes and mouth' / 'scalp, eyes and mouth', in the configured language"""
+        names = [DEFAULTS[self.language]["regions"].get(region, region) for region in regions]
+        if len(names) <= 1:
+            return "".join(names)
+        return f"{', '.join(names[:-1])} {DEFAULTS[self.language]['and']} {names[-1]}"
+
+    def render(self, key: str, regions: List[str], **values) -> str:
+        """The template for key, filled in for this alert"""
+        return self.render_text(self.template(key), regions, **values)
+
+    def render_text(self, text: str, regions: List[str], count_today: int = 0, streak: str = "", session_touches: int = 0) -> str:
+        values = {"region": self.region_names(regions), "count_today": count_today, "streak": streak, "session_touches": session_touches}
+        return render(text, values)
@@ -5,26 +5,69 @@ Facial touch detection with beautiful, minimal interface
 """
 
 import os
-import subprocess
+import secrets
+import sqlite3
 import sys
-import time
+from dataclasses import replace
+from datetime import date
 from pathlib import Path
+from typing import Optional
 
-import cv2
-import numpy as np
-from PyQt6.QtCore import Qt, QThread, QTimer, pyqtSignal
-from PyQt6.QtGui import QAction, QFont, QFontDatabase, QImage, QPixmap
-from PyQt6.QtWidgets import QApplication, QHBoxLayout, QMainWindow, QMessageBox, QVBoxLayout, QWidget
+from PyQt6.QtCore import QEvent, Qt, QTimer, QUrl, pyqtSignal
+from PyQt6.QtGui import QAction, QActionGroup, QDesktopServices, QFont, QFontDatabase, QImage, QPixmap
+from PyQt6.QtWidgets import QApplication, QFileDialog, QHBoxLayout, QMainWindow, QMessageBox, QVBoxLayout, QWidget
 
-from backend.detection import settings_store
+from backend import autostart, cameras, deep_links, erase, foreground, i18n, instance_lock, logs, onboarding, profiles, schedule, single_instance
+from backend.alert_outcomes import DISMISSED, AlertOutcomes, efficacy
+from backend.alerts import ACTIONS, NOTIFY, PULSE, SOUND, SPEAK, TOAST, AlertPipeline, RegionAlert
+from backend.bridge import DetectionBridge
+from backend.detection import custom_regions, settings_store
 from backend.detection.config import Config
-from backend.detection.multi_region_detector import MultiRegionDetector
+from backend.detection.options import BackendOptions
+from backend.focus_mode import FocusModeMonitor
+from backend.hangup import HangupWatcher
+from backend.i18n import tr
+from backend.manager import BackendManager
+from backend.power import PowerMonitor
+from backend.session_monitor import SessionMonitor
+from backend.settings_watcher import SettingsWatcher
+from backend.startup_errors import StartupError
+from backend.stats import EventStore, annotations, backup, baseline, export, health, live, report, retention, sessions, summary, trends
+from backend.stats.achievements import AchievementTracker
+from backend.stats.goals import BASELINE_PERCENT, MAX_TOUCHES, GoalTracker, valid_goals
+from backend.stats.streaks import StreakTracker
+from backend.templates import TEMPLATE_KEYS, AlertTemplates, validate
+from ui import macos, sound
+from ui.hotkey import GlobalHotkey
 from ui.panels.camera_panel import CameraPanel
 from ui.panels.detection_panel import DetectionPanel
+from ui.speech import SpokenAlert
 from ui.styles.theme import Theme
+from ui.widgets.alert_toast import SNOOZE, AlertToast
+from ui.widgets.breathing import BreathingWindow
+from ui.widgets.edge_flash import EdgeFlash
+from ui.widgets.log_console import LogConsole
+from ui.widgets.mini_controls import MiniControls
+from ui.widgets.preview_window import PreviewWindow
 from ui.widgets.status_badge import AppHeader, StatusBadge
+from ui.widgets.status_overlay import StatusOverlay
+from ui.widgets.streak_widget import StreakWidget
+from ui.widgets.tray import TrayIcon
 
-ALERT_SOUND = "/System/Library/Sounds/Glass.aiff"
+SNOOZE_MINUTES = 10
+TRAY_RETRY_MS = 2000  # At login the panel may come up after us; keep looking for a tray this often...
+TRAY_RETRY_LIMIT = 15  # ...this many times
+
+
+def format_duration(seconds) -> str:
+    """90 -> '1m 30s', 3900 -> '1h 05m'"""
+    hours, rem = divmod(int(seconds), 3600)
+    minutes, seconds = divmod(rem, 60)
+    if hours:
+        return f"{hours}h {minutes:02d}m"
+    if minutes:
+        return f"{minutes}m {seconds:02d}s"
+    return f"{seconds}s"
 
 
 def resource_path(relative):
@@ -42,136 +85,168 @@ def load_fonts():
             QFontDatabase.addApplicationFontFromData(font_file.read_bytes())
 
 
-class CameraThread(QThread):
-    """Thread for camera capture and detection"""
-
-    frame_ready = pyqtSignal(np.ndarray)
-    detection_data = pyqtSignal(dict)
-
-    def __init__(self):
-        super().__init__()
-        self.running = False
-        self.detector = None
-        self.cap = None
-        self.is_stopping = False
-
-    def start_detection(self):
-        """Start detection with proper state protection"""
-        # Prevent starting if already running or stopping
-        if self.running or self.is_stopping or self.isRunning():
-            print("Detection already running or stopping, ignoring start request")
-            return True  # Return True to not show error state in UI
-
-        try:
-            # Clean up any existing resources first
-            self._cleanup_resources()
-
-            # Create new detector and camera
-            self.detector = MultiRegionDetector()
-            self.cap = cv2.VideoCapture(0)
-
-            if not self.cap.isOpened():
-                print("Failed to open camera")
-                self._cleanup_resources()
-                return False
-
-            # Set state and start thread
-            self.running = True
-            self.is_stopping = False
-            self.start()
-            return True
-
-        except Exception as e:
-            print(f"Error starting detection: {e}")
-            self._cleanup_resources()
-            return False
-
-    def stop_detection(self):
-        """Stop detection with proper state protection"""
-        # Prevent double stopping
-        if not self.running and not self.isRunning():
-            print("Detection not running, ignoring stop request")
-            return
-
-        if self.is_stopping:
-            print("Already stopping, ignoring stop request")
-            return
-
-        try:
-            print("Stopping detection...")
-            self.is_stopping = True
-            self.running = False
-
-            # Wait for thread to finish with timeout
-            if self.isRunning():
-                if not self.wait(3000):  # 3 second timeout
-                    print("Warning: Thread did not stop gracefully, forcing termination")
-                    self.terminate()
-                    self.wait(1000)  # Wait 1 more second after terminate
-
-            # Clean up resources
-            self._cleanup_resources()
-            self.is_stopping = False
-            print("Detection stopped successfully")
-
-        except Exception as e:
-            print(f"Error stopping detection: {e}")
-            self.is_stopping = False
-
-    def _cleanup_resources(self):
-        """Clean up camera and detector resources"""
-        try:
-            if self.cap:
-                self.cap.release()
-                self.cap = None
-
-            if self.detector:
-                self.detector.cleanup()
-                self.detector = None
-
-        except Exception as e:
-            print(f"Error during cleanup: {e}")
-
-    def run(self):
-        while self.running and self.cap and self.cap.isOpened():
-            ret, frame = self.cap.read()
-            if not ret:
-                continue
-
-            if self.detector:
-                annotated_frame, detection_data = self.detector.process_frame(frame)
-                self.frame_ready.emit(annotated_frame)
-                self.detection_data.emit(detection_data)
-
-
 class MainWindow(QMainWindow):
+    detection_toggled = pyqtSignal(bool)  # The global shortcut paused (False) or resumed/started (True) detection
+    camera_permission_denied = pyqtSignal(str)  # Deep link to the privacy settings where camera access is granted
+    settings_changed = pyqtSignal(list)  # Keys whose values changed, after they were saved and applied
+    settings_reloaded = pyqtSignal(list)  # Keys changed by an edit to the settings file made outside the app
+    erase_progress = pyqtSignal(int, int, str)  # Files erased so far, how many in all, the one just done
+    stats_tick = pyqtSignal(dict)  # get_live_stats(), every Config.STATS_TICK_INTERVAL seconds while anything listens
+
     def __init__(self):
         super().__init__()
-        self.camera_thread = CameraThread()
+        self.backend = BackendManager(self)
+        self.bridge = DetectionBridge(parent=self)  # Session counters and touch events
+        self.alert_pipeline = AlertPipeline(parent=self)  # Cooldowns and burst coalescing between touches and alerts
         self.is_detecting = False
         self.show_feed = True
         self.current_flash_state = "none"
         self.is_transitioning = False  # Prevent rapid state changes
-
-        # Session tracking
-        self.session_start_time = None
-        self.total_detections = 0
-        self.mindful_stops = 0
-        self.last_alert_state = False
-
-        # Timer for session updates
-        self.session_timer = QTimer()
-        self.session_timer.timeout.connect(self._update_session_timer)
+        self.log_console = None  # Created on first use
+        self.quitting = False  # Set by quit_app so closeEvent really closes
+        self.cleaned_up = False
+        self.erase_token = None  # What erase_all_data() wants, once request_erase_token() handed it out
+        self.suspended_for_lock = False  # Suspended because the screen locked, so unlocking resumes
+        self.quiet_alerts = []  # Regions of alerts silenced by Do Not Disturb, summarized when it ends
+        self.battery_saver_active = False  # On battery with the saver on: frame rate capped
+        self.touches_today = 0  # Across sessions, for the icon badge
+        self.touches_day = date.today()
+        self.suspended_for_battery = False
+        self.suspended_for_away = False  # Suspended because nobody touched keyboard or mouse for a while
+        self.tray_hint_shown = False
 
         # Load persisted settings before building the UI so toggles initialize correctly
         self.settings = settings_store.load()
-        Config.ACTIVE_REGIONS = [r for r in self.settings["active_regions"] if r in Config.AVAILABLE_REGIONS]
-        Config.update_contact_duration(self.settings["alert_delay"])
+        self.backend.configure(self._backend_options())
+        self.alert_pipeline.configure(self.settings)
+        self.alert_sound = sound.AlertSound(parent=self)
+        self.alert_sound.configure(self.settings)
+        self.spoken_alert = SpokenAlert(parent=self)
+        self.spoken_alert.configure(self.settings)
+        self.templates = AlertTemplates.from_settings(self.settings)
+        i18n.set_locale(self.settings["language"])
 
         self.setup_ui()
         self.setup_menu()
+        self.setup_tray()
+        self.setup_shortcut()
         self.connect_signals()
 
+        # Step aside while the screen is locked or the machine sleeps
+        self.session_monitor = SessionMonitor(parent=self)
+        self.session_monitor.locked_changed.connect(self._on_screen_locked)
+        self.session_monitor.woke_from_sleep.connect(self._on_woke_from_sleep)
+        self.session_monitor.idle_changed.connect(self._on_user_idle)
+        self.session_monitor.idle_threshold = max(0.0, float(self.settings["away_minutes"])) * 60
+        self.session_monitor.start()
+
+        # Responses to alerts: snooze, "I was aware", false positive
+        self.alert_toast = AlertToast()
+        self.alert_toast.action_chosen.connect(self._on_alert_action)
+
+        # What each alert led to, for judging which alert styles work
+        self.alert_outcomes = AlertOutcomes(parent=self)
+        self.bridge.touch_labeled.connect(lambda label: self.alert_outcomes.responded(label["label"]))
+        self.bridge.hand_near_face.connect(self._on_contact_changed)
+        self.alert_toast.expired.connect(lambda: self.alert_outcomes.responded(DISMISSED))
+
+        # Every touch episode, for the detection history
+        self.event_store = EventStore()
+        sessions.close_abandoned(self.event_store)
+        self.bridge.touch_episode.connect(self._record_episode)
+        self.bridge.touch_labeled.connect(lambda label: self.event_store.respond(label["label"], label["alert_time"]))
+        self.alert_toast.expired.connect(lambda: self.event_store.respond(DISMISSED, self.bridge.last_alert_time))
+        self.prune_timer = QTimer(self)
+        self.prune_timer.timeout.connect(self._prune_history)
+        self.prune_timer.start(int(Config.PRUNE_INTERVAL * 1000))
+        self._prune_history()
+        self.streaks = StreakTracker(self.event_store, parent=self)
+        self.bridge.touch_detected.connect(lambda _: self.streaks.touched())
+        self.streaks.updated.connect(lambda status: self.tray.set_streak(status["current"]))
+        # Daily goals, checked once each touch is recorded and as the streak grows
+        self.goal_tracker = GoalTracker(self.event_store, self.streaks, parent=self)
+        self.goal_tracker.achieved.connect(self._on_goal_achieved)
+        self.bridge.touch_episode.connect(self.goal_tracker.evaluate)
+        self.streaks.updated.connect(self.goal_tracker.evaluate)
+        self.goal_tracker.configure(self.settings["goals"])
+        self.achievements = AchievementTracker(self.event_store, self.streaks, parent=self)
+        self.achievements.unlocked.connect(self._on_achievement_unlocked)
+        self.bridge.touch_episode.connect(self.achievements.check)
+        self.streaks.updated.connect(self.achievements.check)
+        self.stats_tick_timer = QTimer(self)
+        self.stats_tick_timer.timeout.connect(self._emit_stats_tick)
+        self.stats_tick_timer.start(int(Config.STATS_TICK_INTERVAL * 1000))
+        self.breathing_window = BreathingWindow()
+        self.breathing_window.finished.connect(self._on_breathing_finished)
+        self.edge_flash = EdgeFlash(parent=self)
+        self.edge_flash.configure(self.settings)
+        self.snooze_timer = QTimer(self)
+        self.snooze_timer.setSingleShot(True)
+        self.snooze_timer.timeout.connect(self._on_snooze_over)
+        # Keeps the "min left" indicator in the tray current
+        self.snooze_tick = QTimer(self)
+        self.snooze_tick.timeout.connect(self._refresh_snooze_indicator)
+
+        # Live status that stays up while the main window is closed to the tray
+        self.status_overlay = StatusOverlay()
+        self.status_overlay.restore_position(self.settings["status_overlay_position"])
+        self.status_overlay.moved.connect(self._on_overlay_moved)
+        if self.settings["status_overlay"]:
+            self.status_overlay.show()
+
+        # The streak and a pause button pinned to a screen corner, independent of the main window
+        self.streak_widget = StreakWidget()
+        self.streak_widget.pause_requested.connect(lambda: self.set_suspended(True))
+        self.streak_widget.resume_requested.connect(lambda: self.set_suspended(False))
+        self.streak_widget.close_requested.connect(self.close_widget)
+        self.streak_widget.pinned.connect(self._on_widget_pinned)
+        self.streaks.updated.connect(lambda status: self.streak_widget.set_streak(status["current"]))
+        if self.settings["widget"]:
+            self.open_widget()
+
+        # The detector's view in a window of its own, fed from frame_ready only while it's open
+        self.preview_window = PreviewWindow()
+        self.preview_window.closed.connect(self._on_preview_closed)
+        self.preview_subscribed = False
+
+        # Ends a timed pause (mindfultouch://pause?minutes=N)
+        self.pause_timer = QTimer(self)
+        self.pause_timer.setSingleShot(True)
+        self.pause_timer.timeout.connect(lambda: self.set_suspended(False))
+
+        # Detection runs only in the active hours, when there are any; override_schedule() makes an exception
+        self.outside_active_hours = False
+        self.suspended_for_schedule = False
+        self.schedule_override = QTimer(self)
+        self.schedule_override.setSingleShot(True)
+        self.schedule_override.timeout.connect(self._check_active_hours)
+        self.schedule_timer = QTimer(self)
+        self.schedule_timer.timeout.connect(self._check_active_hours)
+        self.schedule_timer.start(int(Config.SCHEDULE_POLL_INTERVAL * 1000))
+        self.tray.override_schedule_requested.connect(self.override_schedule)
+        self._check_active_hours()
+
+        # Alerts stay quiet (tray blink only) while the OS is in Do Not Disturb
+        self.focus_monitor = FocusModeMonitor(parent=self)
+        self.focus_monitor.dnd_changed.connect(self._on_dnd_changed)
+        self.focus_monitor.start()
+
+        # Quiet-app rules (and the history, if opted in) go by the foreground app, looked up off the UI thread while detecting
+        self.foreground_monitor = foreground.ForegroundMonitor()
+
+        # Go easy on the battery
+        self.power_monitor = PowerMonitor(parent=self)
+        self.power_monitor.power_changed.connect(self._on_power_changed)
+        self.power_monitor.start()
+
+        # kill -HUP reloads the settings file (Unix)
+        self.hangup_watcher = HangupWatcher(parent=self)
+        self.hangup_watcher.hangup.connect(self.reload_settings)
+        self.hangup_watcher.install()
+        self.settings_watcher = SettingsWatcher(parent=self)
+        self.settings_watcher.changed.connect(lambda: self.reload_settings(quiet=True))
+        self.settings_watcher.start()
+
         self.detection_panel.set_contact_duration(self.settings["alert_delay"])
 
     def setup_ui(self):
@@ -235,17 +310,793 @@ class MainWindow(QMainWindow):
 
         app_menu = menubar.addMenu("Mindful Touch")
 
-        about_action = QAction("About Mindful Touch", self)
+        # Labelled, and labelled again when the language changes, by retranslate_menu()
+        self.menu_labels = []
+
+        def action(text):
+            item = QAction(self)
+            self.menu_labels.append((item.setText, text))
+            return item
+
+        def submenu(text):
+            item = app_menu.addMenu("")
+            self.menu_labels.append((item.setTitle, text))
+            return item
+
+        about_action = action("About Mindful Touch")
         about_action.triggered.connect(self._show_about)
         app_menu.addAction(about_action)
 
+        restart_action = action("Restart Detection")
+        restart_action.setShortcut("Ctrl+R")
+        restart_action.triggered.connect(self.restart_detection)
+        app_menu.addAction(restart_action)
+
+        self.suspend_action = action("Suspend Detection")
+        self.suspend_action.setShortcut("Ctrl+P")
+        self.suspend_action.setCheckable(True)
+        self.suspend_action.triggered.connect(self.toggle_suspend)
+        app_menu.addAction(self.suspend_action)
+
+        self.overlay_action = action("Show Status Overlay")
+        self.overlay_action.setCheckable(True)
+        self.overlay_action.setChecked(self.settings["status_overlay"])
+        self.overlay_action.triggered.connect(self.toggle_overlay)
+        app_menu.addAction(self.overlay_action)
+
+        self.widget_action = action("Show Streak Widget")
+        self.widget_action.setCheckable(True)
+        self.widget_action.triggered.connect(lambda checked: self.open_widget() if checked else self.close_widget())
+        app_menu.addAction(self.widget_action)
+
+        preview_action = action("Show Detector View")
+        preview_action.triggered.connect(self.open_preview)
+        app_menu.addAction(preview_action)
+
+        edge_flash_action = action("Flash Screen Edges on Alert")
+        edge_flash_action.setCheckable(True)
+        edge_flash_action.setChecked(self.settings["edge_flash"])
+        edge_flash_action.toggled.connect(self.set_edge_flash)
+        app_menu.addAction(edge_flash_action)
+
+        icon_badge_action = action("Show Today's Count on Icon")
+        icon_badge_action.setCheckable(True)
+        icon_badge_action.setChecked(self.settings["icon_badge"])
+        icon_badge_action.toggled.connect(self.set_icon_badge)
+        app_menu.addAction(icon_badge_action)
+
+        foreground_app_action = action("Note the App in Use with Each Touch")
+        foreground_app_action.setCheckable(True)
+        foreground_app_action.setChecked(self.settings["record_foreground_app"])
+        foreground_app_action.toggled.connect(self.set_record_foreground_app)
+        app_menu.addAction(foreground_app_action)
+
+        low_priority_action = action("Run at Low Priority")
+        low_priority_action.setCheckable(True)
+        low_priority_action.setChecked(self.settings["low_priority"])
+        low_priority_action.toggled.connect(self.set_low_priority)
+        app_menu.addAction(low_priority_action)
+
+        close_to_tray_action = action("Keep Running When Closed")
+        close_to_tray_action.setCheckable(True)
+        close_to_tray_action.setChecked(self.settings["close_to_tray"])
+        close_to_tray_action.toggled.connect(self.set_close_to_tray)
+        app_menu.addAction(close_to_tray_action)
+
+        self.autostart_action = action("Open at Login")
+        self.autostart_action.setCheckable(True)
+        self.autostart_action.setChecked(self.settings["autostart"])
+        self.autostart_action.triggered.connect(lambda checked: self.set_autostart(checked, self.settings["silent_start"]))
+        app_menu.addAction(self.autostart_action)
+
+        self.silent_start_action = action("Start in Background at Login")
+        self.silent_start_action.setCheckable(True)
+        self.silent_start_action.setChecked(self.settings["silent_start"])
+        self.silent_start_action.setEnabled(self.settings["autostart"])
+        self.silent_start_action.triggered.connect(lambda checked: self.set_autostart(True, checked))
+        app_menu.addAction(self.silent_start_action)
+
+        self.camera_menu = submenu("Camera")
+        self.camera_menu.aboutToShow.connect(self._populate_camera_menu)
+
+        self.custom_region_menu = submenu("Custom Regions")
+        self.custom_region_menu.aboutToShow.connect(self._populate_custom_region_menu)
+
+        self.sound_menu = submenu("Alert Sound")
+        self.sound_menu.aboutToShow.connect(self._populate_sound_menu)
+
         app_menu.addSeparator()
 
-        quit_action = QAction("Quit", self)
+        export_action = action("Export Settings…")
+        export_action.triggered.connect(self._choose_export_file)
+        app_menu.addAction(export_action)
+
+        import_action = action("Import Settings…")
+        import_action.triggered.connect(self._choose_import_file)
+        app_menu.addAction(import_action)
+
+        history_action = action("Export History…")
+        history_action.triggered.connect(self._choose_history_file)
+        app_menu.addAction(history_action)
+
+        report_action = action("Weekly Report…")
+        report_action.triggered.connect(self._choose_report_file)
+        app_menu.addAction(report_action)
+
+        back_up_action = action("Back Up Data…")
+        back_up_action.triggered.connect(self._choose_backup_file)
+        app_menu.addAction(back_up_action)
+
+        restore_action = action("Restore Data…")
+        restore_action.triggered.connect(self._choose_restore_file)
+        app_menu.addAction(restore_action)
+
+        health_action = action("Export for Apple Health / Google Fit…")
+        health_action.triggered.connect(self._choose_health_file)
+        app_menu.addAction(health_action)
+
+        reset_action = action("Reset to Defaults…")
+        reset_action.triggered.connect(self._confirm_reset)
+        app_menu.addAction(reset_action)
+
+        erase_action = action("Erase All Data…")
+        erase_action.triggered.connect(self._confirm_erase)
+        app_menu.addAction(erase_action)
+
+        self.backup_menu = submenu("Restore Backup")
+        self.backup_menu.aboutToShow.connect(self._populate_backup_menu)
+
+        logs_action = action("Show Logs")
+        logs_action.setShortcut("Ctrl+L")
+        logs_action.triggered.connect(self._show_logs)
+        app_menu.addAction(logs_action)
+
+        app_menu.addSeparator()
+
+        quit_action = action("Quit")
         quit_action.setShortcut("Ctrl+Q")
-        quit_action.triggered.connect(self.close)
+        quit_action.triggered.connect(self.quit_app)
         app_menu.addAction(quit_action)
 
+        self.retranslate_menu()
+
+    def retranslate_menu(self):
+        """Label the app menu again in the current i18n language"""
+        for set_label, text in self.menu_labels:
+            set_label(tr(text))
+
+    def setup_tray(self):
+        # Qt talks StatusNotifierItem (GNOME/KDE) or XEmbed as the desktop supports; without
+        # either, the mini controls window stands in for the tray while the main window is hidden
+        self.tray = TrayIcon(parent=self)
+        self.mini_controls = MiniControls()
+        for controls in (self.tray, self.mini_controls):
+            controls.pause_requested.connect(lambda: self.set_suspended(True))
+            controls.resume_requested.connect(lambda: self.set_suspended(False))
+            controls.open_requested.connect(self.show_dashboard)
+            controls.quit_requested.connect(self.quit_app)
+        self.tray.menu_bar_mode_toggled.connect(self.set_menu_bar_mode)
+        self.tray.snooze_requested.connect(self.snooze_alerts)
+        self.tray.unsnooze_requested.connect(self.unsnooze_alerts)
+        self.tray.profile_requested.connect(self.switch_profile)
+        self._refresh_profile_menu()
+        self.settings_changed.connect(self._on_settings_changed)
+
+        self.tray_retries = 0
+        self.tray_retry_timer = QTimer(self)
+        self.tray_retry_timer.timeout.connect(self._show_tray)
+        self._show_tray()
+        if not self.tray.isVisible():
+            print("No system tray available yet, using the mini controls window")
+            self.tray_retry_timer.start(TRAY_RETRY_MS)
+
+        if self.settings["menu_bar_only"]:
+            self.set_menu_bar_mode(True)
+
+    def _show_tray(self):
+        if TrayIcon.isSystemTrayAvailable():
+            self.tray.show()
+            self.tray_retry_timer.stop()
+            self.mini_controls.hide()
+            return
+        self.tray_retries += 1
+        if self.tray_retries >= TRAY_RETRY_LIMIT:
+            self.tray_retry_timer.stop()
+
+    def enter_background(self):
+        """Keep running without the main window: in the tray, or in the mini controls where there is none"""
+        self.hide()
+        if not self.tray.isVisible():
+            self.mini_controls.show()
+        self._update_subscriptions()
+
+    def setup_shortcut(self):
+        self.hotkey = GlobalHotkey(parent=self)
+        self.hotkey.activated.connect(self._on_toggle_shortcut)
+        self.hotkey.register(self.settings["toggle_shortcut"])
+
+    def reload_settings(self, quiet: bool = False):
+        """Re-read the settings file and apply what changed to the running backend, without a restart
+
+        In-app changes not saved yet are kept, unless the file changed the same setting:
+        then the file, as the later write, wins and a warning is logged.
+        """
+        before = settings_store.last_saved
+        new = settings_store.load()
+        unsaved = [key for key in self.settings if self.settings[key] != before.get(key)]
+        conflicts = [key for key in unsaved if new.get(key) != before.get(key) and new.get(key) != self.settings[key]]
+        if conflicts:
+            print(f"Warning: {', '.join(conflicts)} changed both in the app and in the settings file, keeping the file's")
+        kept = {key: self.settings[key] for key in unsaved if key not in conflicts}
+        changed = self._apply_settings({**new, **kept})
+        if kept:
+            settings_store.save(self.settings)
+        if changed:
+            print(f"Settings reloaded, changed: {', '.join(changed)}")
+            self.settings_reloaded.emit(changed)
+        elif not quiet:
+            print("Settings reloaded, nothing changed")
+
+    def get_settings(self) -> dict:
+        return dict(self.settings)
+
+    def update_settings(self, changes: dict) -> list:
+        """Change any number of settings at once: saved, applied to what's running, and announced on settings_changed
+
+        Unknown keys are refused and values of the wrong type fall back to their default. Returns the changed keys.
+        """
+        unknown = [key for key in changes if key not in settings_store.DEFAULTS]
+        if unknown:
+            raise KeyError(f"Unknown settings: {', '.join(unknown)}")
+        changed = self._apply_settings(settings_store.typed({**self.settings, **changes}))
+        if changed:
+            settings_store.save(self.settings)
+        return changed
+
+    def list_profiles(self) -> list:
+        return [{"name": name, "active": name == self.settings["active_profile"]} for name in sorted(self.settings["profiles"])]
+
+    def switch_profile(self, name: str) -> bool:
+        """Apply a saved profile to the running app"""
+        profile = self.settings["profiles"].get(name)
+        if profile is None:
+            return False
+        # Keys added since the profile was saved keep their current value
+        self.update_settings({**{key: value for key, value in profile.items() if key in profiles.PROFILE_KEYS}, "active_profile": name})
+        self.tray.showMessage("Mindful Touch", tr("Switched to {name}", name=name), self.tray.icon(), 2000)
+        return True
+
+    def save_profile_as(self, name: str) -> bool:
+        """Save the current detection and alert settings as a profile (replacing one of the same name)"""
+        name = profiles.clean_name(name)
+        if name is None:
+            return False
+        self.update_settings({"profiles": {**self.settings["profiles"], name: profiles.snapshot(self.settings)}, "active_profile": name})
+        return True
+
+    def delete_profile(self, name: str) -> bool:
+        if name not in self.settings["profiles"]:
+            return False
+        remaining = {other: profile for other, profile in self.settings["profiles"].items() if other != name}
+        active = "" if self.settings["active_profile"] == name else self.settings["active_profile"]
+        self.update_settings({"profiles": remaining, "active_profile": active})
+        return True
+
+    def export_settings(self, path: str, include_stats: bool = False) -> bool:
+        """Save settings, profiles and per-region settings to one file, with the alert history if asked"""
+        try:
+            settings_store.export_bundle(self.settings, path, self.alert_outcomes.load() if include_stats else None)
+        except OSError as e:
+            print(f"Could not export settings: {e}")
+            return False
+        return True
+
+    def import_settings(self, path: str) -> bool:
+        """Apply an exported settings file; this machine's camera, audio output and login settings are kept"""
+        try:
+            settings, stats = settings_store.read_bundle(path)
+        except (OSError, ValueError) as e:
+            print(f"Could not import settings: {e}")
+            return False
+        self.update_settings(settings)
+        if stats:
+            print(f"Imported {self.alert_outcomes.merge(stats)} alert outcomes")
+        return True
+
+    def _backed_up_paths(self) -> list:
+        """What a backup holds: the settings and the alert history"""
+        return [settings_store.SETTINGS_PATH, self.alert_outcomes.path]
+
+    def reset_settings(self) -> Optional[str]:
+        """Back everything up, then go back to the default settings; returns the backup's name (None if it failed)"""
+        self.alert_outcomes.finalize()
+        try:
+            folder = settings_store.backup(self._backed_up_paths())
+        except OSError as e:
+            print(f"Could not back up before resetting, not resetting: {e}")
+            return None
+        kept = {key: self.settings[key] for key in settings_store.RESET_KEEPS}
+        self.update_settings({**settings_store.DEFAULTS, **kept})
+        print(f"Settings reset to defaults, previous ones in {folder}")
+        return folder.name
+
+    def list_backups(self) -> list:
+        """See settings_store.list_backups()"""
+        return settings_store.list_backups()
+
+    def restore_backup(self, name: str) -> bool:
+        """Undo a reset: put a backup's settings and alert history back and apply them"""
+        self.alert_outcomes.finalize()
+        try:
+            restored = settings_store.restore_backup(name, self._backed_up_paths())
+        except OSError as e:
+            print(f"Could not restore backup {name}: {e}")
+            return False
+        print(f"Restored {', '.join(restored) or 'nothing'} from backup {name}")
+        self.reload_settings()
+        return True
+
+    def backup_data(self, path: str) -> bool:
+        """Save the detection history, settings and alert history to one file; see backup.backup()"""
+        self.alert_outcomes.finalize()
+        try:
+            names = backup.backup(self.event_store, path, self._backed_up_paths())
+        except (OSError, sqlite3.Error) as e:
+            print(f"Could not back up to {path}: {e}")
+            return False
+        print(f"Backed up {', '.join(names)} to {path}")
+        return True
+
+    def restore_data(self, path: str) -> bool:
+        """Replace the detection history, settings and alert history with a backup's, once it checks out, and apply them"""
+        self.alert_outcomes.finalize()
+        sessions.end(self.event_store)
+        try:
+            restored = backup.restore(self.event_store, path, self._backed_up_paths())
+        except (OSError, ValueError, sqlite3.Error) as e:
+            print(f"Could not restore {path}: {e}")
+            return False
+        print(f"Restored {', '.join(restored)} from {path}")
+        self.streaks.refresh()
+        self.achievements.refresh()
+        self.reload_settings()
+        self.goal_tracker.evaluate()
+        return True
+
+    def _choose_backup_file(self):
+        name = f"mindful-touch-backup-{date.today().isoformat()}.zip"
+        path, _ = QFileDialog.getSaveFileName(self, tr("Back Up Data"), str(Path.home() / name), "Backup (*.zip)")
+        if path and not self.backup_data(path):
+            QMessageBox.warning(self, tr("Back Up Data"), tr("The backup could not be saved there."))
+
+    def _choose_restore_file(self):
+        path, _ = QFileDialog.getOpenFileName(self, tr("Restore Data"), str(Path.home()), "Backup (*.zip)")
+        if not path:
+            return
+        answer = QMessageBox.question(self, tr("Restore Data"), tr("Replace your history and settings with the backup's?"))
+        if answer == QMessageBox.StandardButton.Yes and not self.restore_data(path):
+            QMessageBox.warning(self, tr("Restore Data"), tr("That file isn't a backup this version can restore, or it is damaged."))
+
+    def request_erase_token(self) -> str:
+        """The token erase_all_data() wants, new each time, so nothing erases everything by accident"""
+        self.erase_token = secrets.token_urlsafe(8)
+        return self.erase_token
+
+    def erase_all_data(self, confirm_token: str) -> bool:
+        """Stop detection, overwrite and delete the history, logs, settings and backups, then quit
+
+        Needs the token request_erase_token() last gave out. erase_progress follows along.
+        Returns False if the token is wrong; every file that could be erased is gone either way.
+        """
+        token, self.erase_token = self.erase_token, None
+        if token is None or not secrets.compare_digest(str(confirm_token), token):
+            print("Erase refused: wrong confirmation token")
+            return False
+        print("Erasing all data...")
+        self.quitting = True
+        self.cleanup()
+        logs.stop_writing()
+        failed = erase.erase(erase.data_files(), lambda done, total, path: self.erase_progress.emit(done, total, path.name))
+        print(f"Erased all data{f', except {len(failed)} files' if failed else ''}")
+        self.quit_app()
+        return True
+
+    def _confirm_erase(self):
+        answer = QMessageBox.warning(
+            self,
+            tr("Erase All Data"),
+            tr("Erase your touch history, logs, settings and backups from this computer, and quit? This can't be undone."),
+            QMessageBox.StandardButton.Yes | QMessageBox.StandardButton.Cancel,
+            QMessageBox.StandardButton.Cancel,
+        )
+        if answer == QMessageBox.StandardButton.Yes:
+            self.erase_all_data(self.request_erase_token())
+
+    def _confirm_reset(self):
+        answer = QMessageBox.question(self, tr("Reset to Defaults"), tr("Go back to the default settings? The current ones are backed up first."))
+        if answer == QMessageBox.StandardButton.Yes and self.reset_settings() is None:
+            QMessageBox.warning(self, tr("Reset to Defaults"), tr("The current settings couldn't be backed up, so nothing was reset."))
+
+    def _populate_backup_menu(self):
+        self.backup_menu.clear()
+        backups = self.list_backups()
+        if not backups:
+            self.backup_menu.addAction(tr("No Backups")).setEnabled(False)
+        for entry in backups:
+            action = self.backup_menu.addAction(entry["name"].replace("_", " "))
+            action.triggered.connect(lambda _, name=entry["name"]: self.restore_backup(name))
+
+    def _choose_export_file(self):
+        path, _ = QFileDialog.getSaveFileName(self, tr("Export Settings"), str(Path.home() / "mindful-touch-settings.json"), "Settings (*.json)")
+        if not path:
+            return
+        stats = QMessageBox.question(self, tr("Export Settings"), tr("Include your alert history?")) == QMessageBox.StandardButton.Yes
+        if not self.export_settings(path, stats):
+            QMessageBox.warning(self, tr("Export Settings"), tr("The settings could not be saved there."))
+
+    def _choose_import_file(self):
+        path, _ = QFileDialog.getOpenFileName(self, tr("Import Settings"), str(Path.home()), "Settings (*.json)")
+        if path and not self.import_settings(path):
+            QMessageBox.warning(self, tr("Import Settings"), tr("That file isn't a settings file this version can read."))
+
+    def export_events(
+        self, fmt: str, path: str, since: Optional[float] = None, until: Optional[float] = None, relative: bool = False
+    ) -> Optional[int]:
+        """Write the touch history as CSV or JSON (see export.export_events()); returns how many events, None if it failed"""
+        try:
+            return export.export_events(self.event_store, fmt, path, since, until, relative)
+        except OSError as e:
+            print(f"Could not export the history: {e}")
+            return None
+
+    def _choose_history_file(self):
+        path, _ = QFileDialog.getSaveFileName(
+            self, tr("Export History"), str(Path.home() / "mindful-touch-history.csv"), "Spreadsheet (*.csv);;JSON (*.json)"
+        )
+        if not path:
+            return
+        fmt = "json" if path.lower().endswith(".json") else "csv"
+        answer = QMessageBox.question(self, tr("Export History"), tr("Leave out when each touch happened? Times become seconds after the first one."))
+        if self.export_events(fmt, path, relative=answer == QMessageBox.StandardButton.Yes) is None:
+            QMessageBox.warning(self, tr("Export History"), tr("The history could not be saved there."))
+
+    def set_health_export(self, enabled: bool):
+        """Opt in to (or out of) export_health()"""
+        self.update_settings({"health_export": bool(enabled)})
+
+    def export_health(self, target: str, path: str, since: Optional[float] = None) -> Optional[int]:
+        """Write breathing exercises and daily summaries for a health app (see health.TARGETS); returns how many entries
+
+        None if it failed, or if the user hasn't opted in with set_health_export().
+        """
+        if not self.settings["health_export"]:
+            print("Health export is off, not exporting")
+            return None
+        try:
+            return health.export_health(self.event_store, target, path, since)
+        except (OSError, ValueError) as e:
+            print(f"Could not export for {target}: {e}")
+            return None
+
+    def _choose_health_file(self):
+        if not self.settings["health_export"]:
+            answer = QMessageBox.question(
+                self,
+                tr("Export for Health"),
+                tr(
+                    "Export your completed breathing exercises and daily touch counts for Apple Health or Google Fit? "
+                    "They leave Mindful Touch in the file you save."
+                ),
+            )
+            if answer != QMessageBox.StandardButton.Yes:
+                return
+            self.set_health_export(True)
+        filters = {"Apple Health (*.json)": health.HEALTHKIT, "Google Fit (*.json)": health.GOOGLE_FIT}
+        path, chosen = QFileDialog.getSaveFileName(self, tr("Export for Health"), str(Path.home() / "mindful-touch-health.json"), ";;".join(filters))
+        if path and self.export_health(filters.get(chosen, health.HEALTHKIT), path) is None:
+            QMessageBox.warning(self, tr("Export for Health"), tr("The history could not be saved there."))
+
+    def generate_report(self, path: str, week: Optional[str] = None) -> Optional[str]:
+        """Write the HTML report of the week holding week (ISO date, this week by default); returns its path, None if it failed"""
+        try:
+            return str(report.generate_report(self.event_store, path, date.fromisoformat(week) if week else None))
+        except (OSError, ValueError) as e:
+            print(f"Could not write the weekly report: {e}")
+            return None
+
+    def _choose_report_file(self):
+        monday = report.week_start()
+        path, _ = QFileDialog.getSaveFileName(
+            self, tr("Weekly Report"), str(Path.home() / f"mindful-touch-report-{monday.isoformat()}.html"), "Web page (*.html)"
+        )
+        if not path:
+            return
+        if self.generate_report(path) is None:
+            QMessageBox.warning(self, tr("Weekly Report"), tr("The report could not be saved there."))
+            return
+        QDesktopServices.openUrl(QUrl.fromLocalFile(path))
+
+    def _on_settings_changed(self, changed: list):
+        if {"profiles", "active_profile"} & set(changed):
+            self._refresh_profile_menu()
+
+    def _refresh_profile_menu(self):
+        self.tray.set_profiles(sorted(self.settings["profiles"]), self.settings["active_profile"])
+
+    def _apply_settings(self, new: dict) -> list:
+        changed = sorted(key for key in new if new[key] != self.settings.get(key))
+        if not changed:
+            return changed
+        old, self.settings = self.settings, new
+        options = BackendOptions.from_settings(new)
+
+        # Only what differs goes to the running backend, as one command it acknowledges
+        pushed = self.backend.apply_options(options)
+        if "custom_regions" in pushed:
+            self.detection_panel.refresh_regions()
+        if "enabled_regions" in pushed:
+            for name, active in self.backend.region_map().items():
+                self.detection_panel.update_region_state(name, active)
+        if "alert_delay" in pushed:
+            self.detection_panel.set_contact_duration(options.alert_delay)
+        if "away_minutes" in changed:
+            self.session_monitor.idle_threshold = max(0.0, float(new["away_minutes"])) * 60
+        if "toggle_shortcut" in changed and not self.hotkey.register(new["toggle_shortcut"]):
+            self.hotkey.register(old["toggle_shortcut"])
+            self.settings["toggle_shortcut"] = old["toggle_shortcut"]
+            changed.remove("toggle_shortcut")
+        if "icon_badge" in changed:
+            self._update_icon_badge()
+        if any(key.startswith(("alert_", "escalation_", "region_alerts")) for key in changed):
+            self.alert_pipeline.configure(new)
+        if {"alert_sound", "region_sounds", "alert_volume", "audio_output"} & set(changed):
+            self.alert_sound.configure(new)
+        if {"speech_phrases", "alert_volume"} & set(changed):
+            self.spoken_alert.configure(new)
+        if {"language", "alert_templates"} & set(changed):
+            self.templates = AlertTemplates.from_settings(new)
+        if "language" in changed:
+            i18n.set_locale(new["language"])
+            self.tray.retranslate()
+            self.retranslate_menu()
+            self.streak_widget.retranslate()
+            self.preview_window.retranslate()
+        if any(key.startswith("edge_flash") for key in changed):
+            self.edge_flash.configure(new)
+        if "battery_max_fps" in changed and self.battery_saver_active:
+            self.backend.set_frame_rate(new["battery_max_fps"])
+        if "battery_saver" in changed and self.power_monitor.status:
+            self._on_power_changed(self.power_monitor.status)
+        if "active_hours" in changed:
+            self._check_active_hours()
+        if "history_days" in changed:
+            self._prune_history()
+        if "goals" in changed:
+            self.goal_tracker.configure(new["goals"])
+        if {"quiet_apps", "quiet_fullscreen", "record_foreground_app"} & set(changed):
+            self._update_foreground_monitor()
+        # The camera itself can only change with a restart
+        if "camera_index" in changed and self.is_detecting:
+            self.restart_detection()
+        self.settings_changed.emit(changed)
+        return changed
+
+    def set_toggle_shortcut(self, shortcut: str) -> bool:
+        """Change the global pause/resume shortcut; the old one stays if the new one can't be registered"""
+        self.update_settings({"toggle_shortcut": shortcut})
+        return self.settings["toggle_shortcut"] == shortcut
+
+    def _on_toggle_shortcut(self):
+        """Pause or resume from anywhere (say, when an alert goes off while screen sharing)"""
+        if self.is_detecting:
+            self.set_suspended(not self.backend.suspended)
+            active = not self.backend.suspended
+            message = tr("Detection resumed") if active else tr("Detection paused")
+        else:
+            self.start_detection()
+            active = self.is_detecting
+            message = tr("Detection started") if active else tr("Detection could not start")
+        self.tray.showMessage("Mindful Touch", message)
+        self.detection_toggled.emit(active)
+
+    def _refresh_tray(self, _=None):
+        self.tray.set_state(self.is_detecting, self.backend.suspended)
+        self.mini_controls.set_state(self.is_detecting, self.backend.suspended)
+        self.status_overlay.set_state(self.is_detecting, self.backend.suspended)
+        self.streak_widget.set_state(self.is_detecting, self.backend.suspended)
+        self._refresh_preview_message()
+
+    def toggle_overlay(self, _=None) -> bool:
+        """Show or hide the status overlay; returns whether it is now shown"""
+        visible = not self.status_overlay.isVisible()
+        self.status_overlay.setVisible(visible)
+        self.overlay_action.setChecked(visible)
+        self.update_settings({"status_overlay": visible})
+        if visible:
+            self._refresh_overlay_counts()
+        return visible
+
+    def open_widget(self) -> bool:
+        """Show the streak widget in its corner of the screen it was last on; it stays up while the main window is closed"""
+        self.streak_widget.set_state(self.is_detecting, self.backend.suspended)
+        self.streak_widget.set_streak(self.streaks.status()["current"])
+        self.streak_widget.pin(self.settings["widget_corners"], self.settings["widget_screen"])
+        self.streak_widget.show()
+        self.widget_action.setChecked(True)
+        if not self.settings["widget"]:
+            self.update_settings({"widget": True})
+        return True
+
+    def close_widget(self):
+        self.streak_widget.hide()
+        self.widget_action.setChecked(False)
+        self.update_settings({"widget": False})
+
+    def _on_widget_pinned(self, screen: str, corner: str):
+        self.update_settings({"widget_screen": screen, "widget_corners": {**self.settings["widget_corners"], screen: corner}})
+
+    def open_preview(self) -> bool:
+        """Show the annotated camera frames in the preview window, subscribing it to them; returns True"""
+        if not self.preview_subscribed:
+            self.backend.frame_ready.connect(self._on_preview_frame)
+            self.preview_subscribed = True
+        self._refresh_preview_message()
+        self.preview_window.show()
+        self.preview_window.raise_()
+        self._update_subscriptions()
+        return True
+
+    def close_preview(self):
+        self.preview_window.close()  # The subscription goes in _on_preview_closed
+
+    def _on_preview_closed(self):
+        """However the window went away, stop feeding it, and stop the frames if nothing else shows them"""
+        if self.preview_subscribed:
+            self.backend.frame_ready.disconnect(self._on_preview_frame)
+            self.preview_subscribed = False
+        self._update_subscriptions()
+
+    def _on_preview_frame(self, frame):
+        try:
+            if self.is_detecting and self.show_feed and frame is not None:
+                self.preview_window.update_frame(self._frame_pixmap(frame))
+        except Exception as e:
+            print(f"Error updating the preview window: {e}")
+
+    def _refresh_preview_message(self):
+        """What the preview window says until a frame arrives, and while none can"""
+        if not self.is_detecting:
+            self.preview_window.set_message(tr("Start detection to see what the detector sees."))
+        elif self.backend.suspended:
+            self.preview_window.set_message(tr("Detection is suspended."))
+        elif not self.show_feed:
+            self.preview_window.set_message(tr("The camera feed is hidden."))
+        else:
+            self.preview_window.set_message(tr("Waiting for the camera…"))
+
+    def _on_overlay_moved(self, x, y):
+        self.update_settings({"status_overlay_position": [x, y]})
+
+    def _refresh_overlay_counts(self):
+        self.status_overlay.set_counts(self.touches_today, self.bridge.current_clean_streak())
+
+    def quit_app(self):
+        """Really quit: stop detection and close, even when closing normally hides to the tray"""
+        self.quitting = True
+        self.close()
+
+    def set_autostart(self, enabled: bool, silent: bool) -> bool:
+        """Launch at login, optionally straight into the tray with detection running"""
+        ok = autostart.enable(silent) if enabled else autostart.disable()
+        if ok:
+            self.update_settings({"autostart": bool(enabled), "silent_start": bool(silent)})
+        self.autostart_action.setChecked(self.settings["autostart"])
+        self.silent_start_action.setChecked(self.settings["silent_start"])
+        self.silent_start_action.setEnabled(self.settings["autostart"])
+        return ok
+
+    def set_close_to_tray(self, enabled):
+        self.update_settings({"close_to_tray": bool(enabled)})
+
+    def set_edge_flash(self, enabled, color: Optional[str] = None, duration_ms: Optional[int] = None, intensity: Optional[float] = None):
+        """Turn the screen-edge flash on or off, optionally restyling it"""
+        changes = {"edge_flash": bool(enabled)}
+        for key, value in (("edge_flash_color", color), ("edge_flash_duration_ms", duration_ms), ("edge_flash_intensity", intensity)):
+            if value is not None:
+                changes[key] = value
+        self.update_settings(changes)
+
+    def set_icon_badge(self, enabled):
+        self.update_settings({"icon_badge": bool(enabled)})
+
+    def set_record_foreground_app(self, enabled):
+        """Opt in (or out) of noting which app had focus with each touch; what's recorded already stays"""
+        self.update_settings({"record_foreground_app": bool(enabled)})
+
+    def _roll_over_day(self):
+        """Start today's touch count from 0 once the day it was counted on is over"""
+        today = date.today()
+        if self.touches_day != today:
+            self.touches_day, self.touches_today = today, 0
+
+    def _update_icon_badge(self):
+        """Today's touch count on the Dock icon (macOS), taskbar button (Windows) or launcher (Unity)"""
+        self._roll_over_day()
+        # 0 clears the badge
+        QApplication.setBadgeNumber(self.touches_today if self.settings["icon_badge"] else 0)
+
+    def _count_touch(self, alerting):
+        # The bridge counts a touch each time an alert starts
+        if alerting:
+            self._roll_over_day()
+            self.touches_today += 1
+            self._update_icon_badge()
+            self._refresh_overlay_counts()
+
+    def set_menu_bar_mode(self, enabled: bool) -> bool:
+        """macOS: live only in the menu bar (no Dock icon); returns whether the switch happened"""
+        # Without a tray icon there'd be no way back to the window
+        changed = (self.tray.isVisible() or not enabled) and macos.set_dock_icon_visible(not enabled)
+        if changed:
+            self.update_settings({"menu_bar_only": bool(enabled)})
+        self.tray.menu_bar_action.setChecked(self.settings["menu_bar_only"])
+        return changed
+
+    def on_second_launch(self, args):
+        """Mindful Touch was launched again and handed us its arguments instead of starting"""
+        print(f"Second launch with arguments {args}")
+        links = [arg for arg in args if arg.startswith(f"{deep_links.SCHEME}://")]
+        if links:
+            for url in links:
+                self.handle_deep_link(url)
+        elif autostart.SILENT_FLAG in args:
+            self.start_detection()
+        else:
+            self.show_dashboard()
+
+    def handle_deep_link(self, url: str) -> bool:
+        """Run a mindfultouch:// link through the same handlers as the menus; False if it isn't understood"""
+        link = deep_links.parse(url)
+        if link is None:
+            print(f"Ignoring unknown link {url}")
+            return False
+        action, params = link
+        print(f"Deep link: {action} {params}")
+        if action == "pause":
+            self.set_suspended(True)
+            if params.get("minutes") and self.backend.suspended:
+                self.pause_timer.start(int(params["minutes"] * 60 * 1000))
+        elif action == "resume":
+            self.pause_timer.stop()
+            self.set_suspended(False)
+        elif action == "start":
+            self.start_detection()
+        elif action == "stop":
+            self.stop_detection()
+        elif action == "snooze":
+            self.snooze_alerts(params.get("minutes", SNOOZE_MINUTES))
+        elif action == "unsnooze":
+            self.unsnooze_alerts()
+        else:
+            # "open", and "stats" (session stats are on the dashboard)
+            self.show_dashboard()
+        return True
+
+    def eventFilter(self, obj, event):
+        # macOS hands URLs to the running app as a FileOpen event rather than as arguments
+        if event.type() == QEvent.Type.FileOpen and event.url().scheme() == deep_links.SCHEME:
+            self.handle_deep_link(event.url().toString())
+            return True
+        return super().eventFilter(obj, event)
+
+    def show_dashboard(self):
+        """Bring the main window to the front, restoring it if minimized"""
+        self.mini_controls.hide()
+        self.showNormal()
+        self.raise_()
+        self.activateWindow()
+        self._update_subscriptions()
+
     def _show_about(self):
         QMessageBox.about(
             self,
@@ -253,14 +1104,60 @@ class MainWindow(QMainWindow):
             "Mindful Touch v1.0\n\n"
             "A gentle awareness tool that helps you notice\n"
             "unconscious face-touching habits.\n\n"
-            "All processing happens locally on your device.\n"
-            "No data is collected or transmitted.",
+            "All processing happens locally on your device,\n"
+            "and camera frames are never saved.\n\n"
+            "Your touch history is kept on this computer, in\n"
+            "~/.mindful-touch, and is never sent anywhere. It\n"
+            "only leaves it in files you export yourself: the\n"
+            "history, a weekly report, a backup, or data for\n"
+            "Apple Health / Google Fit.",
+        )
+
+    def _show_logs(self):
+        """Open the live log console"""
+        if self.log_console is None:
+            self.log_console = LogConsole(self)
+        self.log_console.show()
+        self.log_console.raise_()
+        self.log_console.activateWindow()
+
+    def warn_previous_instance(self, pid):
+        """A Mindful Touch process from an earlier run is still alive and likely holds the camera"""
+        print(f"Previous instance still running (pid {pid})")
+        answer = QMessageBox.question(
+            self,
+            tr("Mindful Touch is already running"),
+            tr("An earlier Mindful Touch (process {pid}) is still running and may be holding the camera.\n\nQuit it now?", pid=pid),
         )
+        if answer == QMessageBox.StandardButton.Yes:
+            instance_lock.terminate(pid)
 
     def connect_signals(self):
-        # Camera thread signals
-        self.camera_thread.frame_ready.connect(self.update_camera)
-        self.camera_thread.detection_data.connect(self.update_detection)
+        # Backend signals
+        self.backend.frame_ready.connect(self.update_camera)
+        self.backend.detection_data.connect(self.update_detection)
+        self.backend.stopped_unexpectedly.connect(self._on_backend_stopped)
+        self.backend.backend_crashed.connect(self._on_backend_crashed)
+        self.backend.ready.connect(self._on_backend_ready)
+        self.backend.startup_failed.connect(self._on_backend_startup_failed)
+        self.backend.restarted.connect(self._on_backend_restarted)
+        self.backend.restart_failed.connect(self._on_backend_restart_failed)
+        self.backend.options_applied.connect(lambda names: print(f"Backend applied: {', '.join(names)}"))
+        self.backend.status_changed.connect(self._on_backend_status_changed)
+        self.backend.backend_metrics.connect(self._on_backend_metrics)
+        self.backend.protocol_error.connect(self._on_protocol_error)
+        self.backend.state_changed.connect(self._refresh_tray)
+        self.camera_permission_denied.connect(self._show_camera_permission_help)
+
+        # Session events derived from the detection stream
+        self.bridge.touch_detected.connect(self.alert_pipeline.submit)
+        self.alert_pipeline.alert.connect(self._on_touch_detected)
+        self.bridge.hand_near_face.connect(self._refresh_alert_state)
+        self.bridge.alert_changed.connect(self._refresh_alert_state)
+        self.bridge.alert_changed.connect(self._count_touch)
+        self.bridge.mindful_stop.connect(self._on_mindful_stop)
+        self.bridge.session_stats.connect(self._on_session_stats)
+        self.bridge.touch_episode.connect(self._on_touch_episode)
 
         # Panel signals
         self.detection_panel.region_toggled.connect(self.toggle_region)
@@ -276,16 +1173,101 @@ class MainWindow(QMainWindow):
         else:
             self.start_detection()
 
+    def _on_backend_ready(self):
+        """First frame processed — the model and camera are actually up"""
+        if self.is_detecting:
+            print("Detection backend ready")
+            self.status_badge.set_status("detecting")
+
+    def _on_backend_startup_failed(self, error):
+        """Start failed or timed out (failed automatic restarts are handled by the supervisor)"""
+        if self.is_detecting:
+            self._abort_start(error)
+
+    def _abort_start(self, error):
+        """Back out of a start that never became ready and explain why"""
+        print(f"Failed to start detection ({error.kind}): {error}")
+        self.stop_detection()
+        self.status_badge.set_status("error")
+        QMessageBox.warning(self, tr("Could not start detection"), f"{error}\n\n{error.hint}")
+
+    def _on_backend_stopped(self, reason):
+        """Camera thread died on its own; the supervisor takes care of restarting it"""
+        if self.is_detecting:
+            self.status_badge.set_status("error")
+            self.set_flash_state("none")
+
+    def _on_backend_crashed(self, crash):
+        """Say from the tray that the camera thread crashed, and where its output went"""
+        if self.is_detecting:
+            self.status_badge.set_status("error")
+            message = tr(
+                "Detection crashed ({error}) and is restarting. The crash report is in {path}", error=crash["error_type"], path=str(logs.LOG_PATH)
+            )
+            self.tray.showMessage("Mindful Touch", message)
+
+    def _on_backend_restarted(self, attempt):
+        """Detection is back after an automatic restart"""
+        if self.is_detecting:
+            self.status_badge.set_status("detecting")
+
+    def _on_backend_status_changed(self, state):
+        """Reflect a stalled camera thread in the status badge"""
+        print(f"Backend status: {state}")
+        if self.is_detecting and state == "unresponsive":
+            self.status_badge.set_status("error")
+            self.set_flash_state("none")
+
+    def _on_backend_metrics(self, metrics):
+        """Show frame rate and resource usage next to the camera feed"""
+        if not self.is_detecting:
+            return
+        parts = [f"{metrics['fps']:.0f} fps", f"{metrics['cpu_percent']:.0f}% CPU"]
+        if metrics.get("memory_mb") is not None:
+            parts.append(f"{metrics['memory_mb']:.0f} MB")
+        self.camera_panel.set_metrics_text(" · ".join(parts))
+
+    def _on_backend_restart_failed(self, reason):
+        """Automatic restarts exhausted — stop cleanly and tell the user"""
+        if not self.is_detecting:
+            return
+        crash = self.backend.last_crash
+        self.stop_detection()
+        self.status_badge.set_status("error")
+        self.tray.showMessage("Mindful Touch", tr("Detection stopped and could not be restarted automatically."))
+
+        message = QMessageBox(self)
+        message.setIcon(QMessageBox.Icon.Warning)
+        message.setWindowTitle(tr("Detection stopped"))
+        message.setText(tr("Detection stopped and could not be restarted automatically.") + f"\n\n{reason}")
+        message.setInformativeText(tr("Check that no other app is using the camera, then start detection again."))
+        if crash:
+            # The crash report goes behind "Show Details…" for bug reports
+            details = [f"{crash['error_type']}: {crash['reason']}", f"Frames processed: {crash['frames_processed']}", f"Log: {logs.LOG_PATH}", ""]
+            message.setDetailedText("\n".join(details + crash["stderr"]))
+        message.exec()
+
+    def _on_protocol_error(self, error):
+        """The backend sent something this UI doesn't understand — stop rather than guess"""
+        if not self.is_detecting:
+            return
+        self.stop_detection()
+        self.status_badge.set_status("error")
+
+        message = QMessageBox(self)
+        message.setIcon(QMessageBox.Icon.Warning)
+        message.setWindowTitle(tr("Detection stopped"))
+        message.setText(tr("The detection backend sent a message this version can't read.") + f"\n\n{error['error']}")
+        message.setInformativeText(tr("Reinstalling Mindful Touch usually fixes this."))
+        message.setDetailedText(f"Camera: {error['instance']}\n\n{error['payload']!r}")
+        message.exec()
+
     def update_camera(self, frame):
         """Update camera display with error handling"""
         try:
             # Only update camera if detection is running
             if self.is_detecting and frame is not None:
-                height, width, channel = frame.shape
-                bytes_per_line = 3 * width
-                q_image = QImage(frame.data, width, height, bytes_per_line, QImage.Format.Format_RGB888).rgbSwapped()
-
-                pixmap = QPixmap.fromImage(q_image)
+                pixmap = self._frame_pixmap(frame)
                 # Scale to fit the actual camera label size
                 label_size = self.camera_panel.camera_label.size()
                 scaled_pixmap = pixmap.scaled(label_size, Qt.AspectRatioMode.KeepAspectRatio, Qt.TransformationMode.SmoothTransformation)
@@ -294,62 +1276,542 @@ class MainWindow(QMainWindow):
             print(f"Error updating camera display: {e}")
             # Don't crash the app on camera display errors
 
+    @staticmethod
+    def _frame_pixmap(frame) -> QPixmap:
+        """A BGR frame from the backend as a pixmap"""
+        height, width, channel = frame.shape
+        bytes_per_line = 3 * width
+        q_image = QImage(frame.data, width, height, bytes_per_line, QImage.Format.Format_RGB888).rgbSwapped()
+        return QPixmap.fromImage(q_image)
+
     def update_detection(self, data):
-        """Update detection data with error handling"""
+        """Hand detection data to the bridge, which emits only what changed"""
         try:
-            if not data or not self.is_detecting:
-                return
+            if self.is_detecting:
+                self.bridge.process(data)
+                if data["face_detected"] and data["hands_detected"] and onboarding.CALIBRATION not in self.settings["onboarding_steps"]:
+                    self.complete_step(onboarding.CALIBRATION)
+        except Exception as e:
+            print(f"Error updating detection data: {e}")
+            # Don't crash the app on detection update errors
+
+    def _on_touch_detected(self, regions, actions=None):
+        """Alert as loudly as the escalation policy says (everything when actions isn't given)"""
+        actions = ACTIONS if actions is None else actions
+        if PULSE in actions:
+            self.tray.pulse()
+        if self.snooze_timer.isActive() or not self.breathing_window.done:
+            return
+        window = schedule.active_window(self.settings["quiet_hours"])
+        if window:
+            print(f"Alert for {', '.join(regions)} kept quiet (quiet hours {window['start']}-{window['end']})")
+            return
+        if self.focus_monitor.active:
+            self.quiet_alerts.append(regions)
+            return
+        rule = foreground.matching_rule(self.foreground_monitor.current, self.settings["quiet_apps"], self.settings["quiet_fullscreen"])
+        if rule:
+            print(f"Alert for {', '.join(regions)} kept quiet ({rule} in the foreground)")
+            return
+        if NOTIFY in actions:
+            self.tray.showMessage(self._alert_text("notify_title", regions), self._alert_text("notify_body", regions))
+        if SOUND in actions:
+            self._play_alert_sound(regions)
+        if SPEAK in actions:
+            phrase = self.spoken_alert.next_phrase() or self.templates.template("speech")
+            self.spoken_alert.say(self._alert_text(phrase, regions, literal=True))
+        if TOAST in actions:
+            self.alert_toast.show_alert(regions, self._alert_text("toast_title", regions), self._alert_text("toast_detail", regions))
+        if self.settings["edge_flash"]:
+            self.edge_flash.flash()
+        self.alert_outcomes.alert_shown(regions, actions)
+
+    def _alert_text(self, key, regions, literal=False) -> str:
+        """A template (or, with literal, the given text) with {region}, {count_today}, {streak} filled in"""
+        values = {
+            "count_today": self.touches_today,
+            "streak": format_duration(self.bridge.current_clean_streak()),
+            "session_touches": self.bridge.touches,
+        }
+        if literal:
+            return self.templates.render_text(key, regions, **values)
+        return self.templates.render(key, regions, **values)
+
+    def set_alert_template(self, key: str, template: str) -> Optional[str]:
+        """Customize one alert text ("" restores the default); returns what's wrong with it, or None once saved"""
+        if key not in TEMPLATE_KEYS:
+            return f"unknown template {key}"
+        problem = validate(template)
+        if problem:
+            return problem
+        templates = {name: text for name, text in self.settings["alert_templates"].items() if name != key}
+        if template:
+            templates[key] = template
+        self.update_settings({"alert_templates": templates})
+        return None
+
+    def set_locale(self, language: str) -> str:
+        """Language of the alert wording, tray menu, notifications and dialogs ("" = the system's); returns the one in use"""
+        self.update_settings({"language": language})
+        return i18n.locale()
+
+    def _on_contact_changed(self, near):
+        # The touch an alert fired for has ended: the alert worked if that was soon enough
+        if not near:
+            self.alert_outcomes.contact_ended()
+
+    def get_alert_efficacy(self) -> dict:
+        """See alert_outcomes.efficacy(), over every alert recorded so far"""
+        return efficacy(self.alert_outcomes.load())
+
+    def _record_episode(self, episode):
+        """Into the history, with the app in the foreground if the user opted in to that"""
+        if self.settings["record_foreground_app"]:
+            window = self.foreground_monitor.current
+            episode = {**episode, "app": window["app"] if window and window["app"] else None}
+        self.event_store.add(episode)
+
+    def query_events(self, since: Optional[float] = None, until: Optional[float] = None, **filters) -> list:
+        """Recorded touch episodes that started in [since, until); filters as in EventStore.query()"""
+        return self.event_store.query(since, until, **filters)
+
+    def add_annotation(self, timestamp_range, text: str, tags=()) -> Optional[int]:
+        """Note what was going on over (start, end) in Unix times, with tags; returns its id, None if it was refused"""
+        start, end = timestamp_range
+        try:
+            return annotations.add(self.event_store, start, end, text, tags)
+        except ValueError as e:
+            print(f"Annotation not added: {e}")
+            return None
+
+    def remove_annotation(self, annotation_id: int) -> bool:
+        return annotations.remove(self.event_store, annotation_id)
+
+    def query_annotations(self, since: Optional[float] = None, until: Optional[float] = None, tags=None) -> list:
+        """Annotations overlapping [since, until), optionally only those with any of tags"""
+        return annotations.query(self.event_store, since, until, tags)
+
+    def get_stats_summary(self, period: str = "day") -> dict:
+        """This day, week or month so far next to the one before and, once there's one, the baseline
 
-            # Update visual flash state
-            regions_with_contact = data.get("regions_with_contact", [])
-            region_details = data.get("region_details", {})
-            alerts_active = data.get("alerts_active", [])
-            mindful_stops_detected = data.get("mindful_stops_detected", [])
-
-            # Play sound when alerts are triggered (with proper cooldown from backend)
-            if alerts_active:
-                self._play_alert_sound()
-
-            # Check if any regions have active alerts
-            active_alert_regions = [region for region, details in region_details.items() if details.get("alert_active", False)]
-            current_alert_state = len(active_alert_regions) > 0
-
-            # Track session statistics
-            if current_alert_state and not self.last_alert_state:
-                # New alert triggered
-                self.total_detections += 1
-            elif self.last_alert_state and not current_alert_state:
-                # Alert ended (also count as mindful stop)
-                self.mindful_stops += 1
-
-            # Count quick hand removals as mindful stops
-            if mindful_stops_detected:
-                self.mindful_stops += len(mindful_stops_detected)
-                self.camera_panel.show_mindful_stop_flash()
-                print(f"Mindful stop detected in regions: {mindful_stops_detected}")
-
-            self.last_alert_state = current_alert_state
-
-            # Update status badge
-            if active_alert_regions:
-                self.status_badge.set_status("alert")
-                self.set_flash_state("red")
-            elif regions_with_contact:
-                self.status_badge.set_status("detecting")
-                self.set_flash_state("orange")
+        See summary.summarize(); "baseline" is baseline.compare() (per day, None without a complete baseline).
+        """
+        result = summary.summarize(self.event_store, period)
+        result["baseline"] = baseline.compare(self.event_store, result, result["start"], result["end"])
+        return result
+
+    def set_baseline_period(self, days: int, start_day: Optional[str] = None) -> Optional[dict]:
+        """Measure progress against days days from start_day (ISO date; by default the first day of use), 0 for none"""
+        try:
+            start = date.fromisoformat(start_day) if start_day else None
+        except ValueError as e:
+            print(f"Baseline not set: {e}")
+            return None
+        period = baseline.set_period(self.event_store, days, start)
+        self.goal_tracker.evaluate()
+        return period
+
+    def get_baseline(self) -> Optional[dict]:
+        """The baseline period with its daily averages ("daily_average", None until it's over), None if there's none"""
+        period = baseline.period(self.event_store)
+        return None if period is None else {**period, "daily_average": baseline.daily_average(self.event_store)}
+
+    def get_heatmap(self, since: Optional[float] = None, until: Optional[float] = None) -> dict:
+        """Touches per region and hour of day in [since, until); see summary.heatmap()"""
+        return summary.heatmap(self.event_store, since, until)
+
+    def get_trends(self, window_days: int = 28) -> dict:
+        """Daily touch counts over the last window_days days and whether they're going down; see trends.trends()"""
+        return trends.trends(self.event_store, window_days)
+
+    def _prune_history(self):
+        retention.prune(self.event_store, self.settings["history_days"])
+
+    def get_storage_usage(self) -> dict:
+        """How big the detection history is and what it holds; see retention.storage_usage()"""
+        return retention.storage_usage(self.event_store)
+
+    def start_session(self, label: str = "") -> Optional[int]:
+        """Bracket a work block (not the same as detecting): touches from now on count towards it; returns its id"""
+        session_id = sessions.start(self.event_store, label)
+        if session_id is not None:
+            print(f"Session {session_id} started{f' ({label})' if label else ''}")
+        return session_id
+
+    def end_session(self) -> Optional[dict]:
+        """End the work session in progress; returns its summary (None if there was none)"""
+        session_id = sessions.end(self.event_store)
+        return None if session_id is None else sessions.summary(self.event_store, session_id)
+
+    def get_session_summary(self, session_id: int) -> Optional[dict]:
+        """Duration, touches, average time to respond and longest streak of a work session; see sessions.summary()"""
+        return sessions.summary(self.event_store, session_id)
+
+    def list_sessions(self) -> list:
+        return sessions.list_sessions(self.event_store)
+
+    def set_goals(self, goals: list) -> list:
+        """Replace the daily goals ({"kind", "target"}, see goals.KINDS); returns those kept, invalid ones left out"""
+        kept = valid_goals(goals)
+        self.update_settings({"goals": kept})
+        return kept
+
+    def get_goal_progress(self) -> list:
+        """Today's progress on each goal; see GoalTracker"""
+        return self.goal_tracker.evaluate()
+
+    def get_goal_outcomes(self, since_day: Optional[str] = None) -> list:
+        """How each day's goals went, from since_day (ISO date) on"""
+        return self.goal_tracker.outcomes(since_day)
+
+    def _on_goal_achieved(self, goal):
+        if goal["kind"] == MAX_TOUCHES:
+            text = tr("Fewer than {count} touches today", count=f"{goal['target']:g}")
+        elif goal["kind"] == BASELINE_PERCENT:
+            text = tr("{percent}% fewer touches than your baseline", percent=f"{goal['target']:g}")
+        else:
+            text = tr("At least one {minutes}-minute streak", minutes=f"{goal['target']:g}")
+        print(f"Goal reached: {goal['kind']} {goal['target']:g}")
+        self.tray.showMessage(tr("Goal reached"), text)
+
+    def get_achievements(self) -> list:
+        """Every achievement ({"id", "title", "description", "unlocked_at"}), unlocked_at None for those still locked"""
+        return self.achievements.achievements()
+
+    def _on_achievement_unlocked(self, achievement):
+        print(f"Achievement unlocked: {achievement['id']}")
+        self.tray.showMessage(tr("Achievement unlocked"), f"{tr(achievement['title'])}: {tr(achievement['description'])}")
+
+    def get_live_stats(self) -> dict:
+        """What stats_tick carries: live.snapshot() with the detection session so far ("detection") and the backend's state"""
+        return {
+            **live.snapshot(self.event_store, self.streaks),
+            "detection": self.bridge.summary(),
+            "detecting": self.is_detecting,
+            "backend": self.backend.status()["state"],
+            "snoozed": self.snooze_timer.isActive(),
+        }
+
+    def _emit_stats_tick(self):
+        # Nothing to work out while no dashboard is listening
+        if self.receivers(self.stats_tick):
+            self.stats_tick.emit(self.get_live_stats())
+
+    def get_streak(self) -> dict:
+        """The clean streak in progress and the best one so far; see StreakTracker"""
+        return self.streaks.status()
+
+    def _on_touch_episode(self, episode):
+        """A long touch: offer a breathing exercise, holding further alerts until it's closed"""
+        threshold = float(self.settings["breathing_after_seconds"])
+        if threshold > 0 and episode["duration"] >= threshold and self.breathing_window.done:
+            print(f"Touch lasted {episode['duration']:.0f}s, offering a breathing exercise")
+            self.alert_toast.hide()
+            self.breathing_window.start()
+
+    def _on_breathing_finished(self, completed):
+        print(f"Breathing exercise {'completed' if completed else 'skipped'}")
+        self.bridge.record_breathing(completed)
+        if completed:
+            health.record_intervention(self.event_store, health.BREATHING, self.breathing_window.total_seconds())
+
+    def _on_alert_action(self, action):
+        """A button on the alert toast: the bridge records the label, snoozing also mutes alerts for a while"""
+        self.bridge.label_touch(action)
+        if action == SNOOZE:
+            self.snooze_alerts(SNOOZE_MINUTES)
+
+    def snooze_alerts(self, minutes: float):
+        """No sound or toast for a while; detection and counting carry on"""
+        print(f"Alerts snoozed for {minutes:g} min")
+        self.snooze_timer.start(int(minutes * 60 * 1000))
+        self.snooze_tick.start(30 * 1000)
+        self._refresh_snooze_indicator()
+
+    def unsnooze_alerts(self):
+        """End a snooze early"""
+        if self.snooze_timer.isActive():
+            print("Snooze ended early")
+            self.snooze_timer.stop()
+        self.snooze_tick.stop()
+        self._refresh_snooze_indicator()
+
+    def get_snooze_state(self) -> dict:
+        """{"active": bool, "remaining_seconds": int}"""
+        active = self.snooze_timer.isActive()
+        return {"active": active, "remaining_seconds": max(0, self.snooze_timer.remainingTime()) // 1000 if active else 0}
+
+    def _on_snooze_over(self):
+        print("Snooze over, alerts are back on")
+        self.snooze_tick.stop()
+        self._refresh_snooze_indicator()
+        self.tray.showMessage("Mindful Touch", tr("Alerts resumed"))
+
+    def _refresh_snooze_indicator(self):
+        state = self.get_snooze_state()
+        # Rounded up, so the last minute reads "1 min left" rather than 0
+        minutes_left = -(-state["remaining_seconds"] // 60) if state["active"] else None
+        self.tray.set_snooze(minutes_left)
+        self.mini_controls.set_snooze(minutes_left)
+
+    def get_custom_regions(self) -> dict:
+        return {region: dict(definition) for region, definition in self.settings["custom_regions"].items()}
+
+    def add_custom_region(self, region: str, definition: Optional[dict] = None) -> bool:
+        """Define (or redefine) a region of your own and start watching it; without a definition, the preset of that id"""
+        cleaned = custom_regions.validate(region, definition if definition is not None else custom_regions.PRESETS.get(region))
+        if cleaned is None:
+            print(f"Invalid custom region {region}: {definition!r}")
+            return False
+        active = self.settings["active_regions"] + ([] if region in self.settings["active_regions"] else [region])
+        self.update_settings({"custom_regions": {**self.settings["custom_regions"], region: cleaned}, "active_regions": active})
+        return True
+
+    def remove_custom_region(self, region: str) -> bool:
+        """Forget a custom region, with its own alert settings and sound"""
+        if region not in self.settings["custom_regions"]:
+            return False
+        self.update_settings(
+            {
+                "custom_regions": {other: value for other, value in self.settings["custom_regions"].items() if other != region},
+                "active_regions": [other for other in self.settings["active_regions"] if other != region],
+                "region_alerts": {other: value for other, value in self.settings["region_alerts"].items() if other != region},
+                "region_sounds": {other: value for other, value in self.settings["region_sounds"].items() if other != region},
+            }
+        )
+        return True
+
+    def _populate_custom_region_menu(self):
+        """Presets not added yet, then the regions there are, to remove"""
+        self.custom_region_menu.clear()
+        for region, preset in custom_regions.PRESETS.items():
+            if region not in self.settings["custom_regions"]:
+                action = self.custom_region_menu.addAction(tr("Add {name}", name=preset["name"]))
+                action.triggered.connect(lambda _, region=region: self.add_custom_region(region))
+        if self.settings["custom_regions"]:
+            self.custom_region_menu.addSeparator()
+        for region, definition in self.settings["custom_regions"].items():
+            action = self.custom_region_menu.addAction(tr("Remove {name}", name=definition["name"]))
+            action.triggered.connect(lambda _, region=region: self.remove_custom_region(region))
+
+    def get_region_alerts(self) -> dict:
+        """Each region's own alert settings, with its sound; regions without any are left out"""
+        regions = {region: dict(config) for region, config in self.settings["region_alerts"].items()}
+        for region, value in self.settings["region_sounds"].items():
+            regions.setdefault(region, {})["sound"] = value
+        return regions
+
+    def set_region_alert(self, region: str, **config) -> bool:
+        """Give one region its own enabled/actions/cooldown/quiet_hours (None resets one to the general setting) or sound"""
+        if region not in Config.all_regions():
+            return False
+        if "sound" in config and not self.set_alert_sound(config.pop("sound") or "", region=region):
+            return False
+        current = dict(self.settings["region_alerts"].get(region, {}))
+        for key, value in config.items():
+            if key not in ("enabled", "actions", "cooldown", "quiet_hours"):
+                print(f"Unknown region alert setting {key}")
+                return False
+            if value is None:
+                current.pop(key, None)
             else:
-                if self.is_detecting:
-                    self.status_badge.set_status("detecting")
-                else:
-                    self.status_badge.set_status("ready")
-                self.set_flash_state("none")
+                current[key] = value
+        cleaned = RegionAlert.from_settings(current)
+        if len(cleaned.quiet_hours) != len(current.get("quiet_hours", [])):
+            print(f"Invalid quiet hours for {region}")
+            return False
+        region_alerts = {name: other for name, other in self.settings["region_alerts"].items() if name != region}
+        if current:
+            region_alerts[region] = current
+        self.update_settings({"region_alerts": region_alerts})
+        return True
+
+    def get_quiet_hours(self) -> list:
+        return [dict(window) for window in self.settings["quiet_hours"]]
+
+    def set_quiet_hours(self, windows) -> bool:
+        """Replace the quiet-hours schedule; refused as a whole if any window is invalid"""
+        cleaned = [schedule.validate_window(window) for window in windows]
+        if None in cleaned:
+            print(f"Invalid quiet hours {windows!r}")
+            return False
+        self.update_settings({"quiet_hours": cleaned})
+        return True
 
-            # Update activity stats
-            self.camera_panel.update_stats(self.total_detections, self._get_session_seconds(), self.mindful_stops)
+    def add_quiet_hours(self, start: str, end: str, days=None) -> bool:
+        return self.set_quiet_hours(self.settings["quiet_hours"] + [{"start": start, "end": end, "days": days or schedule.ALL_DAYS}])
 
-        except Exception as e:
-            print(f"Error updating detection data: {e}")
-            # Don't crash the app on detection update errors
+    def remove_quiet_hours(self, index: int) -> bool:
+        if not 0 <= index < len(self.settings["quiet_hours"]):
+            return False
+        return self.set_quiet_hours([window for i, window in enumerate(self.settings["quiet_hours"]) if i != index])
+
+    def get_active_hours(self) -> list:
+        return [dict(window) for window in self.settings["active_hours"]]
+
+    def set_active_hours(self, windows) -> bool:
+        """Replace the hours detection runs in (none = any time); refused as a whole if any window is invalid"""
+        cleaned = [schedule.validate_window(window) for window in windows]
+        if None in cleaned:
+            print(f"Invalid active hours {windows!r}")
+            return False
+        self.update_settings({"active_hours": cleaned})
+        return True
+
+    def override_schedule(self, minutes: float):
+        """Detect outside the active hours for a while, e.g. for an evening session (0 ends it early)"""
+        if minutes > 0:
+            print(f"Detecting outside the active hours for {minutes:g} min")
+            self.schedule_override.start(int(minutes * 60 * 1000))
+        else:
+            self.schedule_override.stop()
+        self._check_active_hours()
+
+    def get_schedule_state(self) -> dict:
+        """{"in_active_hours": bool, "override_seconds": int}"""
+        override = self.schedule_override.isActive()
+        return {
+            "in_active_hours": self.in_active_hours(),
+            "override_seconds": max(0, self.schedule_override.remainingTime()) // 1000 if override else 0,
+        }
+
+    def in_active_hours(self) -> bool:
+        windows = self.settings["active_hours"]
+        return not windows or self.schedule_override.isActive() or schedule.active_window(windows) is not None
+
+    def _check_active_hours(self):
+        """Suspend when the active hours end and pick up again when they begin"""
+        outside = not self.in_active_hours()
+        if outside == self.outside_active_hours:
+            return
+        self.outside_active_hours = outside
+        self.tray.set_outside_schedule(outside)
+        if outside:
+            if self.is_detecting and not self.backend.suspended:
+                print("Outside the active hours, suspending detection")
+                self.set_suspended(True)
+                self.suspended_for_schedule = self.backend.suspended
+        elif self.suspended_for_schedule:
+            self.suspended_for_schedule = False
+            print("Active hours began, resuming detection")
+            self.set_suspended(False)
+        elif not self.is_detecting and self.settings["active_hours"]:
+            print("Active hours began, starting detection")
+            self.start_automatically()
+
+    def get_foreground_app(self):
+        """See foreground.get_foreground_app(); the last one polled while quiet-app rules or the history are watching"""
+        if self.foreground_monitor.running:
+            return self.foreground_monitor.current
+        return foreground.get_foreground_app()
+
+    def _update_foreground_monitor(self):
+        """Poll the foreground app only while detecting with a quiet-app rule (fullscreen counts) that could match, or to record it"""
+        needed = any(rule.strip() for rule in self.settings["quiet_apps"]) or self.settings["quiet_fullscreen"] or self.settings["record_foreground_app"]
+        if self.is_detecting and needed:
+            self.foreground_monitor.start()
+        else:
+            self.foreground_monitor.stop()
+
+    def get_quiet_apps(self) -> list:
+        return list(self.settings["quiet_apps"])
+
+    def set_quiet_apps(self, rules) -> list:
+        """Replace the apps (names or window-title words) that keep alerts quiet while focused"""
+        cleaned = []
+        for rule in rules:
+            rule = rule.strip()
+            if rule and rule.lower() not in (existing.lower() for existing in cleaned):
+                cleaned.append(rule)
+        self.update_settings({"quiet_apps": cleaned})
+        return self.get_quiet_apps()
+
+    def add_quiet_app(self, rule: str) -> list:
+        return self.set_quiet_apps(self.settings["quiet_apps"] + [rule])
+
+    def remove_quiet_app(self, rule: str) -> list:
+        return self.set_quiet_apps([existing for existing in self.settings["quiet_apps"] if existing.lower() != rule.strip().lower()])
+
+    def get_dnd_state(self) -> dict:
+        return {"active": self.focus_monitor.active, "supported": self.focus_monitor.supported, "quiet_alerts": len(self.quiet_alerts)}
+
+    def _on_dnd_changed(self, active):
+        """Going quiet mutes the sound; coming back gives one summary of what was noticed meanwhile"""
+        print(f"Do Not Disturb {'on' if active else 'off'}")
+        self.tray.setToolTip(tr("Mindful Touch — quiet while Do Not Disturb is on") if active else "Mindful Touch")
+        if active or not self.quiet_alerts:
+            return
+        regions = sorted({region for alert in self.quiet_alerts for region in alert})
+        count = len(self.quiet_alerts)
+        self.quiet_alerts = []
+        noticed = tr("1 touch noticed ({regions})") if count == 1 else tr("{count} touches noticed ({regions})")
+        self.tray.showMessage(tr("While Do Not Disturb was on"), noticed.format(count=count, regions=", ".join(regions)))
+
+    def _refresh_alert_state(self, _=None):
+        """Badge and border follow the bridge: red while alerting, orange while touching"""
+        if not self.is_detecting:
+            return
+        if self.bridge.alert_active:
+            self.status_badge.set_status("alert")
+            self.set_flash_state("red")
+        elif self.bridge.contact:
+            self.status_badge.set_status("detecting")
+            self.set_flash_state("orange")
+        else:
+            self.status_badge.set_status("detecting")
+            self.set_flash_state("none")
+
+    def _on_mindful_stop(self, regions):
+        self.camera_panel.show_mindful_stop_flash()
+        print(f"Mindful stop detected in regions: {regions}")
+
+    def _on_session_stats(self, stats):
+        self.camera_panel.update_stats(stats["touches"], stats["session_seconds"], stats["mindful_stops"])
+        if self.touches_day != date.today():
+            self._update_icon_badge()
+        if self.status_overlay.isVisible():
+            self._refresh_overlay_counts()
+
+    def get_onboarding_state(self) -> dict:
+        """See onboarding.state()"""
+        return onboarding.state(self.settings["onboarding_steps"])
+
+    def complete_step(self, step: str) -> bool:
+        if step not in onboarding.STEPS:
+            print(f"Unknown onboarding step {step}")
+            return False
+        self.update_settings({"onboarding_steps": onboarding.complete(self.settings["onboarding_steps"], step)})
+        if self.get_onboarding_state()["finished"]:
+            print("First-run setup finished")
+        return True
+
+    def start_automatically(self):
+        """Start detection without being asked (e.g. at login), but only once first-run setup is done and in the active hours"""
+        step = onboarding.next_step(self.settings["onboarding_steps"])
+        if step is not None:
+            print(f"Setup not finished ({step} next), waiting for detection to be started by hand")
+            self.show_dashboard()
+            return
+        if not self.in_active_hours():
+            print("Outside the active hours, detection will start when they begin")
+            return
+        self.start_detection()
+
+    def check_camera_permission(self) -> str:
+        """macOS camera authorization (authorized, denied, restricted, not_determined), or unknown elsewhere"""
+        return macos.camera_authorization() or "unknown"
+
+    def _show_camera_permission_help(self, url):
+        message = QMessageBox(self)
+        message.setIcon(QMessageBox.Icon.Warning)
+        message.setWindowTitle(tr("Camera access needed"))
+        message.setText(tr("Mindful Touch isn't allowed to use the camera."))
+        message.setInformativeText(tr("Turn on Mindful Touch under Privacy & Security → Camera, then start detection again."))
+        open_button = message.addButton(tr("Open System Settings"), QMessageBox.ButtonRole.AcceptRole)
+        message.addButton(QMessageBox.StandardButton.Cancel)
+        message.exec()
+        if message.clickedButton() == open_button:
+            QDesktopServices.openUrl(QUrl(url))
 
     def start_detection(self):
         """Start detection process with UI state management"""
@@ -358,29 +1820,40 @@ class MainWindow(QMainWindow):
             print("Start detection ignored - already detecting or transitioning")
             return
 
+        # Opening a camera we may not use just fails without saying why; "not_determined" gets the system prompt
+        permission = self.check_camera_permission()
+        if permission in ("denied", "restricted"):
+            print(f"Camera permission {permission}, not starting detection")
+            self.camera_permission_denied.emit(macos.CAMERA_PRIVACY_URL)
+            return
+        if permission in ("authorized", "unknown") and onboarding.CAMERA_PERMISSION not in self.settings["onboarding_steps"]:
+            self.complete_step(onboarding.CAMERA_PERMISSION)
+
         try:
             print("Starting detection...")
             self.is_transitioning = True
 
             # Disable buttons during transition
             self._set_buttons_enabled(False)
-            self.status_badge.set_status("detecting")
+            self.status_badge.set_status("starting")
 
-            # Attempt to start camera thread
-            if self.camera_thread.start_detection():
-                # Success - update state
-                self.is_detecting = True
-                self.session_start_time = time.time()
-                self.total_detections = 0
-                self.mindful_stops = 0
-                self.last_alert_state = False
+            # Attempt to start camera thread; it reports ready once the first frame is processed
+            options = self._backend_options()
+            if self.backend.start(options):
+                for camera_index in self.settings["extra_cameras"]:
+                    self.backend.start_instance(f"camera-{camera_index}", replace(options, camera_index=camera_index))
 
-                # Start session timer
-                self.session_timer.start(1000)  # Update every second
+                # Success - update state; the bridge ticks the session clock
+                self.is_detecting = True
+                self.bridge.start_session()
+                self.streaks.start()
+                self.alert_pipeline.reset()
+                self._update_foreground_monitor()
 
                 # Update UI
                 self.camera_panel.set_detection_state(True)
                 self.detection_panel.set_detection_state(True)
+                self._refresh_tray()
                 print("Detection started successfully")
 
             else:
@@ -410,21 +1883,30 @@ class MainWindow(QMainWindow):
             # Disable buttons during transition
             self._set_buttons_enabled(False)
 
-            # Stop camera thread
-            self.camera_thread.stop_detection()
+            # Stop camera thread (and any pending automatic restart)
+            self.backend.stop()
 
             # Update state
             self.is_detecting = False
-            self.session_start_time = None
-
-            # Stop session timer
-            self.session_timer.stop()
+            self._update_foreground_monitor()
+            self.bridge.end_session()
+            self.streaks.stop()
+            if onboarding.FIRST_SESSION not in self.settings["onboarding_steps"]:
+                self.complete_step(onboarding.FIRST_SESSION)
 
             # Update UI
             self.camera_panel.set_detection_state(False)
             self.detection_panel.set_detection_state(False)
             self.status_badge.set_status("ready")
+            self.suspend_action.setChecked(False)
+            self.suspended_for_lock = False
+            self.suspended_for_battery = False
+            self.suspended_for_away = False
+            self.suspended_for_schedule = False
+            self.pause_timer.stop()
+            self._refresh_tray()
             self.show_feed = True
+            self._update_subscriptions()  # Next start shows the feed again
             self.set_flash_state("none")
             print("Detection stopped successfully")
 
@@ -435,6 +1917,108 @@ class MainWindow(QMainWindow):
             self.is_transitioning = False
             self._set_buttons_enabled(True)
 
+    def toggle_suspend(self):
+        self.set_suspended(not self.backend.suspended)
+
+    def set_suspended(self, suspended: bool):
+        """Release the camera without ending the session; resuming reopens it"""
+        if not self.is_detecting or self.is_transitioning:
+            self.suspend_action.setChecked(False)
+            self._refresh_tray()
+            return
+
+        if self.backend.suspended and not suspended:
+            if not self.backend.resume():
+                self._abort_start(StartupError(StartupError.UNKNOWN, "Could not resume the camera thread."))
+                return
+            self.camera_panel.set_suspended_state(False)
+            # A backend that went dormant while suspended has to load the model again
+            self.status_badge.set_status("starting" if self.backend.state == BackendManager.STARTING else "detecting")
+        elif suspended and not self.backend.suspended and self.backend.suspend():
+            self.bridge.reset_frame_state()
+            self.set_flash_state("none")
+            self.camera_panel.set_suspended_state(True)
+            self.status_badge.set_status("paused")
+        self.suspend_action.setChecked(self.backend.suspended)
+        self._refresh_tray()
+
+    def _on_screen_locked(self, locked):
+        """Nobody is at the screen: release the camera, and pick up again on unlock"""
+        if locked:
+            if self.is_detecting and not self.backend.suspended:
+                print("Screen locked, suspending detection")
+                self.set_suspended(True)
+                self.suspended_for_lock = self.backend.suspended
+        elif self.suspended_for_lock:
+            # A suspension the user chose themselves stays in place
+            self.suspended_for_lock = False
+            print("Screen unlocked, resuming detection")
+            self.set_suspended(False)
+
+    def _on_user_idle(self, idle):
+        """No keyboard or mouse for a while: probably an empty chair, so stop looking until input returns"""
+        if idle:
+            if self.is_detecting and not self.backend.suspended:
+                print("No input for a while, suspending detection")
+                self.set_suspended(True)
+                self.suspended_for_away = self.backend.suspended
+        elif self.suspended_for_away:
+            self.suspended_for_away = False
+            print("Input again, resuming detection")
+            self.set_suspended(False)
+
+    def _on_woke_from_sleep(self, seconds):
+        """The camera handle rarely survives sleep; reopen it unless we're suspended anyway"""
+        if self.is_detecting and not self.backend.suspended:
+            self.restart_detection()
+
+    def _backend_options(self) -> BackendOptions:
+        """Options from the saved settings, plus runtime limits such as the battery frame-rate cap"""
+        max_fps = self.settings["battery_max_fps"] if self.battery_saver_active else 0.0
+        return replace(BackendOptions.from_settings(self.settings), max_fps=max_fps)
+
+    def _on_power_changed(self, status):
+        """On battery, detect at a lower frame rate; nearly empty, suspend until charging"""
+        saver = self.settings["battery_saver"] and status["on_battery"]
+        if saver != self.battery_saver_active:
+            self.battery_saver_active = saver
+            self.backend.set_frame_rate(self.settings["battery_max_fps"] if saver else 0.0)
+            if self.is_detecting:
+                message = tr("On battery, detecting at a lower frame rate") if saver else tr("Plugged in, detecting at full speed")
+                self.tray.showMessage("Mindful Touch", message)
+
+        percent = status["percent"]
+        low = saver and percent is not None and percent <= self.settings["battery_pause_percent"]
+        if low and self.is_detecting and not self.backend.suspended:
+            self.set_suspended(True)
+            self.suspended_for_battery = self.backend.suspended
+            if self.suspended_for_battery:
+                self.tray.showMessage("Mindful Touch", tr("Battery at {percent}%, detection suspended until you plug in", percent=f"{percent:.0f}"))
+        elif not low and self.suspended_for_battery:
+            self.suspended_for_battery = False
+            self.set_suspended(False)
+
+    def set_low_priority(self, low):
+        """Lower detection's CPU priority now and on every later start"""
+        self.update_settings({"low_priority": bool(low)})
+
+    def restart_detection(self):
+        """Restart the camera thread with the current settings without ending the session"""
+        if self.is_transitioning or not self.is_detecting:
+            print("Restart detection ignored - not detecting or transitioning")
+            return
+
+        print("Restarting detection...")
+
+        # Session counters and timer keep running; only per-frame state starts over
+        self.bridge.reset_frame_state()
+        self.set_flash_state("none")
+        self.status_badge.set_status("starting")
+        self.suspend_action.setChecked(False)
+
+        if not self.backend.restart(self._backend_options()):
+            self._abort_start(StartupError(StartupError.UNKNOWN, "Could not restart the camera thread."))
+
     def _set_buttons_enabled(self, enabled):
         """Enable/disable detection buttons during state transitions"""
         try:
@@ -446,6 +2030,23 @@ class MainWindow(QMainWindow):
         """Toggle camera feed visibility without stopping detection"""
         self.show_feed = not self.show_feed
         self.camera_panel.set_privacy_state(self.show_feed)
+        self._refresh_preview_message()
+        self._update_subscriptions()
+
+    def _update_subscriptions(self):
+        """Only ask the backend for what the window currently shows"""
+        topics = ["detections"]
+        window_shown = self.isVisible() and not self.isMinimized()
+        if window_shown:
+            topics.append("metrics")
+        if self.show_feed and (window_shown or self.preview_window.isVisible()):
+            topics.append("preview")
+        self.backend.subscribe_events(topics)
+
+    def changeEvent(self, event):
+        if event.type() == QEvent.Type.WindowStateChange:
+            self._update_subscriptions()
+        super().changeEvent(event)
 
     def _central_style(self, tint=None, border_color=None):
         """Build the central widget stylesheet, optionally tinted for alerts"""
@@ -481,75 +2082,239 @@ class MainWindow(QMainWindow):
 
     def toggle_region(self, region: str, enabled: bool):
         """Handle region toggle from settings panel"""
-        # Update Config directly so toggles work before detection starts too;
-        # the detector reads Config.ACTIVE_REGIONS live on every frame
-        if enabled and region not in Config.ACTIVE_REGIONS:
-            Config.ACTIVE_REGIONS.append(region)
-        elif not enabled and region in Config.ACTIVE_REGIONS:
-            Config.ACTIVE_REGIONS.remove(region)
+        # Applied between frames by the camera thread, or at the next start when idle
+        regions = self.backend.toggle_region(region, enabled)
 
-        self.settings["active_regions"] = list(Config.ACTIVE_REGIONS)
-        settings_store.save(self.settings)
+        # The backend's map is authoritative; keep the toggles and saved settings in line with it
+        for name, active in regions.items():
+            self.detection_panel.update_region_state(name, active)
+        self.update_settings({"active_regions": [name for name, active in regions.items() if active]})
 
     def update_contact_duration(self, duration: float):
         """Handle contact duration change from settings panel"""
-        Config.update_contact_duration(duration)
-        self.settings["alert_delay"] = duration
-        settings_store.save(self.settings)
-
-    def _update_session_timer(self):
-        """Update session timer display"""
-        if self.is_detecting and self.session_start_time:
-            self.camera_panel.update_stats(self.total_detections, self._get_session_seconds(), self.mindful_stops)
-
-    def _get_session_seconds(self):
-        """Get current session duration in seconds"""
-        if self.session_start_time:
-            return int(time.time() - self.session_start_time)
-        return 0
-
-    def _play_alert_sound(self):
-        """Play alert sound - cooldown already handled by backend"""
-        try:
-            subprocess.Popen(["afplay", ALERT_SOUND, "-t", "0.35"])
-        except Exception as e:
-            print(f"Could not play sound: {e}")
+        self.update_settings({"alert_delay": float(duration)})
+
+    def _play_alert_sound(self, regions):
+        """Play the alert sound for these regions - cooldown already handled by the alert pipeline"""
+        self.alert_sound.play(regions)
+
+    def list_cameras(self) -> list:
+        """See cameras.list_cameras()"""
+        return cameras.list_cameras()
+
+    def set_camera(self, index: int) -> bool:
+        """Use another camera from now on; a running session switches over right away"""
+        if index < 0:
+            return False
+        self.update_settings({"camera_index": index})
+        return True
+
+    def _populate_camera_menu(self):
+        """Rebuilt each time so cameras plugged in since show up"""
+        self.camera_menu.clear()
+        found = self.list_cameras()
+        if self.settings["camera_index"] not in [camera["index"] for camera in found]:
+            found.append({"index": self.settings["camera_index"], "name": tr("Camera {index} (not connected)", index=self.settings["camera_index"])})
+        choices = QActionGroup(self.camera_menu)
+        for camera in found:
+            action = self.camera_menu.addAction(camera["name"])
+            action.setCheckable(True)
+            action.setChecked(camera["index"] == self.settings["camera_index"])
+            action.setActionGroup(choices)
+            action.triggered.connect(lambda _, index=camera["index"]: self.set_camera(index))
+
+    def _populate_sound_menu(self):
+        """Built-in chimes, the user's own file and the output device, rebuilt each time so new devices show up"""
+        self.sound_menu.clear()
+        current = self.settings["alert_sound"]
+        choices = QActionGroup(self.sound_menu)
+        names = {"system": tr("System Alert"), **{name: name.capitalize() for name in sound.BUILTIN_CHIMES}}
+        for value, label in names.items():
+            action = self.sound_menu.addAction(label)
+            action.setCheckable(True)
+            action.setChecked(current == value)
+            action.setActionGroup(choices)
+            action.triggered.connect(lambda _, value=value: self.set_alert_sound(value))
+        custom = self.sound_menu.addAction(tr("Choose File…") if current in names else tr("Custom: {name}", name=Path(current).name))
+        custom.setCheckable(True)
+        custom.setChecked(current not in names)
+        custom.setActionGroup(choices)
+        custom.triggered.connect(self._choose_sound_file)
+
+        self.sound_menu.addSeparator()
+        outputs = self.sound_menu.addMenu(tr("Output Device"))
+        devices = QActionGroup(outputs)
+        for device in [{"id": "", "name": tr("System Default")}] + sound.output_devices():
+            action = outputs.addAction(device["name"])
+            action.setCheckable(True)
+            action.setChecked(self.settings["audio_output"] == device["id"])
+            action.setActionGroup(devices)
+            action.triggered.connect(lambda _, device_id=device["id"]: self.set_audio_output(device_id))
+
+    def _choose_sound_file(self):
+        path, _ = QFileDialog.getOpenFileName(self, tr("Choose Alert Sound"), str(Path.home()), "Audio (*.wav *.mp3 *.aiff *.m4a *.ogg)")
+        if path:
+            self.set_alert_sound(path)
+
+    def set_alert_sound(self, value: str, region: Optional[str] = None) -> bool:
+        """Use a built-in chime, "system" or an audio file, for every region or just one ("" clears a region's own sound)"""
+        if value and sound.sound_path(value) is None:
+            print(f"Alert sound {value} not found")
+            return False
+        if region is None:
+            self.update_settings({"alert_sound": value or "system"})
+        elif value:
+            self.update_settings({"region_sounds": {**self.settings["region_sounds"], region: value}})
+        else:
+            self.update_settings({"region_sounds": {name: other for name, other in self.settings["region_sounds"].items() if name != region}})
+        return True
+
+    def set_alert_volume(self, volume: float):
+        self.update_settings({"alert_volume": min(1.0, max(0.0, float(volume)))})
+
+    def set_speech_phrases(self, phrases) -> list:
+        """Phrases the "speak" alert action reads out, in turn"""
+        self.update_settings({"speech_phrases": [phrase.strip() for phrase in phrases if phrase.strip()]})
+        return list(self.spoken_alert.phrases)
+
+    def get_audio_outputs(self) -> list:
+        return sound.output_devices()
+
+    def set_audio_output(self, device_id: str) -> bool:
+        """Play alerts on this output device ("" = system default)"""
+        found = not device_id or any(device["id"] == device_id for device in sound.output_devices())
+        if found:
+            self.update_settings({"audio_output": device_id})
+        return found
+
+    def _confirm_quit(self, summary) -> bool:
+        """Ask before ending a session in progress, showing how it went"""
+        message = QMessageBox(self)
+        message.setIcon(QMessageBox.Icon.Question)
+        message.setWindowTitle(tr("Quit Mindful Touch?"))
+        message.setText(tr("Quitting ends the current session."))
+        message.setInformativeText(
+            tr(
+                "Session length: {duration}\nTouches: {touches}\nLongest streak without touching: {streak}",
+                duration=format_duration(summary["duration"]),
+                touches=summary["touches"],
+                streak=format_duration(summary["longest_clean_streak"]),
+            )
+        )
+        message.setStandardButtons(QMessageBox.StandardButton.Cancel)
+        quit_button = message.addButton(tr("Quit"), QMessageBox.ButtonRole.AcceptRole)
+        message.setDefaultButton(quit_button)
+        message.exec()
+        return message.clickedButton() == quit_button
 
     def closeEvent(self, event):
-        """Ensure proper cleanup when app is closed"""
+        """Hide to the tray (detection keeps running), or clean up when really quitting"""
+        if not self.quitting and self.settings["close_to_tray"]:
+            event.ignore()
+            self.enter_background()
+            if self.tray.isVisible() and not self.tray_hint_shown:
+                self.tray_hint_shown = True
+                self.tray.showMessage(tr("Mindful Touch is still running"), tr("Detection continues in the background. Quit from the tray icon."))
+            return
+
+        if self.bridge.session_start_time is not None and not self._confirm_quit(self.bridge.summary()):
+            self.quitting = False
+            event.ignore()
+            return
+
+        self.cleanup()
+        event.accept()
+
+    def on_commit_data(self, manager=None):
+        """Logoff/shutdown is coming (WM_QUERYENDSESSION on Windows): save what would be lost, nothing more
+
+        Another app can still cancel the logoff, and Qt asks at session-save checkpoints too, so this
+        must leave the app running; if the session really ends, aboutToQuit follows and cleans up.
+        """
+        print("Session ending, saving state")
+        self.alert_outcomes.finalize()
+
+    def cleanup(self):
+        """Stop detection, the monitors and the session; safe to call more than once"""
+        if self.cleaned_up:
+            return
+        self.cleaned_up = True
         try:
             print("Application closing, cleaning up...")
-
-            # Stop detection if running
-            if self.is_detecting:
+            self.hotkey.unregister()
+            self.session_monitor.stop()
+            self.focus_monitor.stop()
+            self.foreground_monitor.stop()
+            self.power_monitor.stop()
+            self.hangup_watcher.uninstall()
+            self.alert_toast.close()
+            self.edge_flash.hide()
+            self.breathing_window.close()
+            self.alert_outcomes.finalize()
+            self.status_overlay.close()
+            self.streak_widget.close()
+            self.preview_window.close()
+            self.mini_controls.hide()
+            self.tray_retry_timer.stop()
+            self.stats_tick_timer.stop()
+            self.schedule_timer.stop()
+            self.settings_watcher.stop()
+
+            # Stop detection if running; the thread escalates on its own if it hangs
+            if self.is_detecting or self.backend.is_running:
                 print("Stopping detection before exit...")
-                self.camera_thread.stop_detection()
-
-            # Stop any timers
-            if self.session_timer.isActive():
-                self.session_timer.stop()
+            self.backend.stop(wait=True)
+            self.is_detecting = False
 
-            # Force cleanup of camera thread
-            if self.camera_thread.isRunning():
-                print("Forcing camera thread cleanup...")
-                self.camera_thread.terminate()
-                self.camera_thread.wait(2000)  # Wait up to 2 seconds
+            # Stop the session clock; the touch in progress, if any, is still recorded
+            self.bridge.end_session()
+            self.streaks.stop()
+            sessions.end(self.event_store)
+            self.event_store.close()
 
             print("Application cleanup completed")
 
         except Exception as e:
             print(f"Error during application cleanup: {e}")
 
-        finally:
-            event.accept()
-
 
 def main():
+    logs.install()
     app = QApplication(sys.argv)
     load_fonts()
     app.setFont(QFont(Theme.FONT_BODY, 13))
+
+    # Only one instance may own the camera; a second launch just wakes the first
+    if single_instance.forward(sys.argv[1:]):
+        print("Mindful Touch is already running, handed over to it")
+        return
+
+    # Listen before the (slow) window is built, so a launch meanwhile finds this instance instead of starting another
+    instance_server = single_instance.InstanceServer(parent=app)
+    instance_server.listen()
+
+    previous_instance = instance_lock.acquire()
+
     window = MainWindow()
-    window.show()
+    instance_server.message_received.connect(window.on_second_launch)
+    app.installEventFilter(window)
+    # Also emitted when the session ends (Windows kills processes still around after logoff, the session manager does on Linux)
+    app.aboutToQuit.connect(window.cleanup)
+    app.aboutToQuit.connect(instance_lock.release)
+    app.commitDataRequest.connect(window.on_commit_data)
+    deep_links.register()
+
+    if autostart.SILENT_FLAG in sys.argv[1:]:
+        # Launched at login: stay in the background and get to work
+        window.enter_background()
+        window.start_automatically()
+    else:
+        window.show()
+    for arg in sys.argv[1:]:
+        if arg.startswith(f"{deep_links.SCHEME}://"):
+            window.handle_deep_link(arg)
+    if previous_instance:
+        window.warn_previous_instance(previous_instance)
     sys.exit(app.exec())
 
 
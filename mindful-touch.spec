@@ -34,6 +34,9 @@ a = Analysis(
         # PyQt6 modules
         'PyQt6.QtCore',
         'PyQt6.QtGui',
+        'PyQt6.QtMultimedia',
+        'PyQt6.QtNetwork',
+        'PyQt6.QtTextToSpeech',
         'PyQt6.QtWidgets',
         'PyQt6.QtSvg',
         'PyQt6.QtSvgWidgets',
@@ -41,14 +44,74 @@ a = Analysis(
         'mediapipe',
         'cv2',
         'numpy',
+        # Global shortcut backends, picked by pynput at runtime (bundled when it's installed)
+        'pynput.keyboard._darwin',
+        'pynput.keyboard._win32',
+        'pynput.keyboard._xorg',
         # Backend modules
+        'backend.alert_outcomes',
+        'backend.alerts',
+        'backend.autostart',
+        'backend.bridge',
+        'backend.cameras',
+        'backend.channels',
+        'backend.deep_links',
+        'backend.erase',
+        'backend.focus_mode',
+        'backend.foreground',
+        'backend.hangup',
+        'backend.i18n',
+        'backend.instance_lock',
+        'backend.logs',
+        'backend.manager',
+        'backend.metrics',
+        'backend.onboarding',
+        'backend.power',
+        'backend.profiles',
+        'backend.protocol',
+        'backend.schedule',
+        'backend.session_monitor',
+        'backend.settings_watcher',
+        'backend.single_instance',
+        'backend.startup_errors',
+        'backend.templates',
         'backend.detection.multi_region_detector',
         'backend.detection.config',
+        'backend.detection.custom_regions',
+        'backend.detection.options',
         'backend.detection.settings_store',
+        'backend.stats.achievements',
+        'backend.stats.annotations',
+        'backend.stats.backup',
+        'backend.stats.baseline',
+        'backend.stats.events',
+        'backend.stats.export',
+        'backend.stats.goals',
+        'backend.stats.health',
+        'backend.stats.live',
+        'backend.stats.report',
+        'backend.stats.retention',
+        'backend.stats.sessions',
+        'backend.stats.streaks',
+        'backend.stats.summary',
+        'backend.stats.trends',
         # UI modules
         'ui.panels.camera_panel',
         'ui.panels.detection_panel',
         'ui.styles.theme',
+        'ui.hotkey',
+        'ui.macos',
+        'ui.sound',
+        'ui.speech',
+        'ui.widgets.alert_toast',
+        'ui.widgets.breathing',
+        'ui.widgets.edge_flash',
+        'ui.widgets.log_console',
+        'ui.widgets.mini_controls',
+        'ui.widgets.preview_window',
+        'ui.widgets.status_overlay',
+        'ui.widgets.streak_widget',
+        'ui.widgets.tray',
         'ui.widgets.status_badge',
         'ui.widgets.toggle_switch',
     ],
@@ -106,5 +169,6 @@ app = BUNDLE(
         'NSCameraUsageDescription': 'Mindful Touch uses the camera to detect hand movements near your face for mindfulness awareness.',
         'NSPrincipalClass': 'NSApplication',
         'LSUIElement': False,  # Show in dock
+        'CFBundleURLTypes': [{'CFBundleURLName': 'com.mindfultouch.app', 'CFBundleURLSchemes': ['mindfultouch']}],
     },
 )
@@ -6,12 +6,14 @@ def test_imports():
     from backend.detection import settings_store
     from backend.detection.config import Config
     from backend.detection.multi_region_detector import MultiRegionDetector
+    from backend.manager import BackendManager
     from ui.panels.camera_panel import CameraPanel
     from ui.panels.detection_panel import DetectionPanel
     from ui.styles.theme import Theme
 
     assert Config is not None
     assert MultiRegionDetector is not None
+    assert BackendManager is not None
     assert CameraPanel is not None
     assert DetectionPanel is not None
     assert Theme is not None
@@ -37,6 +39,12 @@ def test_settings_store_defaults(tmp_path, monkeypatch):
     settings = settings_store.load()
     assert settings == settings_store.DEFAULTS
 
+    # Editing a loaded list or dict in place leaves the defaults alone
+    settings["escalation_levels"][0]["actions"].append("sound")
+    settings_store.typed({"region_alerts": None})["region_alerts"]["mouth"] = {}
+    assert settings_store.DEFAULTS["escalation_levels"][0]["actions"] == ["pulse"]
+    assert settings_store.DEFAULTS["region_alerts"] == {}
+
     settings["alert_delay"] = 2.5
     settings["active_regions"] = ["mouth"]
     settings_store.save(settings)
@@ -44,3 +52,1374 @@ def test_settings_store_defaults(tmp_path, monkeypatch):
     reloaded = settings_store.load()
     assert reloaded["alert_delay"] == 2.5
     assert reloaded["active_regions"] == ["mouth"]
+
+
+def test_settings_store_types(tmp_path, monkeypatch):
+    """Values of the wrong type fall back to their default; saving leaves no temporary file behind"""
+    import json
+
+    from backend.detection import settings_store
+
+    monkeypatch.setattr(settings_store, "SETTINGS_PATH", tmp_path / "settings.json")
+    (tmp_path / "settings.json").write_text(
+        json.dumps({"schema_version": settings_store.SCHEMA_VERSION, "alert_delay": "2", "sensitivity": 2, "edge_flash": 1, "future_key": True})
+    )
+
+    settings = settings_store.load()
+    assert settings["alert_delay"] == settings_store.DEFAULTS["alert_delay"]
+    assert settings["sensitivity"] == 2.0 and isinstance(settings["sensitivity"], float)
+    assert settings["edge_flash"] is False
+    assert settings["future_key"] is True
+
+    settings_store.save(settings)
+    assert [path.name for path in tmp_path.iterdir()] == ["settings.json"]
+
+
+def test_settings_migration(tmp_path, monkeypatch):
+    """Old files are upgraded step by step on load, with the original kept as a backup"""
+    import json
+
+    from backend.detection import settings_store
+
+    monkeypatch.setattr(settings_store, "SETTINGS_PATH", tmp_path / "settings.json")
+    old = {"alert_delay": 2.0}
+    (tmp_path / "settings.json").write_text(json.dumps(old))
+
+    settings = settings_store.load()
+    assert settings["schema_version"] == settings_store.SCHEMA_VERSION
+    assert settings["alert_delay"] == 2.0
+    assert settings["onboarding_steps"] == ["camera_permission", "calibration", "first_session"]
+    assert len(settings_store.last_migrations) == len(settings_store.MIGRATIONS)
+    assert json.loads((tmp_path / "settings.v0.json").read_text()) == old
+    assert json.loads((tmp_path / "settings.json").read_text())["schema_version"] == settings_store.SCHEMA_VERSION
+
+    settings_store.load()  # Already current: nothing more to do
+    assert settings_store.last_migrations == []
+
+    newer = {"schema_version": settings_store.SCHEMA_VERSION + 1, "onboarding_steps": []}
+    assert settings_store.migrate(newer) == (newer, [])
+
+
+def test_log_capture_splits_lines(tmp_path, monkeypatch):
+    """Captured output is recorded line by line, including writes split across calls"""
+    import io
+
+    from backend import logs
+
+    monkeypatch.setattr(logs, "LOG_PATH", tmp_path / "backend.log")
+    stream = logs._TeeStream(io.StringIO(), "stdout")
+    stream.write("first\nsec")
+    stream.write("ond\n")
+
+    assert [entry["line"] for entry in logs.get_backend_logs(2)] == ["first", "second"]
+    assert stream.stream.getvalue() == "first\nsecond\n"
+
+
+def test_log_file_rotates(tmp_path, monkeypatch):
+    """The log file is rotated once it grows past the size limit"""
+    from backend import logs
+
+    monkeypatch.setattr(logs, "LOG_PATH", tmp_path / "backend.log")
+    monkeypatch.setattr(logs, "MAX_LOG_BYTES", 100)
+    monkeypatch.setattr(logs, "_log_file", None)
+
+    for i in range(10):
+        logs._record("stdout", f"line {i} " + "x" * 20)
+
+    logs._record("stderr", "last")
+
+    assert (tmp_path / "backend.log.1").exists()
+    assert (tmp_path / "backend.log").read_text().endswith("[stderr] last\n")
+    assert not (tmp_path / f"backend.log.{logs.LOG_BACKUPS + 1}").exists()
+
+
+def test_backend_options_from_settings():
+    """Backend options come from saved settings and drop invalid values"""
+    from backend.detection.options import BackendOptions
+
+    options = BackendOptions.from_settings(
+        {"active_regions": ["mouth", "nose"], "camera_index": 1, "sensitivity": 1.5, "log_level": "loud", "idle_shutdown_minutes": -5}
+    )
+
+    assert options.enabled_regions == ["mouth"]
+    assert options.camera_index == 1
+    assert options.sensitivity == 1.5
+    assert options.log_level == "info"
+    assert options.low_priority is False
+    assert options.idle_shutdown_minutes == 0
+
+
+def test_instance_lock_reaps_stale_lock(tmp_path, monkeypatch):
+    """A lock left by a process that is gone is replaced with ours"""
+    import json
+    import os
+
+    from backend import instance_lock
+
+    lock_path = tmp_path / "app.pid"
+    monkeypatch.setattr(instance_lock, "LOCK_PATH", lock_path)
+    lock_path.write_text(json.dumps({"pid": 2**22 + 1, "command": "gone"}))
+
+    assert instance_lock.acquire() is None
+    assert json.loads(lock_path.read_text())["pid"] == os.getpid()
+
+    instance_lock.release()
+    assert not lock_path.exists()
+
+
+def test_merge_detections_across_cameras():
+    """Contact from any camera counts; one-shot events only come from the camera that just reported"""
+    from backend.manager import merge_detections
+
+    def detection(contacts, sound=False, stop=False):
+        details = {
+            "contacts": contacts,
+            "alert_active": bool(contacts),
+            "should_play_sound": sound,
+            "mindful_stop_detected": stop,
+            "contact_duration": 0,
+        }
+        return {"active_regions": ["mouth"], "region_details": {"mouth": details}}
+
+    latest = {"main": detection([], stop=True), "camera-1": detection([{"point": (1, 2)}], sound=True)}
+
+    merged = merge_detections(latest, "main")
+    assert merged["regions_with_contact"] == ["mouth"]
+    assert merged["region_details"]["mouth"]["alert_active"]
+    assert merged["alerts_active"] == []
+    assert merged["mindful_stops_detected"] == ["mouth"]
+
+    assert merge_detections(latest, "camera-1")["alerts_active"] == ["mouth"]
+
+
+def test_supervisor_restart_backoff_and_give_up(monkeypatch):
+    """A camera thread that keeps crashing is restarted with a doubling delay, then given up on with restart_failed"""
+    from types import SimpleNamespace
+
+    from PyQt6.QtCore import QCoreApplication, QObject, pyqtSignal
+
+    from backend import manager
+    from backend.detection.config import Config
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    now = [1000.0]
+    monkeypatch.setattr(manager, "time", SimpleNamespace(time=lambda: now[0], monotonic=lambda: now[0]))
+
+    class CrashingThread(QObject):
+        """Stands in for CameraThread: starts at once, and dies whenever told to"""
+
+        stopped_unexpectedly = pyqtSignal(str)
+        ready = pyqtSignal()
+        startup_failed = pyqtSignal(object)
+
+        def __init__(self):
+            super().__init__()
+            self.running = self.paused = self.suspended = False
+            self.last_heartbeat = self.started_at = None
+            self.frames_processed = 0
+            self.starts = 0
+
+        def isRunning(self):
+            return self.running
+
+        def dropped_counts(self):
+            return {"frames": 0, "detections": 0}
+
+        def start_detection(self, options=None):
+            self.starts += 1
+            self.running = True
+            return True
+
+        def stop_detection(self, timeout=None, wait=False):
+            self.running = False
+
+    thread = CrashingThread()
+    supervisor = manager.BackendSupervisor(thread)
+    restarted, failed = [], []
+    supervisor.backend_restarted.connect(restarted.append)
+    supervisor.restart_failed.connect(failed.append)
+    supervisor.mark_started()
+    thread.start_detection()
+
+    def crash(reason, after=5.0):
+        """The delay of the restart it schedules, in seconds (None if it gives up)"""
+        now[0] += after
+        thread.running = False
+        thread.stopped_unexpectedly.emit(reason)
+        return supervisor.restart_timer.interval() / 1000 if supervisor.restart_timer.isActive() else None
+
+    def restart(ready=True):
+        # What the restart timer does once it fires
+        supervisor.restart_timer.stop()
+        supervisor._restart()
+        if ready:
+            thread.ready.emit()
+        else:
+            thread.startup_failed.emit("camera busy")
+
+    assert crash("first") == 1.0
+    restart()
+    assert crash("second") == 2.0
+    restart(ready=False)  # Failing to come back counts as another attempt
+    assert supervisor.restart_timer.interval() == 4000
+    restart()
+    assert restarted == [1, 3]
+
+    # Crashing after a stable run starts the backoff over
+    assert crash("much later", after=Config.RESTART_STABLE_TIME) == 1.0
+    restart()
+    delays = []
+    for attempt in range(2, 6):
+        delays.append(crash(f"crash {attempt}"))
+        restart()
+    assert delays == [2.0, 4.0, 8.0, 16.0]
+    assert supervisor.restart_attempts == Config.MAX_RESTART_ATTEMPTS and failed == []
+
+    assert crash("for good") is None
+    assert failed == ["for good"] and thread.starts == 9
+
+
+def test_stop_while_the_model_loads_does_not_block():
+    """Stopping during the model load returns at once; the stop completes when the thread exits, or escalates on timeout"""
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.detection.config import Config
+    from backend.detection.options import BackendOptions
+    from backend.manager import BackendManager
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    backend = BackendManager()
+    thread = backend.camera_thread
+    alive, waits, terminated = [False], [], []
+    # The thread never really starts: it is "loading the model" until the test lets it exit
+    thread.start = lambda: alive.__setitem__(0, True)
+    thread.isRunning = lambda: alive[0]
+    thread.wait = lambda ms=0: waits.append(ms) or not alive[0]
+    thread.terminate = lambda: terminated.append(True) or alive.__setitem__(0, False)
+
+    assert backend.start(BackendOptions())
+    backend.stop()
+    assert waits == [] and backend.state == BackendManager.STOPPED
+    assert thread.is_stopping and thread.stop_timer.interval() == Config.STARTUP_TIMEOUT * 1000
+
+    # Starting again meanwhile waits for the stop to finish
+    assert backend.start(BackendOptions()) and thread.pending_start is not None
+    alive[0] = False  # The load is done and run() returned
+    thread._on_finished()
+    assert not thread.is_stopping and not thread.stop_timer.isActive()
+    assert thread.running and alive[0] and thread.pending_start is None
+
+    # A load that never finishes is escalated once the stop timer runs out
+    backend.stop()
+    assert thread.stop_timer.isActive()
+    thread.stop_timer.stop()  # What the stop timer does when it runs out
+    thread._on_stop_timeout()
+    assert terminated == [True] and not alive[0] and not thread.is_stopping
+
+    # Quitting waits anyway
+    waits.clear()
+    assert backend.start(BackendOptions())
+    backend.stop(wait=True)
+    assert waits and not thread.stop_timer.isActive()
+
+
+def test_backend_suspend_resume_and_dormant_states():
+    """stopped -> starting -> running <-> suspended -> dormant after the idle period -> starting again on resume()"""
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.detection.options import BackendOptions
+    from backend.manager import BackendManager
+    from backend.protocol import Resume, Suspend
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    backend = BackendManager()
+    thread = backend.camera_thread
+    alive = [False]
+
+    def exit_when_asked(ms=0):
+        alive[0] = False
+        return True
+
+    thread.start = lambda: alive.__setitem__(0, True)
+    thread.isRunning = lambda: alive[0]
+    thread.wait = exit_when_asked
+    states = []
+    backend.state_changed.connect(states.append)
+
+    assert not backend.suspend()  # Nothing to suspend yet
+    assert backend.start(BackendOptions(idle_shutdown_minutes=5))
+    thread.last_heartbeat = 1.0  # First frame processed
+    thread.ready.emit()
+
+    assert backend.suspend() and backend.suspended
+    assert backend.idle_timer.isActive() and backend.idle_timer.interval() == 5 * 60 * 1000
+    assert backend.resume() and not backend.suspended and not backend.idle_timer.isActive()
+    assert [type(thread.commands.get_nowait()) for _ in range(2)] == [Suspend, Resume]
+
+    backend.suspend()
+    backend._on_idle_timeout()  # Suspended for the whole idle period
+    assert backend.state == BackendManager.DORMANT and backend.suspended and not alive[0]
+
+    assert backend.resume() and alive[0]  # Started from scratch
+    backend.stop()
+    assert states == ["starting", "running", "suspended", "running", "suspended", "dormant", "starting", "stopped"]
+
+
+def test_commands_wait_for_a_starting_camera():
+    """Commands sent while the camera loads are delivered once it's ready, oldest first; stale ones fail instead"""
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.detection.options import BackendOptions
+    from backend.manager import BackendManager
+    from backend.protocol import Pause, SetAlertDelay, SetRegions
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    backend = BackendManager()
+    thread = backend.camera_thread
+    alive = [False]
+    thread.start = lambda: alive.__setitem__(0, True)
+    thread.isRunning = lambda: alive[0]
+    failed = []
+    backend.command_failed.connect(failed.append)
+
+    assert not backend.send_backend_command(Pause())  # Nothing running to take it
+    assert backend.start(BackendOptions())
+    regions, delay, stale = SetRegions(["mouth"]), SetAlertDelay(2.0), Pause()
+    stale.sent_at -= stale.buffer_timeout + 1
+    for command in (regions, stale, delay):
+        assert backend.send_backend_command(command)
+    assert thread.commands.empty()
+
+    thread.last_heartbeat = 1.0  # First frame processed
+    thread.ready.emit()
+    delivered = []
+    while not thread.commands.empty():
+        delivered.append(thread.commands.get_nowait().id)
+    assert delivered == [regions.id, delay.id]
+    assert [result.id for result in failed] == [stale.id]
+    backend.stop()
+
+
+def test_camera_open_error_kinds(monkeypatch):
+    """A camera that won't open is classified so the user gets a useful hint"""
+    import os
+    import sys
+
+    from backend.startup_errors import StartupError, camera_open_error
+
+    monkeypatch.setattr(sys, "platform", "linux")
+    monkeypatch.setattr(os.path, "exists", lambda path: False)
+    assert camera_open_error(3).kind == StartupError.CAMERA_NOT_FOUND
+
+    monkeypatch.setattr(os.path, "exists", lambda path: True)
+    monkeypatch.setattr(os, "access", lambda path, mode: False)
+    error = camera_open_error(0)
+    assert error.kind == StartupError.CAMERA_PERMISSION
+    assert "/dev/video0" in str(error) and error.hint
+
+
+def test_bridge_counts_touches_and_mindful_stops():
+    """The bridge counts an alert once per episode and emits only on changes"""
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.bridge import DetectionBridge
+
+    # The session clock is a QTimer, which needs an application object
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    bridge = DetectionBridge()
+    bridge.start_session()
+
+    def frame(alert=False, contact=False, stops=()):
+        return {
+            "regions_with_contact": ["mouth"] if contact else [],
+            "alerts_active": [],
+            "mindful_stops_detected": list(stops),
+            "region_details": {"mouth": {"alert_active": alert}},
+        }
+
+    episodes = []
+    bridge.touch_episode.connect(episodes.append)
+
+    bridge.process(frame(contact=True), now=100.0)
+    bridge.process(frame(alert=True, contact=True), now=100.1)
+    bridge.process(frame(), now=100.2)  # A flicker shorter than the merge gap...
+    bridge.process(frame(alert=True, contact=True), now=100.3)  # ...doesn't split the episode
+    bridge.process(frame(), now=100.4)
+    bridge.process(frame(stops=["eyes"]), now=101.0)
+
+    assert bridge.touches == 2
+    assert bridge.mindful_stops == 3
+    assert not bridge.alert_active and not bridge.contact
+    assert len(episodes) == 1
+    assert episodes[0]["regions"] == ["mouth"] and episodes[0]["alerted"]
+    assert abs(episodes[0]["duration"] - 0.3) < 1e-9
+    assert episodes[0]["confidence"] == 0.6  # Contact in 3 of its 5 frames
+    bridge.end_session()
+
+
+def test_bridge_debounces_touch_episodes():
+    """Contact breaks shorter than the merge gap stay one episode; end_session finishes the one in progress"""
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.bridge import DetectionBridge
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    bridge = DetectionBridge(merge_gap=0.5)
+    episodes = []
+    bridge.touch_episode.connect(episodes.append)
+    bridge.start_session()
+
+    def frame(*regions):
+        return {"regions_with_contact": list(regions), "contact_points": len(regions), "alerts_active": [], "region_details": {}}
+
+    bridge.process(frame("mouth"), now=10.0)
+    bridge.process(frame(), now=10.2)  # Shorter than the gap
+    bridge.process(frame("mouth"), now=10.3)
+    bridge.process(frame(), now=10.7)
+    assert episodes == [] and bridge.contact
+    bridge.process(frame(), now=10.8)  # The gap since the last contact is up
+    assert len(episodes) == 1 and not bridge.contact
+    assert (episodes[0]["start"], episodes[0]["end"], episodes[0]["regions"]) == (10.0, 10.3, ["mouth"])
+    assert abs(episodes[0]["duration"] - 0.3) < 1e-9
+
+    # The camera restarting forgets the touch it was in the middle of
+    bridge.process(frame("eyes"), now=15.0)
+    bridge.reset_frame_state()
+    bridge.process(frame(), now=16.0)
+    assert len(episodes) == 1
+
+    bridge.process(frame("mouth"), now=20.0)
+    bridge.process(frame("mouth", "eyes"), now=20.1)
+    bridge.process(frame(), now=20.3)
+    bridge.end_session()  # Mid-touch, and within the gap: still recorded
+    assert len(episodes) == 2
+    assert (episodes[1]["start"], episodes[1]["end"], episodes[1]["regions"]) == (20.0, 20.1, ["eyes", "mouth"])
+    assert episodes[1]["peak_contacts"] == 2 and episodes[1]["confidence"] == round(2 / 3, 3)
+    assert bridge.episode is None and not bridge.contact
+
+
+def test_protocol_commands_are_correlated():
+    """Every command gets its own id, which its result echoes back"""
+    from backend.protocol import CommandResult, Pause, SetRegions
+
+    first, second = SetRegions(["mouth"]), Pause()
+    assert first.id != second.id
+    assert first.name == "SetRegions" and first.regions == ["mouth"]
+
+    result = CommandResult(second.id, second.name, ok=True)
+    assert result.id == second.id and result.error is None
+
+
+def test_bounded_channel_keeps_critical_items():
+    """A full channel drops its oldest ordinary item, never an alert or mindful stop"""
+    from backend.channels import BoundedChannel, is_critical_detection
+
+    channel = BoundedChannel(2, is_critical=is_critical_detection)
+    assert channel.put({"alerts_active": ["mouth"]})
+    assert not channel.put({"frame": 1})
+    channel.put({"frame": 2})
+    channel.put({"mindful_stops_detected": ["eyes"]})
+
+    items = channel.drain()
+    assert channel.dropped == 2
+    assert items == [{"alerts_active": ["mouth"]}, {"mindful_stops_detected": ["eyes"]}]
+    assert len(channel) == 0
+
+
+def test_detection_messages_are_validated():
+    """Malformed or other-version detection messages raise ProtocolError carrying the payload"""
+    import pytest
+
+    from backend.protocol import PROTOCOL_VERSION, ProtocolError, validate_detection
+
+    details = {"contacts": [], "alert_active": False, "should_play_sound": False, "mindful_stop_detected": False, "contact_duration": 0.5}
+    message = {
+        "protocol_version": PROTOCOL_VERSION,
+        "hands_detected": True,
+        "face_detected": True,
+        "contact_points": 0,
+        "active_regions": ["mouth"],
+        "regions_with_contact": [],
+        "alerts_active": [],
+        "mindful_stops_detected": [],
+        "region_details": {"mouth": details},
+    }
+    assert validate_detection(message) is message
+
+    old = dict(message, protocol_version=PROTOCOL_VERSION - 1)
+    with pytest.raises(ProtocolError) as error:
+        validate_detection(old)
+    assert error.value.payload is old
+
+    with pytest.raises(ProtocolError, match="contact_duration"):
+        validate_detection(dict(message, region_details={"mouth": dict(details, contact_duration="soon")}))
+
+
+def test_autostart_desktop_entry(tmp_path, monkeypatch):
+    """On Linux, launch at login is an XDG autostart entry that carries the background flag"""
+    import sys
+
+    from backend import autostart
+
+    monkeypatch.setattr(sys, "platform", "linux")
+    entry = tmp_path / "autostart" / "mindful-touch.desktop"
+    monkeypatch.setattr(autostart, "DESKTOP_ENTRY_PATH", entry)
+
+    assert autostart.enable(silent=True)
+    assert autostart.SILENT_FLAG in entry.read_text()
+
+    assert autostart.disable()
+    assert not entry.exists()
+
+
+def test_shortcut_conversion():
+    """Qt-style shortcuts are translated for the global hotkey listener, and bad ones rejected"""
+    from ui.hotkey import DEFAULT_SHORTCUT, to_pynput
+
+    assert to_pynput(DEFAULT_SHORTCUT) == "<ctrl>+<shift>+m"
+    assert to_pynput("Alt+F8") == "<alt>+<f8>"
+    assert to_pynput("M") is None
+    assert to_pynput("Ctrl+Shift") is None
+
+
+def test_deep_link_parsing():
+    """mindfultouch:// links become an action plus parameters; anything else is rejected"""
+    from backend.deep_links import parse
+
+    assert parse("mindfultouch://pause?minutes=30") == ("pause", {"minutes": 30.0})
+    assert parse("mindfultouch://stats/today") == ("stats", {"view": "today"})
+    assert parse("mindfultouch://pause?minutes=soon") is None
+    assert parse("mindfultouch://format-disk") is None
+    assert parse("https://pause") is None
+
+
+def test_session_summary_longest_clean_streak():
+    """The longest stretch between touches, counting the stretch still running"""
+    from backend.bridge import DetectionBridge
+
+    bridge = DetectionBridge()
+    bridge.start_session()
+    bridge.session_start_time = 100.0
+    alert = {"region_details": {"mouth": {"alert_active": True}}, "regions_with_contact": ["mouth"]}
+
+    bridge.process(alert, now=160.0)  # 60s clean
+    bridge.process({}, now=161.0)
+    bridge.process({"region_details": {}}, now=170.0)
+    bridge.process(alert, now=190.0)  # 30s since the last touch
+    summary = bridge.summary(now=200.0)
+    assert summary["touches"] == 2 and summary["duration"] == 100.0
+    assert summary["longest_clean_streak"] == 60.0
+    assert bridge.summary(now=300.0)["longest_clean_streak"] == 110.0
+
+
+def test_foreground_quiet_rules():
+    """Quiet-app rules match whole words in the app name or title, plus fullscreen windows"""
+    from backend.foreground import matching_rule
+
+    rules = ["zoom", "obs", "google meet"]
+    assert matching_rule({"app": "zoom.us", "title": "", "fullscreen": False}, rules) == "zoom"
+    assert matching_rule({"app": "firefox", "title": "Google Meet - Standup", "fullscreen": False}, rules) == "google meet"
+    assert matching_rule({"app": "code", "title": "jobs.py", "fullscreen": False}, rules) is None
+    assert matching_rule({"app": "vlc", "title": "", "fullscreen": True}, rules) == "fullscreen"
+    assert matching_rule({"app": "vlc", "title": "", "fullscreen": True}, rules, quiet_fullscreen=False) is None
+    assert matching_rule(None, rules) is None
+
+
+def test_foreground_monitor_polls_off_the_ui_thread(monkeypatch):
+    """The foreground app is looked up on the monitor's own thread, and forgotten once it stops"""
+    import threading
+    import time
+
+    from backend import foreground
+
+    lookups = []
+
+    def lookup():
+        lookups.append(threading.current_thread())
+        return {"app": "zoom.us", "title": "", "fullscreen": False}
+
+    monkeypatch.setattr(foreground, "get_foreground_app", lookup)
+    monitor = foreground.ForegroundMonitor(interval=0.01)
+    assert monitor.current is None and not monitor.running
+
+    monitor.start()
+    deadline = time.monotonic() + 2
+    while monitor.current is None and time.monotonic() < deadline:
+        time.sleep(0.01)
+    assert monitor.current["app"] == "zoom.us"
+    assert lookups[0] is not threading.current_thread()
+
+    monitor.stop()
+    assert monitor.current is None and not monitor.running
+
+
+def test_alert_limiter_cooldown_and_hourly_cap():
+    """Each region waits out its cooldown, and the hourly cap holds back everything"""
+    from backend.alerts import AlertLimiter
+
+    limiter = AlertLimiter(min_interval=10.0, max_per_hour=3)
+    assert limiter.allow(["mouth"], now=0.0) == ["mouth"]
+    assert limiter.allow(["mouth", "eyes"], now=5.0) == ["eyes"]  # mouth is cooling down
+    assert limiter.allow(["mouth"], now=9.0) == []
+    assert limiter.allow(["mouth"], now=10.0) == ["mouth"]
+    assert limiter.allow(["scalp"], now=20.0) == []  # Third alert this hour already went out
+    assert limiter.allow(["scalp"], now=3600.0) == ["scalp"]  # The first one has aged out
+
+
+def test_escalation_policy_levels():
+    """Later touches in the window escalate the alert; old ones age out"""
+    from backend.alerts import EscalationPolicy
+
+    policy = EscalationPolicy.from_settings(
+        {
+            "escalation_window_minutes": 10.0,
+            "escalation_levels": [{"touches": 1, "actions": ["pulse"]}, {"touches": 3, "actions": ["pulse", "sound"]}, {"bogus": 1}],
+        }
+    )
+    levels = []
+    for now in (0.0, 60.0, 120.0):
+        policy.record(now)
+        levels.append(policy.actions(now))
+    assert levels == [["pulse"], ["pulse"], ["pulse", "sound"]]
+    assert policy.actions(700.0) == ["pulse"]  # Only the touch at 120s is left in the window
+
+
+def test_builtin_chime_is_rendered_once(tmp_path, monkeypatch):
+    """Built-in chimes are written as WAV files on first use and reused after"""
+    import wave
+
+    from ui import sound
+
+    monkeypatch.setattr(sound, "SOUNDS_DIR", tmp_path)
+    path = sound.chime_path("chime")
+    with wave.open(str(path)) as chime:
+        assert chime.getframerate() == sound.SAMPLE_RATE
+        assert chime.getnframes() == sum(int(sound.SAMPLE_RATE * seconds) for _, seconds in sound.BUILTIN_CHIMES["chime"])
+    modified = path.stat().st_mtime_ns
+    assert sound.chime_path("chime") == path and path.stat().st_mtime_ns == modified
+    assert sound.sound_path(str(tmp_path / "missing.wav")) is None
+
+
+def test_quiet_hours_windows():
+    """Windows match local time and weekday, including ones that cross midnight"""
+    from datetime import datetime
+
+    from backend.schedule import active_window, validate_window
+
+    overnight = {"start": "21:00", "end": "08:00", "days": [4]}  # Friday night
+    assert active_window([overnight], datetime(2024, 5, 10, 22, 0))  # Friday 22:00
+    assert active_window([overnight], datetime(2024, 5, 11, 7, 59))  # Saturday morning, same window
+    assert not active_window([overnight], datetime(2024, 5, 11, 22, 0))  # Saturday night
+    assert not active_window([overnight], datetime(2024, 5, 10, 8, 30))  # Friday morning belongs to Thursday
+
+    lunch = validate_window({"start": "12:00", "end": "13:00"})
+    assert lunch == {"start": "12:00", "end": "13:00", "days": [0, 1, 2, 3, 4, 5, 6]}
+    assert active_window([lunch], datetime(2024, 5, 12, 12, 30)) == lunch
+    assert validate_window({"start": "25:00", "end": "08:00"}) is None
+    assert validate_window({"start": "08:00", "end": "08:00"}) is None
+    assert validate_window({"start": "08:00", "end": "09:00", "days": [7]}) is None
+
+
+def test_region_alert_overrides():
+    """A region's own cooldown, actions and on/off switch take precedence over the general settings"""
+    from backend.alerts import AlertPipeline
+    from backend.detection.settings_store import DEFAULTS
+
+    pipeline = AlertPipeline()
+    pipeline.configure(
+        {
+            **DEFAULTS,
+            "alert_burst_window": 0.0,
+            "escalation_levels": [{"touches": 1, "actions": ["pulse"]}],
+            "region_alerts": {"mouth": {"cooldown": 60.0, "actions": ["sound"]}, "eyes": {"enabled": False}},
+        }
+    )
+    alerts = []
+    pipeline.alert.connect(lambda regions, actions: alerts.append((regions, actions)))
+
+    pipeline.submit(["mouth", "eyes"], now=0.0)
+    pipeline.submit(["mouth"], now=30.0)  # Still cooling down, 60s for this region
+    pipeline.submit(["scalp"], now=30.0)
+    assert alerts == [(["mouth"], ["sound"]), (["scalp"], ["pulse"])]
+
+
+def test_alert_templates_render_placeholders():
+    """Placeholders are filled in, region names localized, and bad templates never raise"""
+    from backend.templates import AlertTemplates, render, validate
+
+    english = AlertTemplates("en", {"notify_body": "{region}! {count_today} today, clean for {streak}"})
+    assert english.render("notify_body", ["scalp", "eyes", "mouth"], count_today=3, streak="12m") == "scalp, eyes and mouth! 3 today, clean for 12m"
+    assert AlertTemplates("fr").render("notify_body", ["eyes"]) == "Éloignez vos mains : yeux"
+    assert AlertTemplates("xx").language in ("en", "de", "es", "fr", "el")
+
+    assert validate("{region} {streak}") is None
+    assert validate("{regoin}") == "unknown placeholder {regoin}"
+    assert validate("{region") is not None
+    assert render("{unknown} {region}", {"region": "mouth"}) == "{unknown} mouth"
+    assert render("{region", {"region": "mouth"}) == "{region"
+
+
+def test_alert_outcomes_efficacy(tmp_path):
+    """Each alert's outcome is appended to the log; efficacy groups them by alert style"""
+    from backend.alert_outcomes import AlertOutcomes, efficacy
+
+    outcomes = AlertOutcomes(path=tmp_path / "outcomes.jsonl")
+    outcomes.alert_shown(["mouth"], ["pulse"], now=100.0)
+    outcomes.contact_ended(now=103.0)
+    outcomes.alert_shown(["mouth"], ["pulse", "sound"], now=200.0)  # Finalizes the first one
+    outcomes.responded("false_positive")
+    outcomes.responded("aware")  # Only the first response counts
+    outcomes.finalize()
+
+    loaded = outcomes.load()
+    assert [o["stopped_after"] for o in loaded] == [3.0, None]
+    assert loaded[1]["response"] == "false_positive"
+    styles = efficacy(loaded)
+    assert styles["pulse"]["stop_rate"] == 1.0
+    assert styles["pulse+sound"] == {"alerts": 1, "stopped": 0, "responses": {"false_positive": 1}, "stop_rate": 0.0}
+
+
+def test_profile_snapshot():
+    """A profile holds only the profile keys; names are trimmed and must not be empty"""
+    from backend import profiles
+    from backend.detection import settings_store
+
+    profile = profiles.snapshot(settings_store.DEFAULTS)
+    assert set(profile) == set(profiles.PROFILE_KEYS)
+    assert all(key in settings_store.DEFAULTS for key in profiles.PROFILE_KEYS)
+    assert profiles.clean_name("  Deep   work ") == "Deep work"
+    assert profiles.clean_name("   ") is None
+    assert profiles.clean_name("x" * 41) is None
+
+
+def test_settings_bundle_round_trip(tmp_path):
+    """An exported file brings back settings and stats, minus this machine's own keys"""
+    import json
+
+    import pytest
+
+    from backend.detection import settings_store
+
+    settings = {**settings_store.DEFAULTS, "sensitivity": 1.4, "camera_index": 2, "profiles": {"Evening": {"sensitivity": 0.8}}}
+    settings_store.export_bundle(settings, tmp_path / "bundle.json", stats=[{"time": 1.0}])
+
+    imported, stats = settings_store.read_bundle(tmp_path / "bundle.json")
+    assert imported["sensitivity"] == 1.4 and imported["profiles"] == {"Evening": {"sensitivity": 0.8}}
+    assert "camera_index" not in imported
+    assert stats == [{"time": 1.0}]
+
+    (tmp_path / "newer.json").write_text(json.dumps({"format": settings_store.BUNDLE_FORMAT, "version": 99, "settings": {}}))
+    with pytest.raises(ValueError):
+        settings_store.read_bundle(tmp_path / "newer.json")
+
+
+def test_onboarding_steps():
+    """Steps are kept in order and setup finishes once all are done"""
+    import pytest
+
+    from backend import onboarding
+
+    assert onboarding.state([]) == {"steps": dict.fromkeys(onboarding.STEPS, False), "next": "camera_permission", "finished": False}
+    completed = onboarding.complete(["first_session"], "camera_permission")
+    assert completed == ["camera_permission", "first_session"]
+    assert onboarding.next_step(completed) == "calibration"
+    assert onboarding.state(onboarding.complete(completed, "calibration"))["finished"]
+    with pytest.raises(ValueError):
+        onboarding.complete([], "tutorial")
+
+
+def test_linux_cameras_skip_metadata_nodes(tmp_path, monkeypatch):
+    """Each camera is listed once, by its capture node"""
+    from backend import cameras
+
+    for node, index, name in [("video0", "0", "Integrated Camera"), ("video1", "1", "Integrated Camera"), ("video2", "0", "USB Webcam")]:
+        (tmp_path / node).mkdir()
+        (tmp_path / node / "index").write_text(index + "\n")
+        (tmp_path / node / "name").write_text(name + "\n")
+    monkeypatch.setattr(cameras, "V4L_PATH", tmp_path)
+
+    assert cameras._linux_cameras() == [{"index": 0, "name": "Integrated Camera"}, {"index": 2, "name": "USB Webcam"}]
+
+
+def test_custom_region_definitions():
+    """Custom regions need a fresh id and at least three face landmarks; presets are valid as they are"""
+    from backend.detection import custom_regions
+    from backend.detection.options import BackendOptions
+
+    for region, preset in custom_regions.PRESETS.items():
+        assert custom_regions.validate(region, preset)["name"] == preset["name"]
+
+    assert custom_regions.validate("left_ear", {"landmarks": [127, 234, 93]}) == {
+        "name": "Left ear",
+        "landmarks": [127, 234, 93],
+        "offset": [0.0, 0.0],
+        "padding": 0.0,
+    }
+    assert custom_regions.validate("mouth", {"landmarks": [1, 2, 3]}) is None  # Built-in
+    assert custom_regions.validate("Neck!", {"landmarks": [1, 2, 3]}) is None
+    assert custom_regions.validate("neck", {"landmarks": [1, 1, 2]}) is None
+    assert custom_regions.validate("neck", {"landmarks": [1, 2, 999]}) is None
+    assert custom_regions.validate("neck", {"landmarks": [1, 2, 3], "offset": [0, 5]}) is None
+
+    options = BackendOptions.from_settings({"active_regions": ["mouth", "neck", "chin"], "custom_regions": {"neck": custom_regions.PRESETS["neck"]}})
+    assert options.enabled_regions == ["mouth", "neck"]
+    assert list(options.custom_regions) == ["neck"]
+
+
+def test_settings_backups(tmp_path, monkeypatch):
+    """A backup copies the files that exist and restoring puts them back"""
+    import pytest
+
+    from backend.detection import settings_store
+
+    monkeypatch.setattr(settings_store, "BACKUPS_PATH", tmp_path / "backups")
+    settings, history = tmp_path / "settings.json", tmp_path / "alert_outcomes.jsonl"
+    settings.write_text("original")
+
+    first = settings_store.backup([settings, history])
+    second = settings_store.backup([settings, history])
+    assert first != second  # Same second, still two backups
+    assert [entry["files"] for entry in settings_store.list_backups()] == [["settings.json"], ["settings.json"]]
+
+    settings.write_text("changed")
+    assert settings_store.restore_backup(first.name, [settings, history]) == ["settings.json"]
+    assert settings.read_text() == "original"
+    with pytest.raises(FileNotFoundError):
+        settings_store.restore_backup("../..", [settings])
+
+
+def test_translation_catalogs():
+    """Every translation keeps the English text's placeholders, and unknown languages follow the system"""
+    import string
+
+    from backend import i18n
+
+    def placeholders(text):
+        return {name for _, name, _, _ in string.Formatter().parse(text) if name}
+
+    for catalog in i18n.CATALOGS.values():
+        for english, translated in catalog.items():
+            assert placeholders(translated) == placeholders(english), english
+
+    try:
+        assert i18n.set_locale("fr") == "fr"
+        assert i18n.tr("For {minutes} Minutes", minutes=15) == "Pendant 15 minutes"
+        assert i18n.tr("Not in any catalog") == "Not in any catalog"
+        assert i18n.set_locale("xx") == i18n.system_language()
+    finally:
+        i18n.set_locale("en")
+
+
+def test_event_store_records_and_filters(tmp_path):
+    """Episodes go in with their regions, responses attach to the alerted one, and queries filter by range and fields"""
+    import sqlite3
+
+    from backend.stats import events
+
+    store = events.EventStore(tmp_path / "history.db")
+
+    def episode(start, regions, alerted):
+        return {"start": start, "end": start + 2, "duration": 2.0, "regions": regions, "peak_contacts": 1, "alerted": alerted, "confidence": 0.8}
+
+    store.respond("aware", 101.0)  # Toast clicked while the touch is still going on
+    store.add(episode(100.0, ["mouth", "beard"], alerted=True))
+    store.add(episode(200.0, ["eyes"], alerted=True))
+    store.respond("snooze", 201.0)
+    store.respond("false_positive", 201.5)  # Only the first response counts
+    store.add(episode(300.0, ["eyes"], alerted=False))
+
+    assert [event["response"] for event in store.query()] == ["aware", "snooze", None]
+    assert store.query()[0]["regions"] == ["beard", "mouth"]
+    assert [event["start"] for event in store.query(since=150.0, until=300.0)] == [200.0]
+    assert [event["start"] for event in store.query(regions=["eyes"], alerted=True)] == [200.0]
+    assert [event["start"] for event in store.query(responses=["aware", None])] == [100.0, 300.0]
+    assert [event["start"] for event in store.query(limit=2)] == [200.0, 300.0]
+    store.close()
+
+    # Reopening an up-to-date database doesn't migrate again
+    db = sqlite3.connect(str(tmp_path / "history.db"))
+    assert events.migrate(db) == events.SCHEMA_VERSION
+    db.close()
+
+
+def test_stats_summary_compares_periods(tmp_path):
+    """A day's summary counts its own touches per region and hour, against the whole day before"""
+    from datetime import datetime
+
+    from backend.stats import EventStore, summary
+
+    store = EventStore(tmp_path / "history.db")
+    noon = datetime(2024, 5, 15, 12, 0).timestamp()
+    for start, regions in [(noon, ["mouth"]), (noon + 60, ["mouth", "eyes"]), (noon + 3 * 3600, ["eyes"]), (noon - 86400, ["scalp"])]:
+        store.add({"start": start, "end": start + 3, "duration": 3.0, "regions": regions, "alerted": True})
+
+    day = summary.summarize(store, "day", now=noon)
+    assert day["touches"] == 3 and day["total_duration"] == 9.0 and day["alerts"] == 3
+    assert list(day["regions"]) == ["eyes", "mouth"] and day["regions"]["mouth"] == {"touches": 2, "duration": 6.0}
+    assert day["busiest_hours"] == [[12, 2], [15, 1]]
+    assert day["previous"]["touches"] == 1 and day["change"]["touches"] == 2.0
+
+    assert summary.period_bounds("week", now=noon)[0] == datetime(2024, 5, 13).timestamp()
+    assert summary.period_bounds("month", now=noon)[1:] == (datetime(2024, 6, 1).timestamp(), datetime(2024, 4, 1).timestamp())
+    assert summary.summarize(store, "month", now=noon)["change"]["touches"] is None  # Nothing in April
+    store.close()
+
+
+def test_streaks_are_recorded_and_best_survives(tmp_path):
+    """A touch ends the streak in progress, and the best one is remembered across restarts"""
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.stats import EventStore
+    from backend.stats.streaks import StreakTracker
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    store = EventStore(tmp_path / "history.db")
+    tracker = StreakTracker(store)
+    tracker.start(now=1000.0)
+    tracker.touched(now=1600.0)  # Ten minutes clean
+    assert tracker.status(now=1660.0) == {"current": 60.0, "best": 600.0, "best_at": 1000.0}
+    tracker.stop(now=1700.0)
+    assert tracker.status()["current"] is None
+
+    assert StreakTracker(store).status() == {"current": None, "best": 600.0, "best_at": 1000.0}
+    store.close()
+
+
+def test_history_export_formats(tmp_path):
+    """CSV and JSON exports share the stable columns; relative exports hold offsets instead of dates"""
+    import csv
+    import json
+
+    import pytest
+
+    from backend.stats import EventStore, export
+
+    store = EventStore(tmp_path / "history.db")
+    for start, regions in [(1000.0, ["mouth", "eyes"]), (1060.5, ["scalp"])]:
+        store.add({"start": start, "end": start + 2, "duration": 2.0, "regions": regions, "alerted": start == 1000.0})
+    store.respond("aware", 1001.0)
+
+    assert export.export_events(store, "csv", tmp_path / "h.csv") == 2
+    with (tmp_path / "h.csv").open() as f:
+        rows = list(csv.DictReader(f))
+    assert list(rows[0]) == export.COLUMNS
+    assert rows[0]["regions"] == "eyes;mouth" and rows[0]["alerted"] == "1" and rows[0]["response"] == "aware"
+    assert rows[1]["response"] == ""
+
+    assert export.export_events(store, "json", tmp_path / "h.json", since=1050.0, relative=True) == 1
+    exported = json.loads((tmp_path / "h.json").read_text())
+    assert exported["relative_times"] and "exported" not in exported
+    assert exported["events"][0]["start"] == 0.0 and exported["events"][0]["end"] == 2.0
+
+    with pytest.raises(ValueError):
+        export.export_events(store, "xlsx", tmp_path / "h.xlsx")
+    store.close()
+
+
+def test_history_retention_rolls_up_old_events(tmp_path):
+    """Pruning keeps recent events, folds older ones into daily totals, and summaries still count them"""
+    from datetime import datetime
+
+    from backend.stats import EventStore, retention, summary
+
+    store = EventStore(tmp_path / "history.db")
+    now = datetime(2024, 5, 20, 12, 0).timestamp()
+    old = datetime(2024, 5, 1, 9, 0).timestamp()
+    for start, regions in [(old, ["mouth", "eyes"]), (old + 60, ["mouth"]), (now - 3600, ["eyes"])]:
+        store.add({"start": start, "end": start + 4, "duration": 4.0, "regions": regions, "alerted": True})
+
+    assert retention.prune(store, 0, now=now) == 0  # Keeping everything
+    assert retention.prune(store, 7, now=now) == 2
+    assert retention.prune(store, 7, now=now) == 0
+    assert [event["start"] for event in store.query()] == [now - 3600]
+
+    usage = retention.storage_usage(store)
+    assert usage["events"] == 1 and usage["rolled_up_days"] == 1 and usage["oldest_day"] == "2024-05-01" and usage["bytes"] > 0
+
+    month = summary.summarize(store, "month", now=now)
+    assert month["touches"] == 3 and month["total_duration"] == 12.0
+    assert month["regions"]["mouth"] == {"touches": 2, "duration": 8.0} and month["regions"]["eyes"]["touches"] == 2
+    store.close()
+
+
+def test_work_sessions_collect_their_events(tmp_path):
+    """Events recorded during a session belong to it, and its summary measures responses and streaks"""
+    from backend.stats import EventStore, sessions
+
+    store = EventStore(tmp_path / "history.db")
+    store.add({"start": 50.0, "end": 52.0, "duration": 2.0, "regions": ["eyes"], "alerted": False})  # Before the session
+    session_id = sessions.start(store, " Writing ", now=100.0)
+    store.add({"start": 160.0, "end": 165.0, "duration": 5.0, "regions": ["mouth"], "alerted": True, "alerted_at": 161.0})
+    store.add({"start": 400.0, "end": 402.0, "duration": 2.0, "regions": ["mouth"], "alerted": True, "alerted_at": 401.0})
+    store.add({"start": 500.0, "end": 501.0, "duration": 1.0, "regions": ["eyes"], "alerted": False})
+    assert sessions.end(store, now=1000.0) == session_id
+    store.add({"start": 1200.0, "end": 1201.0, "duration": 1.0, "regions": ["eyes"], "alerted": False})  # After it
+
+    result = sessions.summary(store, session_id)
+    assert result["label"] == "Writing" and result["duration"] == 900.0
+    assert result["touches"] == 3 and result["alerts"] == 2
+    assert result["average_response"] == 2.5  # 4s and 1s
+    assert result["longest_streak"] == 599.0  # From the last alert to the end
+    assert sessions.summary(store, 999) is None
+
+    sessions.start(store, "Crashed", now=2000.0)
+    store.session_id = None  # As after a crash
+    sessions.close_abandoned(store)
+    assert sessions.list_sessions(store)[0]["end"] == 2000.0
+    store.close()
+
+
+def test_stats_heatmap_by_region_and_hour(tmp_path):
+    """The heatmap counts each region's touches per local hour, and names the busiest cell"""
+    from datetime import datetime
+
+    from backend.stats import EventStore, summary
+
+    store = EventStore(tmp_path / "history.db")
+    at = [datetime(2024, 5, 15, hour, minute).timestamp() for hour, minute in [(15, 0), (15, 30), (9, 5), (15, 45)]]
+    for start, regions in zip(at, [["scalp"], ["scalp", "mouth"], ["mouth"], ["scalp"]]):
+        store.add({"start": start, "end": start + 1, "duration": 1.0, "regions": regions, "alerted": False})
+
+    result = summary.heatmap(store)
+    assert sorted(result["counts"]) == ["mouth", "scalp"] and len(result["counts"]["scalp"]) == 24
+    assert result["counts"]["scalp"][15] == 3 and result["counts"]["mouth"][9] == 1 and result["counts"]["mouth"][15] == 1
+    assert result["peak"] == {"region": "scalp", "hour": 15, "touches": 3}
+    window = summary.heatmap(store, since=at[0] + 1, until=at[3])["counts"]  # Only the 15:30 touch
+    assert {region: sum(hours) for region, hours in window.items()} == {"mouth": 1, "scalp": 1}
+    store.close()
+
+
+def test_stats_trends_over_daily_counts(tmp_path):
+    """Daily counts (rolled-up days included) give a moving average, week-over-week change and a falling slope"""
+    from datetime import datetime, timedelta
+
+    from backend.stats import EventStore, retention, trends
+
+    store = EventStore(tmp_path / "history.db")
+    today = datetime(2024, 5, 28, 12, 0)
+    # 14 days ago: 14 touches, then one fewer each day
+    for days_ago in range(14):
+        for touch in range(days_ago + 1):
+            start = (today - timedelta(days=days_ago)).timestamp() - touch * 60
+            store.add({"start": start, "end": start + 1, "duration": 1.0, "regions": ["mouth"], "alerted": False})
+    retention.prune(store, 10, now=today.timestamp())  # The oldest days only have totals now
+
+    result = trends.trends(store, 14, now=today.timestamp())
+    assert result["days"][-1] == "2024-05-28" and result["counts"] == list(range(14, 0, -1))
+    assert result["moving_average"][0] == 14 and result["moving_average"][-1] == 4.0  # (7 + 6 + ... + 1) / 7
+    assert result["week_over_week"] == (28 - 77) / 77
+    assert abs(result["slope"] + 1.0) < 1e-9
+    assert trends.trends(store, 1, now=today.timestamp())["slope"] is None
+    store.close()
+
+
+def test_daily_goals_progress_and_outcomes(tmp_path):
+    """A streak goal is reached during the day, a touch goal once the day ends under its target"""
+    from datetime import datetime
+
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.stats import EventStore
+    from backend.stats.goals import ACHIEVED, IN_PROGRESS, MAX_TOUCHES, STREAK_MINUTES, GoalTracker, valid_goals
+    from backend.stats.streaks import StreakTracker
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    assert valid_goals([{"kind": "max_touches", "target": 0}, {"kind": "nope", "target": 5}, {"kind": "streak_minutes", "target": 30}]) == [
+        {"kind": STREAK_MINUTES, "target": 30}
+    ]
+
+    store = EventStore(tmp_path / "history.db")
+    streaks = StreakTracker(store)
+    tracker = GoalTracker(store, streaks)
+    reached = []
+    tracker.achieved.connect(reached.append)
+    morning = datetime(2024, 5, 15, 9, 0).timestamp()
+    tracker.configure([{"kind": MAX_TOUCHES, "target": 3}, {"kind": STREAK_MINUTES, "target": 60}], now=morning)
+
+    streaks.start(now=morning)
+    store.add({"start": morning + 600, "end": morning + 602, "duration": 2.0, "regions": ["mouth"], "alerted": True})
+    streaks.touched(now=morning + 600)
+    progress = tracker.evaluate(now=morning + 601)
+    assert [(entry["value"], entry["status"]) for entry in progress] == [(1, IN_PROGRESS), (10.0, IN_PROGRESS)]
+
+    tracker.evaluate(now=morning + 600 + 3600)  # An hour since the touch
+    assert [(entry["kind"], entry["status"]) for entry in reached] == [(STREAK_MINUTES, ACHIEVED)]
+    tracker.evaluate(now=morning + 600 + 3660)
+    assert len(reached) == 1  # Announced once
+
+    streaks.stop(now=morning + 4500)
+    tracker.evaluate(now=(datetime(2024, 5, 16, 9, 0)).timestamp())
+    assert reached[-1]["kind"] == MAX_TOUCHES
+    outcomes = {(entry["day"], entry["kind"]): entry["achieved"] for entry in tracker.outcomes()}
+    assert outcomes[("2024-05-15", MAX_TOUCHES)] is True and outcomes[("2024-05-15", STREAK_MINUTES)] is True
+    assert outcomes[("2024-05-16", MAX_TOUCHES)] is None  # Today's isn't decided yet
+    assert {entry["day"] for entry in tracker.outcomes(since_day="2024-05-16")} == {"2024-05-16"}
+    store.close()
+
+
+def test_achievements_unlock_once_and_persist(tmp_path):
+    """A week of use, halving the touches and an hour-long streak each unlock once, and stay unlocked"""
+    from datetime import datetime, timedelta
+
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.stats import EventStore
+    from backend.stats.achievements import HALVED_WEEK, HOUR_STREAK, WEEK_OF_USE, AchievementTracker
+    from backend.stats.streaks import StreakTracker
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    store = EventStore(tmp_path / "history.db")
+    streaks = StreakTracker(store)
+    tracker = AchievementTracker(store, streaks)
+    unlocked = []
+    tracker.unlocked.connect(lambda achievement: unlocked.append(achievement["id"]))
+
+    first = datetime(2024, 5, 1, 10, 0)
+    for offset in range(14):
+        for touch in range(2 if offset < 7 else 1):  # Two touches a day for a week, then one
+            start = (first + timedelta(days=offset, hours=touch)).timestamp()
+            store.add({"start": start, "end": start + 1, "duration": 1.0, "regions": ["nose"], "alerted": False})
+        if offset == 5:
+            assert tracker.check(now=start) == []  # Six days in
+    assert tracker.check(now=(first + timedelta(days=6, hours=12)).timestamp()) == [WEEK_OF_USE]
+    evening = (first + timedelta(days=13, hours=12)).timestamp()
+    assert tracker.check(now=evening) == [HALVED_WEEK]
+
+    streaks.start(now=evening)
+    assert tracker.check(now=evening + 1800) == []
+    assert tracker.check(now=evening + 3600) == [HOUR_STREAK]
+    assert tracker.check(now=evening + 7200) == []
+    assert unlocked == [WEEK_OF_USE, HALVED_WEEK, HOUR_STREAK]
+
+    again = AchievementTracker(store, streaks)
+    assert {entry["id"]: entry["unlocked_at"] for entry in again.achievements()}[HOUR_STREAK] == evening + 3600
+    store.close()
+
+
+def test_weekly_report_html(tmp_path):
+    """The weekly report counts the week's touches per day and region against the week before, and escapes what it shows"""
+    from datetime import date, datetime
+
+    from backend.stats import EventStore, sessions
+    from backend.stats.report import generate_report, week_start
+
+    assert week_start(date(2024, 5, 16)) == date(2024, 5, 13)
+    store = EventStore(tmp_path / "history.db")
+    for day, region in ((8, "mouth"), (14, "nose"), (14, "nose"), (16, "mouth")):
+        start = datetime(2024, 5, day, 10, 0).timestamp()
+        store.add({"start": start, "end": start + 90, "duration": 90.0, "regions": [region], "alerted": True})
+    start = datetime(2024, 5, 15, 9, 0).timestamp()
+    sessions.start(store, "<Deep> work", now=start)
+    sessions.end(store, now=start + 3600)
+
+    path = generate_report(store, tmp_path / "report.html", week=date(2024, 5, 16), now=start + 7200)
+    html = path.read_text(encoding="utf-8")
+    assert "2024-05-13" in html and "2024-05-19" in html
+    assert "+200%" in html  # Three touches this week, one the week before
+    assert "<td>nose</td><td>2</td><td>3m 00s</td><td>0</td>" in html
+    assert "<td>mouth</td><td>1</td><td>1m 30s</td><td>1</td>" in html
+    assert "&lt;Deep&gt; work" in html and "<Deep>" not in html
+    store.close()
+
+
+def test_annotations_over_time_ranges(tmp_path):
+    """Annotations overlap the ranges asked for, filter by tag, and come back with summaries, trends and the weekly report"""
+    from datetime import date, datetime
+
+    import pytest
+
+    from backend.stats import EventStore, annotations, summary, trends
+    from backend.stats.report import render
+
+    store = EventStore(tmp_path / "history.db")
+    morning = datetime(2024, 5, 15, 9, 0).timestamp()
+    deadline = annotations.add(store, morning, morning + 4 * 3600, " Stressful deadline ", ["Work", "stress", "work", " "])
+    annotations.add(store, morning + 86400, morning + 86400 + 600, "Forgot to take a break", ["breaks"])
+    with pytest.raises(ValueError):
+        annotations.add(store, morning, morning - 1, "Backwards")
+
+    assert annotations.query(store)[0] == {
+        "id": deadline,
+        "start": morning,
+        "end": morning + 4 * 3600,
+        "text": "Stressful deadline",
+        "tags": ["stress", "work"],
+    }
+    assert [note["id"] for note in annotations.query(store, since=morning + 3600, until=morning + 7200)] == [deadline]  # Overlapping
+    assert [note["text"] for note in annotations.query(store, tags=["BREAKS"])] == ["Forgot to take a break"]
+
+    noon = morning + 3 * 3600
+    assert [note["id"] for note in summary.summarize(store, "day", now=noon)["annotations"]] == [deadline]
+    assert len(trends.trends(store, 7, now=noon + 86400)["annotations"]) == 2
+    assert "Stressful deadline" in render(store, date(2024, 5, 15), now=noon)
+
+    assert annotations.remove(store, deadline) and not annotations.remove(store, deadline)
+    assert [note["text"] for note in annotations.query(store)] == ["Forgot to take a break"]
+    store.close()
+
+
+def test_backup_and_restore_verified(tmp_path):
+    """A backup brings back the history and settings it was made with, and a damaged one changes nothing"""
+    import zipfile
+
+    import pytest
+
+    from backend.stats import EventStore, backup
+
+    store = EventStore(tmp_path / "history.db")
+    settings = tmp_path / "settings.json"
+    settings.write_text('{"sensitivity": 1.4}')
+    store.add({"start": 100.0, "end": 102.0, "duration": 2.0, "regions": ["eyes"], "alerted": False})
+    assert backup.backup(store, tmp_path / "backup.zip", [settings, tmp_path / "missing.jsonl"]) == ["history.db", "settings.json"]
+
+    store.add({"start": 200.0, "end": 201.0, "duration": 1.0, "regions": ["mouth"], "alerted": True})
+    settings.write_text('{"sensitivity": 0.8}')
+    assert backup.restore(store, tmp_path / "backup.zip", [settings]) == ["history.db", "settings.json"]
+    assert [event["regions"] for event in store.query()] == [["eyes"]]
+    assert settings.read_text() == '{"sensitivity": 1.4}'
+
+    # Tampered with: the checksum no longer matches, so nothing is restored
+    with zipfile.ZipFile(tmp_path / "backup.zip") as original, zipfile.ZipFile(tmp_path / "tampered.zip", "w") as tampered:
+        for name in original.namelist():
+            tampered.writestr(name, '{"sensitivity": 9}' if name == "settings.json" else original.read(name))
+    store.add({"start": 300.0, "end": 301.0, "duration": 1.0, "regions": ["nose"], "alerted": False})
+    with pytest.raises(ValueError, match="settings.json"):
+        backup.restore(store, tmp_path / "tampered.zip", [settings])
+    (tmp_path / "not-a-backup.zip").write_text("hello")
+    with pytest.raises(ValueError):
+        backup.restore(store, tmp_path / "not-a-backup.zip", [settings])
+    assert len(store.query()) == 2 and settings.read_text() == '{"sensitivity": 1.4}'
+    store.close()
+
+
+def test_erase_overwrites_then_deletes(tmp_path):
+    """Erasing zeroes each file before unlinking it, reports progress, and leaves out the instance lock"""
+    import os
+
+    from backend import erase
+
+    (tmp_path / "logs").mkdir()
+    for name in ("history.db", "settings.json", "logs/backend.log"):
+        (tmp_path / name).write_text("private " * 100)
+    os.link(tmp_path / "history.db", tmp_path / "peek")  # A second name for the same data shows what shred() left behind
+    files = [path for path in erase.data_files(tmp_path) if path.name != "peek"]
+    assert files[0] == tmp_path / "logs" / "backend.log"  # Deepest first
+
+    progress = []
+    assert erase.erase(files, lambda done, total, path: progress.append((done, total, path.name)), folder=tmp_path) == []
+    assert progress[-1] == (3, 3, "settings.json") and len(progress) == 3
+    assert (tmp_path / "peek").read_bytes() == bytes(800)
+    assert sorted(path.name for path in tmp_path.iterdir()) == ["peek"]  # The emptied logs folder went too
+
+
+def test_live_stats_snapshot(tmp_path):
+    """A live snapshot counts the touches of this clock hour and today, with the streak and work session in progress"""
+    from datetime import datetime
+
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.stats import EventStore, live, sessions
+    from backend.stats.streaks import StreakTracker
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    store = EventStore(tmp_path / "history.db")
+    streaks = StreakTracker(store)
+    now = datetime(2024, 5, 15, 14, 20).timestamp()
+    for start in (now - 4 * 3600, now - 1800, now - 600):  # 10:20, 13:50 and 14:10
+        store.add({"start": start, "end": start + 1, "duration": 1.0, "regions": ["eyes"], "alerted": False})
+
+    snapshot = live.snapshot(store, streaks, now=now)
+    assert (snapshot["touches_this_hour"], snapshot["touches_today"]) == (1, 3)
+    assert snapshot["streak"]["current"] is None and snapshot["work_session"] is None
+
+    streaks.start(now=now - 300)
+    sessions.start(store, "Writing", now=now - 60)
+    snapshot = live.snapshot(store, streaks, now=now)
+    assert snapshot["streak"]["current"] == 300.0
+    assert snapshot["work_session"]["label"] == "Writing" and snapshot["work_session"]["duration"] == 60.0
+    store.close()
+
+
+def test_stats_summary_by_foreground_app(tmp_path):
+    """Touches recorded with the app in the foreground add up per app, busiest first; those without one are left out"""
+    from datetime import datetime
+
+    from backend.stats import EventStore, summary
+
+    store = EventStore(tmp_path / "history.db")
+    noon = datetime(2024, 5, 15, 12, 0).timestamp()
+    for offset, app in enumerate(["Slack", "Code", "Slack", None]):
+        start = noon + offset * 60
+        store.add({"start": start, "end": start + 2, "duration": 2.0, "regions": ["mouth"], "alerted": False, "app": app})
+
+    assert [event["app"] for event in store.query()] == ["Slack", "Code", "Slack", None]
+    apps = summary.summarize(store, "day", now=noon)["apps"]
+    assert list(apps) == ["Slack", "Code"] and apps["Slack"] == {"touches": 2, "duration": 4.0}
+    store.close()
+
+
+def test_health_export_formats(tmp_path):
+    """Breathing exercises become HealthKit mindful sessions (with daily summaries) or Google Fit meditation segments"""
+    import json
+    from datetime import datetime
+
+    from backend.stats import EventStore, health
+
+    store = EventStore(tmp_path / "history.db")
+    noon = datetime(2024, 5, 15, 12, 0).timestamp()
+    store.add({"start": noon - 90, "end": noon - 60, "duration": 30.0, "regions": ["mouth"], "alerted": True})
+    health.record_intervention(store, health.BREATHING, 60.0, now=noon)
+
+    assert health.export_health(store, health.HEALTHKIT, tmp_path / "health.json", now=noon) == 2
+    healthkit = json.loads((tmp_path / "health.json").read_text())
+    assert healthkit["format"] == health.EXPORT_FORMAT and healthkit["samples"][0]["type"] == health.MINDFUL_SESSION
+    assert datetime.fromisoformat(healthkit["samples"][0]["end"]).timestamp() == noon
+    assert healthkit["daily_summaries"] == [{"date": "2024-05-15", "touches": 1, "total_duration": 30.0, "alerts": 1}]
+
+    assert health.export_health(store, health.GOOGLE_FIT, tmp_path / "fit.json") == 1
+    fit = json.loads((tmp_path / "fit.json").read_text())
+    assert fit["point"][0]["value"] == [{"intVal": health.FIT_MEDITATION}]
+    assert (fit["minStartTimeNs"], fit["maxEndTimeNs"]) == (int((noon - 60) * 1e9), int(noon * 1e9))
+    assert health.export_health(store, health.GOOGLE_FIT, tmp_path / "later.json", since=noon + 1) == 0
+    store.close()
+
+
+def test_baseline_period_and_relative_progress(tmp_path):
+    """Once the baseline days are over, summaries and baseline goals compare each day with their daily average"""
+    from datetime import date, datetime
+
+    from PyQt6.QtCore import QCoreApplication
+
+    from backend.stats import EventStore, baseline
+    from backend.stats.goals import ACHIEVED, BASELINE_PERCENT, IN_PROGRESS, GoalTracker, valid_goals
+    from backend.stats.streaks import StreakTracker
+    from backend.stats.summary import _totals
+
+    app = QCoreApplication.instance() or QCoreApplication([])  # noqa: F841
+    assert valid_goals([{"kind": BASELINE_PERCENT, "target": 150}]) == []
+    store = EventStore(tmp_path / "history.db")
+    for day, touches in ((1, 10), (2, 10), (15, 6)):
+        for minute in range(touches):
+            start = datetime(2024, 5, day, 10, minute).timestamp()
+            store.add({"start": start, "end": start + 1, "duration": 1.0, "regions": ["mouth"], "alerted": False})
+
+    period = baseline.set_period(store, 2)  # From the first day of use
+    assert period["start"] == datetime(2024, 5, 1).timestamp() and period["end"] == datetime(2024, 5, 3).timestamp()
+    assert baseline.daily_average(store, now=datetime(2024, 5, 2, 12).timestamp()) is None  # Not over yet
+    assert baseline.daily_average(store, now=datetime(2024, 5, 3).timestamp())["touches"] == 10.0
+
+    since, until = datetime(2024, 5, 15).timestamp(), datetime(2024, 5, 16).timestamp()
+    change = baseline.compare(store, _totals(store, since, until), since, until, now=until)
+    assert round(change["touches"], 2) == -0.4
+
+    tracker = GoalTracker(store, StreakTracker(store))
+    tracker.configure([{"kind": BASELINE_PERCENT, "target": 30}], now=datetime(2024, 5, 15, 18).timestamp())
+    assert [(entry["value"], entry["status"]) for entry in tracker.last] == [(40.0, IN_PROGRESS)]
+    reached = []
+    tracker.achieved.connect(reached.append)
+    tracker.evaluate(now=datetime(2024, 5, 16, 9).timestamp())
+    assert [(entry["kind"], entry["status"]) for entry in reached] == [(BASELINE_PERCENT, ACHIEVED)]
+
+    assert baseline.set_period(store, 0) is None and baseline.period(store) is None
+    assert baseline.set_period(store, 7, date(2024, 5, 10))["days"] == 7
+    store.close()
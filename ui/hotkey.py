@@ -0,0 +1,66 @@
+"""
+Global keyboard shortcut for Mindful Touch
+System-wide hotkey (works while other apps have focus) built on pynput's listener thread
+"""
+
+from typing import Optional
+
+from PyQt6.QtCore import QObject, pyqtSignal
+
+DEFAULT_SHORTCUT = "Ctrl+Shift+M"
+
+# Qt-style modifier names -> pynput's
+_MODIFIERS = {"ctrl": "<ctrl>", "shift": "<shift>", "alt": "<alt>", "option": "<alt>", "meta": "<cmd>", "cmd": "<cmd>"}
+
+
+def to_pynput(shortcut: str) -> Optional[str]:
+    """'Ctrl+Shift+M' -> '<ctrl>+<shift>+m'; None if it isn't modifiers plus one key"""
+    parts = [part.strip().lower() for part in shortcut.split("+") if part.strip()]
+    if len(parts) < 2 or any(part not in _MODIFIERS for part in parts[:-1]) or parts[-1] in _MODIFIERS:
+        return None
+    key = parts[-1]
+    if len(key) > 1:
+        key = f"<{key}>"  # Named keys such as F8 or space
+    return "+".join([_MODIFIERS[part] for part in parts[:-1]] + [key])
+
+
+class GlobalHotkey(QObject):
+    """One registered shortcut; activated is emitted on the UI thread"""
+
+    # pynput calls back on its own thread; the signal hops it onto the UI thread
+    activated = pyqtSignal()
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.listener = None
+        self.shortcut = None
+
+    def register(self, shortcut: str) -> bool:
+        """Listen for shortcut system-wide, replacing any earlier one; False if it can't be registered"""
+        self.unregister()
+        combination = to_pynput(shortcut)
+        if combination is None:
+            print(f"Invalid shortcut: {shortcut!r}")
+            return False
+        try:
+            from pynput import keyboard
+        except Exception as e:
+            # Missing package, or no display server to hook into
+            print(f"Global shortcuts unavailable: {e}")
+            return False
+        try:
+            self.listener = keyboard.GlobalHotKeys({combination: self.activated.emit})
+            self.listener.daemon = True
+            self.listener.start()
+        except Exception as e:
+            print(f"Could not register shortcut {shortcut}: {e}")
+            self.listener = None
+            return False
+        self.shortcut = shortcut
+        return True
+
+    def unregister(self):
+        if self.listener:
+            self.listener.stop()
+            self.listener = None
+        self.shortcut = None
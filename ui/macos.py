@@ -0,0 +1,82 @@
+"""
+macOS integration for Mindful Touch
+Small AppKit/AVFoundation calls Qt doesn't expose, made through the Objective-C runtime with ctypes
+"""
+
+import ctypes
+import ctypes.util
+import sys
+from typing import Optional
+
+# NSApplicationActivationPolicy
+ACTIVATION_POLICY_REGULAR = 0  # Dock icon and menu bar
+ACTIVATION_POLICY_ACCESSORY = 1  # Menu bar extras only, no Dock icon
+
+# AVAuthorizationStatus values, by name
+CAMERA_AUTHORIZATION = {0: "not_determined", 1: "restricted", 2: "denied", 3: "authorized"}
+CAMERA_PRIVACY_URL = "x-apple.systempreferences:com.apple.preference.security?Privacy_Camera"
+AVFOUNDATION_PATH = "/System/Library/Frameworks/AVFoundation.framework/AVFoundation"
+
+_objc = None
+
+
+def _runtime():
+    global _objc
+    if _objc is None:
+        _objc = ctypes.cdll.LoadLibrary(ctypes.util.find_library("objc"))
+        _objc.objc_getClass.restype = ctypes.c_void_p
+        _objc.objc_getClass.argtypes = [ctypes.c_char_p]
+        _objc.sel_registerName.restype = ctypes.c_void_p
+        _objc.sel_registerName.argtypes = [ctypes.c_char_p]
+    return _objc
+
+
+def _send(receiver, selector: bytes, *args, restype=ctypes.c_void_p, argtypes=()):
+    """objc_msgSend with an exact prototype (required on Apple silicon, where it isn't variadic)"""
+    objc = _runtime()
+    prototype = ctypes.CFUNCTYPE(restype, ctypes.c_void_p, ctypes.c_void_p, *argtypes)
+    msg_send = ctypes.cast(objc.objc_msgSend, prototype)
+    return msg_send(receiver, objc.sel_registerName(selector), *args)
+
+
+def _shared_application():
+    return _send(_runtime().objc_getClass(b"NSApplication"), b"sharedApplication")
+
+
+def set_dock_icon_visible(visible: bool) -> bool:
+    """Switch between a regular app and a menu-bar-only one; returns False where unsupported"""
+    if sys.platform != "darwin":
+        return False
+    try:
+        app = _shared_application()
+        policy = ACTIVATION_POLICY_REGULAR if visible else ACTIVATION_POLICY_ACCESSORY
+        if not _send(app, b"setActivationPolicy:", policy, restype=ctypes.c_bool, argtypes=(ctypes.c_long,)):
+            return False
+        # Changing policy deactivates the app; bring it back so its windows stay usable
+        _send(app, b"activateIgnoringOtherApps:", True, restype=None, argtypes=(ctypes.c_bool,))
+        return True
+    except (OSError, AttributeError) as e:
+        print(f"Could not change Dock icon visibility: {e}")
+        return False
+
+
+def camera_authorization() -> Optional[str]:
+    """Whether we may use the camera: see CAMERA_AUTHORIZATION; None where there's nothing to ask"""
+    if sys.platform != "darwin":
+        return None
+    try:
+        ctypes.cdll.LoadLibrary(AVFOUNDATION_PATH)
+        objc = _runtime()
+        # AVMediaTypeVideo is the string "vide"
+        media_type = _send(objc.objc_getClass(b"NSString"), b"stringWithUTF8String:", b"vide", argtypes=(ctypes.c_char_p,))
+        status = _send(
+            objc.objc_getClass(b"AVCaptureDevice"),
+            b"authorizationStatusForMediaType:",
+            media_type,
+            restype=ctypes.c_long,
+            argtypes=(ctypes.c_void_p,),
+        )
+        return CAMERA_AUTHORIZATION.get(status)
+    except (OSError, AttributeError) as e:
+        print(f"Could not check camera permission: {e}")
+        return None
@@ -96,6 +96,11 @@ class CameraPanel(QWidget):
         header_row.addWidget(title)
         header_row.addStretch()
 
+        # Frame rate / CPU / memory while detecting
+        self.metrics_label = QLabel("")
+        self.metrics_label.setStyleSheet(Theme.helper_text_style())
+        header_row.addWidget(self.metrics_label)
+
         self.privacy_button = QPushButton("Hide feed")
         self.privacy_button.setEnabled(False)
         self.privacy_button.setCursor(Qt.CursorShape.PointingHandCursor)
@@ -173,9 +178,12 @@ class CameraPanel(QWidget):
         """Update UI for detection state"""
         self.is_detecting = detecting
         self.privacy_button.setEnabled(detecting)
-        if not detecting:
+        if detecting:
+            self.camera_label.setText("Starting camera…\n\nLoading the detection model.")
+        else:
             self.privacy_button.setText("Hide feed")
             self.show_feed = True
+            self.metrics_label.setText("")
             self._set_default_message()
 
     def set_privacy_state(self, show_feed):
@@ -188,6 +196,20 @@ class CameraPanel(QWidget):
             self.privacy_button.setText("Show feed")
             self.camera_label.setText("Privacy mode\n\nDetection keeps running in the background.\nPress  Show feed  to view the camera.")
 
+    def set_suspended_state(self, suspended):
+        """Show that the camera is off while detection is suspended"""
+        if suspended:
+            self.metrics_label.setText("")
+            self.camera_label.setText("Suspended\n\nThe camera is off and detection uses no CPU.\nChoose  Suspend Detection  again to resume.")
+        elif self.show_feed:
+            self.camera_label.setText("Starting camera…\n\nReopening the camera.")
+        else:
+            self.set_privacy_state(False)
+
+    def set_metrics_text(self, text):
+        """Show the latest resource usage sample in the header"""
+        self.metrics_label.setText(text)
+
     def update_camera_frame(self, pixmap):
         """Update camera display with new frame"""
         if self.show_feed:
@@ -98,14 +98,12 @@ class DetectionPanel(QWidget):
         layout.addWidget(helper)
         layout.addSpacing(10)
 
-        # Region rows
-        regions = Config.AVAILABLE_REGIONS
-        for i, region in enumerate(regions):
-            row = RegionRow(REGION_LABELS.get(region, region.title()), last=(i == len(regions) - 1))
-            row.setChecked(region in Config.ACTIVE_REGIONS)
-            row.toggled.connect(lambda checked, r=region: self.region_toggled.emit(r, checked))
-            self.region_toggles[region] = row
-            layout.addWidget(row)
+        # Region rows, built-in then custom
+        self.region_rows = QVBoxLayout()
+        self.region_rows.setContentsMargins(0, 0, 0, 0)
+        self.region_rows.setSpacing(0)
+        layout.addLayout(self.region_rows)
+        self.refresh_regions()
 
         layout.addStretch()
 
@@ -206,6 +204,21 @@ class DetectionPanel(QWidget):
         """Enable/disable the detection button during transitions"""
         self.detection_button.setEnabled(enabled)
 
+    def refresh_regions(self):
+        """One row per region, e.g. after custom regions were added or removed"""
+        for row in self.region_toggles.values():
+            self.region_rows.removeWidget(row)
+            row.deleteLater()
+        self.region_toggles = {}
+        regions = Config.all_regions()
+        for i, region in enumerate(regions):
+            label = Config.CUSTOM_REGIONS[region]["name"] if region in Config.CUSTOM_REGIONS else REGION_LABELS.get(region, region.title())
+            row = RegionRow(label, last=(i == len(regions) - 1))
+            row.setChecked(region in Config.ACTIVE_REGIONS)
+            row.toggled.connect(lambda checked, r=region: self.region_toggled.emit(r, checked))
+            self.region_toggles[region] = row
+            self.region_rows.addWidget(row)
+
     def update_region_state(self, region, active):
         """Update region toggle state (for external changes)"""
         if region in self.region_toggles:
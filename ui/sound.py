@@ -0,0 +1,112 @@
+"""
+Alert sounds for Mindful Touch
+Built-in chimes or the user's own audio files, per region, on a chosen output device
+"""
+
+import math
+import struct
+import sys
+import wave
+from pathlib import Path
+from typing import List, Optional
+
+from PyQt6.QtCore import QObject, QUrl
+from PyQt6.QtMultimedia import QAudioOutput, QMediaDevices, QMediaPlayer
+
+SOUNDS_DIR = Path.home() / ".mindful-touch" / "sounds"
+SYSTEM_ALERT = "/System/Library/Sounds/Glass.aiff"
+SAMPLE_RATE = 44100
+
+# Built-in chimes as (frequency Hz, seconds) notes, rendered to WAV on first use
+BUILTIN_CHIMES = {
+    "chime": [(880.0, 0.12), (1320.0, 0.28)],
+    "bell": [(660.0, 0.6)],
+    "blip": [(1760.0, 0.08)],
+}
+DEFAULT_SOUND = "chime"
+
+
+def chime_path(name: str) -> Path:
+    """The WAV file for a built-in chime, rendering it the first time"""
+    path = SOUNDS_DIR / f"{name}.wav"
+    if not path.exists():
+        SOUNDS_DIR.mkdir(parents=True, exist_ok=True)
+        samples = []
+        for frequency, seconds in BUILTIN_CHIMES[name]:
+            count = int(SAMPLE_RATE * seconds)
+            for i in range(count):
+                # Sine with an exponential decay, so notes ring out instead of clicking off
+                envelope = math.exp(-4.0 * i / count)
+                samples.append(int(0.6 * 32767 * envelope * math.sin(2 * math.pi * frequency * i / SAMPLE_RATE)))
+        with wave.open(str(path), "wb") as out:
+            out.setnchannels(1)
+            out.setsampwidth(2)
+            out.setframerate(SAMPLE_RATE)
+            out.writeframes(struct.pack(f"<{len(samples)}h", *samples))
+    return path
+
+
+def sound_path(sound: str) -> Optional[Path]:
+    """A built-in chime name, "system" (the macOS alert sound) or a path to an audio file; None if missing"""
+    if sound in BUILTIN_CHIMES:
+        return chime_path(sound)
+    if sound == "system":
+        path = Path(SYSTEM_ALERT)
+        return path if sys.platform == "darwin" and path.exists() else chime_path(DEFAULT_SOUND)
+    path = Path(sound).expanduser()
+    return path if path.is_file() else None
+
+
+def output_devices() -> List[dict]:
+    """Audio outputs: [{"id", "name", "default"}]"""
+    return [
+        {"id": bytes(device.id()).decode(errors="replace"), "name": device.description(), "default": device.isDefault()}
+        for device in QMediaDevices.audioOutputs()
+    ]
+
+
+class AlertSound(QObject):
+    """Plays the alert for a set of regions, independent of which window (if any) is showing"""
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.sound = DEFAULT_SOUND
+        self.region_sounds = {}  # Region -> sound, overriding self.sound
+        self.output = QAudioOutput(self)
+        self.player = QMediaPlayer(self)
+        self.player.setAudioOutput(self.output)
+
+    def configure(self, settings: dict):
+        self.sound = settings["alert_sound"] or DEFAULT_SOUND
+        self.region_sounds = dict(settings["region_sounds"])
+        self.output.setVolume(min(1.0, max(0.0, float(settings["alert_volume"]))))
+        self.set_device(settings["audio_output"])
+
+    def set_device(self, device_id: str) -> bool:
+        """Play on this output (see output_devices()); "" or an unplugged device means the system default"""
+        for device in QMediaDevices.audioOutputs():
+            if device_id and bytes(device.id()).decode(errors="replace") == device_id:
+                self.output.setDevice(device)
+                return True
+        self.output.setDevice(QMediaDevices.defaultAudioOutput())
+        return not device_id
+
+    def sound_for(self, regions: List[str]) -> str:
+        """The first alerting region with a sound of its own decides; otherwise the general sound"""
+        for region in regions:
+            if region in self.region_sounds:
+                return self.region_sounds[region]
+        return self.sound
+
+    def play(self, regions: List[str]):
+        sound = self.sound_for(regions)
+        try:
+            path = sound_path(sound)
+            if path is None:
+                print(f"Alert sound {sound} not found, using the default chime")
+                path = chime_path(DEFAULT_SOUND)
+            self.player.stop()
+            self.player.setSource(QUrl.fromLocalFile(str(path)))
+            self.player.play()
+        except Exception as e:
+            print(f"Could not play sound: {e}")
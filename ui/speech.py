@@ -0,0 +1,52 @@
+"""
+Spoken alerts for Mindful Touch
+A short phrase read out through the OS voice (AVSpeechSynthesizer, SAPI/WinRT or speech-dispatcher, via Qt)
+"""
+
+from typing import Optional
+
+from PyQt6.QtCore import QObject
+
+
+class SpokenAlert(QObject):
+    """Takes turns through the configured phrases; the engine is started on first use"""
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.phrases = []  # None configured: the language's default phrase is used
+        self.volume = 0.8
+        self.engine = None
+        self.supported = True  # Cleared if no speech engine could be started
+        self._next = 0
+
+    def configure(self, settings: dict):
+        self.phrases = [phrase for phrase in settings["speech_phrases"] if phrase.strip()]
+        self.volume = min(1.0, max(0.0, float(settings["alert_volume"])))
+        if self.engine is not None:
+            self.engine.setVolume(self.volume)
+
+    def _ensure_engine(self) -> bool:
+        if self.engine is None and self.supported:
+            try:
+                from PyQt6.QtTextToSpeech import QTextToSpeech
+
+                self.engine = QTextToSpeech(self)
+                self.engine.setVolume(self.volume)
+            except Exception as e:
+                print(f"Speech unavailable, not speaking alerts: {e}")
+                self.supported = False
+        return self.engine is not None
+
+    def next_phrase(self) -> Optional[str]:
+        if not self.phrases:
+            return None
+        phrase = self.phrases[self._next % len(self.phrases)]
+        self._next += 1
+        return phrase
+
+    def say(self, text: str):
+        if not self._ensure_engine():
+            return
+        # A new alert cuts off one still being read
+        self.engine.stop()
+        self.engine.say(text)
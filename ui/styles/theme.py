@@ -26,6 +26,7 @@ class Theme:
     SAGE_HOVER = "#688061"
     CLAY = "#B67F5C"  # Touch noticed / alert accents
     CLAY_BORDER = "#E6D9CC"  # Outlined clay button border
+    AMBER = "#D9A441"  # Tray icon pulse after a touch
 
     SOFT_BLUE = "#E7EDF1"  # Detecting pill background
     SOFT_SAGE = "#EEF2EA"  # Ready pill background
@@ -208,8 +209,10 @@ class Theme:
         """Soft status pill: colored text on a pale tinted background"""
         colors = {
             "ready": (Theme.SOFT_SAGE, Theme.SAGE),
+            "starting": (Theme.SOFT_BLUE, Theme.PRIMARY),
             "detecting": (Theme.SOFT_BLUE, Theme.PRIMARY),
             "alert": (Theme.SOFT_CLAY, Theme.CLAY),
+            "paused": (Theme.SOFT_SAGE, Theme.INK_SOFT),
             "error": (Theme.SOFT_CLAY, Theme.CLAY),
         }
         bg, fg = colors.get(status, (Theme.SOFT_SAGE, Theme.SAGE))
@@ -0,0 +1,86 @@
+"""
+Alert Toast Widget
+Small always-on-top notification for a noticed touch, with quick responses
+"""
+
+from PyQt6.QtCore import Qt, QTimer, pyqtSignal
+from PyQt6.QtGui import QGuiApplication
+from PyQt6.QtWidgets import QHBoxLayout, QLabel, QPushButton, QVBoxLayout, QWidget
+
+from ui.styles.theme import Theme
+
+# Responses, as emitted by action_chosen
+SNOOZE = "snooze"
+AWARE = "aware"
+FALSE_POSITIVE = "false_positive"
+
+
+class AlertToast(QWidget):
+    """Frameless corner popup that doesn't take focus; hides itself after a while"""
+
+    TIMEOUT_MS = 8000
+    MARGIN = 16
+
+    action_chosen = pyqtSignal(str)  # SNOOZE, AWARE or FALSE_POSITIVE
+    expired = pyqtSignal()  # Timed out without a response
+
+    def __init__(self, parent=None):
+        flags = Qt.WindowType.Tool | Qt.WindowType.FramelessWindowHint | Qt.WindowType.WindowStaysOnTopHint
+        super().__init__(parent, flags)
+        self.setAttribute(Qt.WidgetAttribute.WA_ShowWithoutActivating)
+        self.setObjectName("alertToast")
+        self.setStyleSheet(f"""
+            QWidget#alertToast {{
+                background-color: {Theme.SURFACE};
+                border: 1px solid {Theme.CLAY_BORDER};
+                border-radius: {Theme.BORDER_RADIUS}px;
+            }}
+        """)
+
+        layout = QVBoxLayout(self)
+        layout.setContentsMargins(Theme.CARD_PADDING, 18, Theme.CARD_PADDING, 18)
+        layout.setSpacing(Theme.ITEM_SPACING)
+
+        self.title = QLabel("Touch noticed")
+        self.title.setStyleSheet(Theme.body_text_style())
+        layout.addWidget(self.title)
+
+        self.detail = QLabel()
+        self.detail.setStyleSheet(Theme.helper_text_style())
+        layout.addWidget(self.detail)
+
+        buttons = QHBoxLayout()
+        buttons.setSpacing(8)
+        for label, action in (("Snooze 10 min", SNOOZE), ("I was aware", AWARE), ("False positive", FALSE_POSITIVE)):
+            button = QPushButton(label)
+            button.setCursor(Qt.CursorShape.PointingHandCursor)
+            button.setStyleSheet(Theme.button_secondary_style())
+            button.clicked.connect(lambda _=False, chosen=action: self._choose(chosen))
+            buttons.addWidget(button)
+        layout.addLayout(buttons)
+
+        self.hide_timer = QTimer(self)
+        self.hide_timer.setSingleShot(True)
+        self.hide_timer.timeout.connect(self._expire)
+
+    def show_alert(self, regions, title=None, detail=None):
+        """Show (or refresh) the toast in the top-right corner of the screen"""
+        self.title.setText(title or "Touch noticed")
+        self.detail.setText(detail or ", ".join(region.capitalize() for region in regions))
+        self.adjustSize()
+        screen = QGuiApplication.primaryScreen()
+        if screen is not None:
+            area = screen.availableGeometry()
+            self.move(area.right() - self.width() - self.MARGIN, area.top() + self.MARGIN)
+        self.show()
+        self.raise_()
+        self.hide_timer.start(self.TIMEOUT_MS)
+
+    def _expire(self):
+        self.hide()
+        self.expired.emit()
+
+    def _choose(self, action):
+        self.hide_timer.stop()
+        self.hide()
+        self.action_chosen.emit(action)
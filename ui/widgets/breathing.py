@@ -0,0 +1,117 @@
+"""
+Breathing Exercise Widget
+Short paced-breathing window offered after a long touch; the circle grows on the in-breath and shrinks on the out-breath
+"""
+
+from PyQt6.QtCore import QElapsedTimer, QRectF, Qt, QTimer, pyqtSignal
+from PyQt6.QtGui import QColor, QPainter
+from PyQt6.QtWidgets import QLabel, QPushButton, QVBoxLayout, QWidget
+
+from ui.styles.theme import Theme
+
+# (prompt, seconds, circle size at the end of the phase as a fraction of full)
+PHASES = [("Breathe in", 4.0, 1.0), ("Hold", 4.0, 1.0), ("Breathe out", 6.0, 0.35)]
+CYCLES = 4
+TICK_MS = 40
+
+
+class BreathingCircle(QWidget):
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.scale = PHASES[-1][2]
+        self.setMinimumSize(220, 220)
+
+    def paintEvent(self, event):
+        size = min(self.width(), self.height()) * self.scale
+        painter = QPainter(self)
+        painter.setRenderHint(QPainter.RenderHint.Antialiasing)
+        painter.setPen(Qt.PenStyle.NoPen)
+        painter.setBrush(QColor(Theme.SOFT_SAGE))
+        painter.drawEllipse(QRectF((self.width() - size) / 2, (self.height() - size) / 2, size, size))
+        painter.end()
+
+
+class BreathingWindow(QWidget):
+    """CYCLES rounds of PHASES; emits finished(True) when they all ran, finished(False) when skipped or closed"""
+
+    finished = pyqtSignal(bool)
+
+    def __init__(self, parent=None):
+        super().__init__(parent, Qt.WindowType.Tool | Qt.WindowType.WindowStaysOnTopHint)
+        self.setWindowTitle("Take a breath")
+        self.setObjectName("breathing")
+        self.setStyleSheet(f"QWidget#breathing {{ background-color: {Theme.CANVAS}; }}")
+        self.done = True  # Nothing running until start()
+
+        layout = QVBoxLayout(self)
+        layout.setContentsMargins(Theme.CARD_PADDING, Theme.CARD_PADDING, Theme.CARD_PADDING, Theme.CARD_PADDING)
+        layout.setSpacing(Theme.ITEM_SPACING)
+
+        self.prompt = QLabel()
+        self.prompt.setAlignment(Qt.AlignmentFlag.AlignCenter)
+        self.prompt.setStyleSheet(Theme.body_text_style())
+        layout.addWidget(self.prompt)
+
+        self.circle = BreathingCircle()
+        layout.addWidget(self.circle)
+
+        self.progress = QLabel()
+        self.progress.setAlignment(Qt.AlignmentFlag.AlignCenter)
+        self.progress.setStyleSheet(Theme.helper_text_style())
+        layout.addWidget(self.progress)
+
+        skip = QPushButton("Skip")
+        skip.setCursor(Qt.CursorShape.PointingHandCursor)
+        skip.setStyleSheet(Theme.button_secondary_style())
+        skip.clicked.connect(self.close)
+        layout.addWidget(skip)
+
+        self.clock = QElapsedTimer()
+        self.timer = QTimer(self)
+        self.timer.timeout.connect(self._tick)
+
+    @staticmethod
+    def total_seconds() -> float:
+        return CYCLES * sum(seconds for _, seconds, _ in PHASES)
+
+    def start(self):
+        self.done = False
+        self.clock.start()
+        self.timer.start(TICK_MS)
+        self._show_at(0.0)
+        self.show()
+        self.raise_()
+
+    def _tick(self):
+        elapsed = self.clock.elapsed() / 1000.0
+        if elapsed >= self.total_seconds():
+            self._finish(True)
+            self.hide()
+            return
+        self._show_at(elapsed)
+
+    def _show_at(self, elapsed: float):
+        """Prompt and circle size for this many seconds into the exercise"""
+        cycle_length = sum(seconds for _, seconds, _ in PHASES)
+        cycle, into = divmod(elapsed, cycle_length)
+        start_scale = PHASES[-1][2]
+        for prompt, seconds, end_scale in PHASES:
+            if into < seconds:
+                self.prompt.setText(prompt)
+                self.circle.scale = start_scale + (end_scale - start_scale) * (into / seconds)
+                break
+            into -= seconds
+            start_scale = end_scale
+        self.progress.setText(f"Breath {int(cycle) + 1} of {CYCLES}")
+        self.circle.update()
+
+    def _finish(self, completed: bool):
+        if self.done:
+            return
+        self.done = True
+        self.timer.stop()
+        self.finished.emit(completed)
+
+    def closeEvent(self, event):
+        self._finish(False)
+        super().closeEvent(event)
@@ -0,0 +1,81 @@
+"""
+Edge Flash Widget
+Colored border flashed around every screen on an alert, for people who tune out notifications
+"""
+
+from typing import List
+
+from PyQt6.QtCore import QObject, Qt, QTimer
+from PyQt6.QtGui import QColor, QGuiApplication, QPainter, QPen
+from PyQt6.QtWidgets import QWidget
+
+from ui.styles.theme import Theme
+
+MAX_BORDER = 48  # Border width in px at full intensity
+
+
+class EdgeFrame(QWidget):
+    """Transparent, click-through, always-on-top window covering one screen, painted only at the edges"""
+
+    def __init__(self, screen, parent=None):
+        flags = (
+            Qt.WindowType.Tool
+            | Qt.WindowType.FramelessWindowHint
+            | Qt.WindowType.WindowStaysOnTopHint
+            | Qt.WindowType.WindowTransparentForInput
+            | Qt.WindowType.WindowDoesNotAcceptFocus
+        )
+        super().__init__(parent, flags)
+        self.setAttribute(Qt.WidgetAttribute.WA_TranslucentBackground)
+        self.setAttribute(Qt.WidgetAttribute.WA_ShowWithoutActivating)
+        self.color = QColor(Theme.CLAY)
+        self.intensity = 0.6
+        self.setGeometry(screen.geometry())
+
+    def paintEvent(self, event):
+        width = max(2, int(MAX_BORDER * self.intensity))
+        color = QColor(self.color)
+        color.setAlphaF(min(1.0, 0.3 + 0.7 * self.intensity))
+        painter = QPainter(self)
+        painter.setPen(QPen(color, width))
+        # The pen is centered on the path, so inset by half its width to keep it on screen
+        painter.drawRect(self.rect().adjusted(width // 2, width // 2, -(width // 2), -(width // 2)))
+        painter.end()
+
+
+class EdgeFlash(QObject):
+    """One EdgeFrame per screen, shown together for duration_ms"""
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.color = Theme.CLAY
+        self.duration_ms = 600
+        self.intensity = 0.6  # 0..1: border width and opacity
+        self.frames: List[EdgeFrame] = []
+
+        self.hide_timer = QTimer(self)
+        self.hide_timer.setSingleShot(True)
+        self.hide_timer.timeout.connect(self.hide)
+
+    def configure(self, settings: dict):
+        self.color = settings["edge_flash_color"] if QColor(settings["edge_flash_color"]).isValid() else Theme.CLAY
+        self.duration_ms = max(100, int(settings["edge_flash_duration_ms"]))
+        self.intensity = min(1.0, max(0.0, float(settings["edge_flash_intensity"])))
+
+    def flash(self):
+        # Screens come and go (docking, projectors), so frames are made per flash
+        self.hide()
+        for screen in QGuiApplication.screens():
+            frame = EdgeFrame(screen)
+            frame.color = QColor(self.color)
+            frame.intensity = self.intensity
+            frame.show()
+            self.frames.append(frame)
+        self.hide_timer.start(self.duration_ms)
+
+    def hide(self):
+        self.hide_timer.stop()
+        for frame in self.frames:
+            frame.close()
+            frame.deleteLater()
+        self.frames = []
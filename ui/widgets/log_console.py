@@ -0,0 +1,84 @@
+"""
+Log Console Widget
+Live view of captured backend output (stdout/stderr)
+"""
+
+import time
+
+from PyQt6.QtCore import Qt, QUrl, pyqtSignal
+from PyQt6.QtGui import QDesktopServices, QFont
+from PyQt6.QtWidgets import QHBoxLayout, QLabel, QPlainTextEdit, QPushButton, QVBoxLayout, QWidget
+
+from backend import logs
+from ui.styles.theme import Theme
+
+
+class LogConsole(QWidget):
+    """Separate window tailing the log ring buffer"""
+
+    # Log lines arrive from any thread; the signal hops them onto the UI thread
+    backend_log = pyqtSignal(dict)
+
+    def __init__(self, parent=None):
+        super().__init__(parent, Qt.WindowType.Window)
+        self.setWindowTitle("Mindful Touch — Logs")
+        self.resize(720, 420)
+        self.setStyleSheet(f"QWidget {{ background-color: {Theme.CANVAS}; }}")
+        self.setup_ui()
+
+        for entry in logs.get_backend_logs():
+            self._append(entry)
+
+        self.backend_log.connect(self._append)
+        logs.add_listener(self.backend_log.emit)
+
+    def setup_ui(self):
+        layout = QVBoxLayout(self)
+        layout.setContentsMargins(Theme.CARD_PADDING, Theme.CARD_PADDING, Theme.CARD_PADDING, Theme.CARD_PADDING)
+        layout.setSpacing(Theme.ITEM_SPACING)
+
+        header_row = QHBoxLayout()
+        title = QLabel(f"Last {logs.MAX_LINES} lines")
+        title.setStyleSheet(Theme.section_title_style())
+        header_row.addWidget(title)
+        header_row.addStretch()
+
+        folder_button = QPushButton("Open log folder")
+        folder_button.setCursor(Qt.CursorShape.PointingHandCursor)
+        folder_button.setStyleSheet(Theme.button_secondary_style())
+        folder_button.setToolTip(str(logs.get_log_path()))
+        folder_button.clicked.connect(self._open_log_folder)
+        header_row.addWidget(folder_button)
+
+        clear_button = QPushButton("Clear")
+        clear_button.setCursor(Qt.CursorShape.PointingHandCursor)
+        clear_button.setStyleSheet(Theme.button_secondary_style())
+        clear_button.clicked.connect(lambda: self.text.clear())
+        header_row.addWidget(clear_button)
+        layout.addLayout(header_row)
+
+        self.text = QPlainTextEdit()
+        self.text.setReadOnly(True)
+        self.text.setMaximumBlockCount(logs.MAX_LINES)
+        self.text.setFont(QFont("Menlo", 11))
+        self.text.setStyleSheet(f"""
+            QPlainTextEdit {{
+                background-color: {Theme.SURFACE};
+                color: {Theme.INK};
+                border: 1px solid {Theme.BORDER};
+                border-radius: 12px;
+                padding: 8px;
+            }}
+        """)
+        layout.addWidget(self.text)
+
+    def _open_log_folder(self):
+        folder = logs.get_log_path().parent
+        folder.mkdir(parents=True, exist_ok=True)
+        QDesktopServices.openUrl(QUrl.fromLocalFile(str(folder)))
+
+    def _append(self, entry):
+        """Add one captured line, marking stderr output"""
+        stamp = time.strftime("%H:%M:%S", time.localtime(entry["time"]))
+        marker = "!" if entry["source"] == "stderr" else " "
+        self.text.appendPlainText(f"{stamp} {marker} {entry['line']}")
@@ -0,0 +1,80 @@
+"""
+Mini Controls Widget
+Small always-available window standing in for the tray icon on desktops without a tray
+"""
+
+from typing import Optional
+
+from PyQt6.QtCore import Qt, pyqtSignal
+from PyQt6.QtWidgets import QHBoxLayout, QLabel, QPushButton, QWidget
+
+from ui.styles.theme import Theme
+from ui.widgets.status_badge import LogoMark
+
+
+class MiniControls(QWidget):
+    """Same signals and set_state() as TrayIcon, so the window can drive either"""
+
+    pause_requested = pyqtSignal()
+    resume_requested = pyqtSignal()
+    open_requested = pyqtSignal()
+    quit_requested = pyqtSignal()
+
+    def __init__(self, parent=None):
+        super().__init__(parent, Qt.WindowType.Tool | Qt.WindowType.WindowStaysOnTopHint)
+        self.setWindowTitle("Mindful Touch")
+        self.setObjectName("miniControls")
+        self.setStyleSheet(f"QWidget#miniControls {{ background-color: {Theme.CANVAS}; }}")
+        self.paused = False
+        self.detecting = False
+        self.snooze_minutes = None
+
+        layout = QHBoxLayout(self)
+        layout.setContentsMargins(12, 8, 12, 8)
+        layout.setSpacing(8)
+        layout.addWidget(LogoMark(18))
+
+        self.status = QLabel()
+        self.status.setStyleSheet(Theme.helper_text_style())
+        layout.addWidget(self.status)
+
+        self.pause_button = QPushButton()
+        self.pause_button.clicked.connect(self._on_pause_clicked)
+        open_button = QPushButton("Open")
+        open_button.clicked.connect(self.open_requested.emit)
+        quit_button = QPushButton("Quit")
+        quit_button.clicked.connect(self.quit_requested.emit)
+        for button in (self.pause_button, open_button, quit_button):
+            button.setCursor(Qt.CursorShape.PointingHandCursor)
+            button.setStyleSheet(Theme.button_secondary_style())
+            layout.addWidget(button)
+
+        self.set_state(detecting=False, paused=False)
+
+    def set_state(self, detecting: bool, paused: bool):
+        self.paused = paused
+        self.detecting = detecting
+        self._update_status()
+        self.pause_button.setText("Resume" if paused else "Pause")
+        self.pause_button.setEnabled(detecting)
+
+    def set_snooze(self, minutes_left: Optional[int]):
+        self.snooze_minutes = minutes_left
+        self._update_status()
+
+    def _update_status(self):
+        text = ("Paused" if self.paused else "Monitoring") if self.detecting else "Not detecting"
+        if self.snooze_minutes is not None:
+            text += f" · alerts snoozed {self.snooze_minutes}m"
+        self.status.setText(text)
+
+    def _on_pause_clicked(self):
+        if self.paused:
+            self.resume_requested.emit()
+        else:
+            self.pause_requested.emit()
+
+    def closeEvent(self, event):
+        # Closing the stand-in would leave no way back to the app
+        event.ignore()
+        self.open_requested.emit()
@@ -0,0 +1,49 @@
+"""
+Preview Window
+Picture-in-picture view of the annotated camera frames (landmarks and hand boxes), for checking what the detector sees while calibrating
+"""
+
+from PyQt6.QtCore import Qt, pyqtSignal
+from PyQt6.QtWidgets import QLabel, QSizePolicy, QVBoxLayout, QWidget
+
+from backend.i18n import tr
+from ui.styles.theme import Theme
+
+
+class PreviewWindow(QWidget):
+    """Resizable and on top of other windows; emits closed when it goes away, however it was closed"""
+
+    closed = pyqtSignal()
+
+    def __init__(self, parent=None):
+        super().__init__(parent, Qt.WindowType.Tool | Qt.WindowType.WindowStaysOnTopHint)
+        self.setObjectName("previewWindow")
+        self.setStyleSheet(f"QWidget#previewWindow {{ background-color: {Theme.CANVAS}; }}")
+        self.resize(360, 270)
+
+        layout = QVBoxLayout(self)
+        layout.setContentsMargins(0, 0, 0, 0)
+        self.view = QLabel()
+        self.view.setAlignment(Qt.AlignmentFlag.AlignCenter)
+        self.view.setSizePolicy(QSizePolicy.Policy.Ignored, QSizePolicy.Policy.Ignored)
+        self.view.setMinimumSize(160, 120)
+        self.view.setWordWrap(True)
+        self.view.setStyleSheet(Theme.helper_text_style())
+        layout.addWidget(self.view)
+
+        self.retranslate()
+
+    def retranslate(self):
+        self.setWindowTitle(tr("Detector view"))
+
+    def set_message(self, text: str):
+        """Replace the picture with text, e.g. while no frames are coming"""
+        self.view.clear()
+        self.view.setText(text)
+
+    def update_frame(self, pixmap):
+        self.view.setPixmap(pixmap.scaled(self.view.size(), Qt.AspectRatioMode.KeepAspectRatio, Qt.TransformationMode.SmoothTransformation))
+
+    def closeEvent(self, event):
+        super().closeEvent(event)
+        self.closed.emit()
@@ -20,7 +20,14 @@ class StatusBadge(QLabel):
 
     def set_status(self, status):
         """Update badge status and appearance"""
-        status_map = {"ready": "Ready", "detecting": "Detecting", "alert": "Touch noticed", "error": "Error"}
+        status_map = {
+            "ready": "Ready",
+            "starting": "Starting…",
+            "detecting": "Detecting",
+            "alert": "Touch noticed",
+            "paused": "Suspended",
+            "error": "Error",
+        }
 
         text = status_map.get(status, "Unknown")
         self.setText(text)
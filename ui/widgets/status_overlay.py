@@ -0,0 +1,82 @@
+"""
+Status Overlay Widget
+Small draggable always-on-top window with live status, touches today and the current clean streak
+"""
+
+from PyQt6.QtCore import QPoint, Qt, pyqtSignal
+from PyQt6.QtWidgets import QHBoxLayout, QLabel, QVBoxLayout, QWidget
+
+from ui.styles.theme import Theme
+from ui.widgets.status_badge import LogoMark
+
+
+class StatusOverlay(QWidget):
+    """Frameless, doesn't take focus; drag it anywhere, it reports where it was left"""
+
+    moved = pyqtSignal(int, int)  # New top-left position, after a drag
+
+    def __init__(self, parent=None):
+        flags = Qt.WindowType.Tool | Qt.WindowType.FramelessWindowHint | Qt.WindowType.WindowStaysOnTopHint | Qt.WindowType.WindowDoesNotAcceptFocus
+        super().__init__(parent, flags)
+        self.setAttribute(Qt.WidgetAttribute.WA_ShowWithoutActivating)
+        self.setObjectName("statusOverlay")
+        self.setStyleSheet(f"""
+            QWidget#statusOverlay {{
+                background-color: {Theme.SURFACE};
+                border: 1px solid {Theme.BORDER};
+                border-radius: {Theme.BORDER_RADIUS}px;
+            }}
+        """)
+        self.setCursor(Qt.CursorShape.OpenHandCursor)
+        self._drag_offset = None
+
+        layout = QHBoxLayout(self)
+        layout.setContentsMargins(12, 8, 14, 8)
+        layout.setSpacing(10)
+        layout.addWidget(LogoMark(18))
+
+        text = QVBoxLayout()
+        text.setSpacing(2)
+        self.status = QLabel()
+        self.status.setStyleSheet(Theme.body_text_style())
+        text.addWidget(self.status)
+        self.counts = QLabel()
+        self.counts.setStyleSheet(Theme.helper_text_style())
+        text.addWidget(self.counts)
+        layout.addLayout(text)
+
+        self.set_state(detecting=False, paused=False)
+        self.set_counts(0, 0)
+
+    def set_state(self, detecting: bool, paused: bool):
+        self.status.setText(("Paused" if paused else "Monitoring") if detecting else "Not monitoring")
+
+    def set_counts(self, touches_today: int, streak_seconds: float):
+        minutes = int(streak_seconds) // 60
+        streak = f"{minutes // 60}h {minutes % 60:02d}m" if minutes >= 60 else f"{minutes}m"
+        self.counts.setText(f"{touches_today} today · {streak} clean")
+        self.adjustSize()
+
+    def mousePressEvent(self, event):
+        if event.button() == Qt.MouseButton.LeftButton:
+            self._drag_offset = event.globalPosition().toPoint() - self.pos()
+            self.setCursor(Qt.CursorShape.ClosedHandCursor)
+
+    def mouseMoveEvent(self, event):
+        if self._drag_offset is not None:
+            self.move(event.globalPosition().toPoint() - self._drag_offset)
+
+    def mouseReleaseEvent(self, event):
+        if self._drag_offset is not None:
+            self._drag_offset = None
+            self.setCursor(Qt.CursorShape.OpenHandCursor)
+            self.moved.emit(self.x(), self.y())
+
+    def restore_position(self, position):
+        """Move to a saved [x, y], if it is still on some screen"""
+        if not position:
+            return
+        point = QPoint(int(position[0]), int(position[1]))
+        screen = self.screen().virtualSiblingAt(point) if self.screen() else None
+        if screen is not None:
+            self.move(point)
@@ -0,0 +1,126 @@
+"""
+Streak Widget
+Tiny always-on-top window pinned to a screen corner with the clean streak and a pause button; stays up while the main window is closed
+"""
+
+from typing import Optional
+
+from PyQt6.QtCore import Qt, pyqtSignal
+from PyQt6.QtGui import QGuiApplication
+from PyQt6.QtWidgets import QHBoxLayout, QLabel, QPushButton, QWidget
+
+from backend.i18n import tr
+from ui.styles.theme import Theme
+from ui.widgets.status_badge import LogoMark
+
+CORNERS = ["top_left", "top_right", "bottom_left", "bottom_right"]
+DEFAULT_CORNER = "bottom_right"
+MARGIN = 16  # Pixels from the screen edges
+
+
+class StreakWidget(QWidget):
+    """Frameless, doesn't take focus; dragged anywhere, it snaps to the nearest corner of that screen"""
+
+    pause_requested = pyqtSignal()
+    resume_requested = pyqtSignal()
+    close_requested = pyqtSignal()
+    pinned = pyqtSignal(str, str)  # Screen name and corner, after a drag
+
+    def __init__(self, parent=None):
+        flags = Qt.WindowType.Tool | Qt.WindowType.FramelessWindowHint | Qt.WindowType.WindowStaysOnTopHint | Qt.WindowType.WindowDoesNotAcceptFocus
+        super().__init__(parent, flags)
+        self.setAttribute(Qt.WidgetAttribute.WA_ShowWithoutActivating)
+        self.setObjectName("streakWidget")
+        self.setStyleSheet(f"""
+            QWidget#streakWidget {{
+                background-color: {Theme.SURFACE};
+                border: 1px solid {Theme.BORDER};
+                border-radius: {Theme.BORDER_RADIUS}px;
+            }}
+        """)
+        self.setCursor(Qt.CursorShape.OpenHandCursor)
+        self._drag_offset = None
+        self.detecting = False
+        self.paused = False
+        self.streak_seconds = None
+
+        layout = QHBoxLayout(self)
+        layout.setContentsMargins(10, 6, 6, 6)
+        layout.setSpacing(8)
+        layout.addWidget(LogoMark(16))
+
+        self.streak = QLabel()
+        self.streak.setStyleSheet(Theme.body_text_style())
+        layout.addWidget(self.streak)
+
+        self.pause_button = QPushButton()
+        self.pause_button.clicked.connect(self._on_pause_clicked)
+        close_button = QPushButton("×")
+        close_button.clicked.connect(self.close_requested.emit)
+        for button in (self.pause_button, close_button):
+            button.setCursor(Qt.CursorShape.PointingHandCursor)
+            button.setStyleSheet(Theme.button_secondary_style())
+            layout.addWidget(button)
+
+        self.retranslate()
+
+    def retranslate(self):
+        """Label everything again in the current i18n language"""
+        self.set_state(self.detecting, self.paused)
+        self.set_streak(self.streak_seconds)
+
+    def set_state(self, detecting: bool, paused: bool):
+        self.detecting, self.paused = detecting, paused
+        self.pause_button.setText(tr("Resume") if paused else tr("Pause"))
+        self.pause_button.setEnabled(detecting)
+
+    def set_streak(self, seconds: Optional[float]):
+        """The clean streak in progress ("2h 14m hands-free"), or that detection is off (None)"""
+        self.streak_seconds = seconds
+        if seconds is None:
+            self.streak.setText(tr("Not detecting"))
+        else:
+            minutes = int(seconds) // 60
+            duration = f"{minutes // 60}h {minutes % 60:02d}m" if minutes >= 60 else f"{minutes}m"
+            self.streak.setText(tr("{duration} hands-free", duration=duration))
+        self.adjustSize()
+
+    def _on_pause_clicked(self):
+        if self.paused:
+            self.resume_requested.emit()
+        else:
+            self.pause_requested.emit()
+
+    def pin(self, corners: dict, screen_name: Optional[str] = None):
+        """Go to the saved corner (corners maps screen names to one) of the named screen, or of the primary one if it's gone"""
+        screen = next((screen for screen in QGuiApplication.screens() if screen.name() == screen_name), None) or QGuiApplication.primaryScreen()
+        if screen is not None:
+            self._place(screen, corners.get(screen.name(), DEFAULT_CORNER))
+
+    def _place(self, screen, corner: str):
+        self.adjustSize()
+        area = screen.availableGeometry()
+        x = area.left() + MARGIN if corner.endswith("left") else area.right() - self.width() - MARGIN
+        y = area.top() + MARGIN if corner.startswith("top") else area.bottom() - self.height() - MARGIN
+        self.move(x, y)
+
+    def mousePressEvent(self, event):
+        if event.button() == Qt.MouseButton.LeftButton:
+            self._drag_offset = event.globalPosition().toPoint() - self.pos()
+            self.setCursor(Qt.CursorShape.ClosedHandCursor)
+
+    def mouseMoveEvent(self, event):
+        if self._drag_offset is not None:
+            self.move(event.globalPosition().toPoint() - self._drag_offset)
+
+    def mouseReleaseEvent(self, event):
+        if self._drag_offset is None:
+            return
+        self._drag_offset = None
+        self.setCursor(Qt.CursorShape.OpenHandCursor)
+        centre = self.frameGeometry().center()
+        screen = QGuiApplication.screenAt(centre) or self.screen()
+        area = screen.availableGeometry()
+        corner = f"{'top' if centre.y() < area.center().y() else 'bottom'}_{'left' if centre.x() < area.center().x() else 'right'}"
+        self._place(screen, corner)
+        self.pinned.emit(screen.name(), corner)
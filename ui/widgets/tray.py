@@ -0,0 +1,238 @@
+"""
+Tray Icon Widget
+System tray / menu bar icon with quick detection controls
+"""
+
+import sys
+from typing import Optional
+
+from PyQt6.QtCore import Qt, QTimer, pyqtSignal
+from PyQt6.QtGui import QAction, QColor, QIcon, QPainter, QPixmap
+from PyQt6.QtSvg import QSvgRenderer
+from PyQt6.QtWidgets import QMenu, QSystemTrayIcon
+
+from backend.i18n import tr
+from ui.styles.theme import LOGO_SVG, Theme
+
+
+class TrayIcon(QSystemTrayIcon):
+    """Tray icon whose menu mirrors the detection state; actions are emitted, not applied here
+
+    A dot on the logo shows the state at a glance: sage while monitoring, gray
+    while paused, and a brief amber blink after a touch.
+    """
+
+    DOT_COLORS = {"monitoring": Theme.SAGE, "paused": Theme.MUTED, "touch": Theme.AMBER}
+    ICON_SIZE = 64
+    PULSE_BLINKS = 6  # Amber on/off half-periods after a touch
+    PULSE_INTERVAL_MS = 250
+    SNOOZE_CHOICES = [15, 30, 60]  # Minutes offered in the Snooze Alerts menu
+    OVERRIDE_MINUTES = 60  # How long "Detect Anyway" runs outside the active hours
+
+    pause_requested = pyqtSignal()
+    resume_requested = pyqtSignal()
+    open_requested = pyqtSignal()
+    quit_requested = pyqtSignal()
+    menu_bar_mode_toggled = pyqtSignal(bool)  # macOS only: hide (True) or show the Dock icon
+    snooze_requested = pyqtSignal(int)  # Minutes
+    unsnooze_requested = pyqtSignal()
+    profile_requested = pyqtSignal(str)
+    override_schedule_requested = pyqtSignal(int)  # Minutes to detect outside the active hours
+
+    def __init__(self, parent=None):
+        super().__init__(parent)
+        self.icons = {}  # Indicator state -> rendered QIcon
+        self.indicator = None
+        self.base_indicator = "idle"  # What the icon settles back to after a pulse
+        self.pulse_ticks = 0
+        self.pulse_timer = QTimer(self)
+        self.pulse_timer.timeout.connect(self._on_pulse_tick)
+        self.snooze_minutes_left = None
+        self.streak_seconds = None
+
+        self.menu = QMenu()
+
+        # While detecting: the clean streak so far
+        self.streak_action = QAction(self)
+        self.streak_action.setEnabled(False)
+        self.menu.addAction(self.streak_action)
+
+        self.pause_action = QAction(self)
+        self.pause_action.setCheckable(True)
+        self.pause_action.triggered.connect(self._on_pause_triggered)
+        self.menu.addAction(self.pause_action)
+
+        self.resume_action = QAction(self)
+        self.resume_action.triggered.connect(self.resume_requested.emit)
+        self.menu.addAction(self.resume_action)
+
+        self.snooze_menu = self.menu.addMenu("")
+        self.snooze_choice_actions = []
+        for minutes in self.SNOOZE_CHOICES:
+            action = QAction(self)
+            action.triggered.connect(lambda _=False, minutes=minutes: self.snooze_requested.emit(minutes))
+            self.snooze_menu.addAction(action)
+            self.snooze_choice_actions.append((minutes, action))
+
+        # Shown only while snoozed: how long is left, and a way to end it early
+        self.snooze_status_action = QAction("", self)
+        self.snooze_status_action.setEnabled(False)
+        self.menu.addAction(self.snooze_status_action)
+        self.unsnooze_action = QAction(self)
+        self.unsnooze_action.triggered.connect(self.unsnooze_requested.emit)
+        self.menu.addAction(self.unsnooze_action)
+
+        # Shown only outside the active hours
+        self.schedule_status_action = QAction(self)
+        self.schedule_status_action.setEnabled(False)
+        self.menu.addAction(self.schedule_status_action)
+        self.override_action = QAction(self)
+        self.override_action.triggered.connect(lambda: self.override_schedule_requested.emit(self.OVERRIDE_MINUTES))
+        self.menu.addAction(self.override_action)
+
+        # Filled in by set_profiles; hidden while there are none
+        self.profile_menu = self.menu.addMenu("")
+
+        self.menu.addSeparator()
+
+        self.open_action = QAction(self)
+        self.open_action.triggered.connect(self.open_requested.emit)
+        self.menu.addAction(self.open_action)
+
+        # A menu-bar-only app has no app menu, so the switch back lives here
+        self.menu_bar_action = QAction(self)
+        self.menu_bar_action.setCheckable(True)
+        self.menu_bar_action.triggered.connect(self.menu_bar_mode_toggled.emit)
+        self.menu_bar_action.setVisible(sys.platform == "darwin")
+        self.menu.addAction(self.menu_bar_action)
+
+        self.quit_action = QAction(self)
+        self.quit_action.triggered.connect(self.quit_requested.emit)
+        self.menu.addAction(self.quit_action)
+
+        self.setContextMenu(self.menu)
+        self.activated.connect(self._on_activated)
+        self.retranslate()
+        self.set_state(detecting=False, paused=False)
+        self.set_snooze(None)
+        self.set_outside_schedule(False)
+        self.set_profiles([], "")
+        self.set_streak(None)
+
+    def retranslate(self):
+        """Label everything again in the current i18n language"""
+        self.pause_action.setText(tr("Pause Detection"))
+        self.resume_action.setText(tr("Resume"))
+        self.snooze_menu.setTitle(tr("Snooze Alerts"))
+        for minutes, action in self.snooze_choice_actions:
+            action.setText(tr("For {minutes} Minutes", minutes=minutes))
+        self.unsnooze_action.setText(tr("Resume Alerts"))
+        self.schedule_status_action.setText(tr("Outside scheduled hours"))
+        self.override_action.setText(tr("Detect Anyway for an Hour"))
+        self.profile_menu.setTitle(tr("Profile"))
+        self.open_action.setText(tr("Open Dashboard"))
+        self.menu_bar_action.setText(tr("Hide Dock Icon"))
+        self.quit_action.setText(tr("Quit"))
+        self.set_snooze(self.snooze_minutes_left)
+        self.set_streak(self.streak_seconds)
+
+    def set_streak(self, seconds: Optional[float]):
+        """Show the clean streak ("2h 14m hands-free"), or hide it (None)"""
+        self.streak_seconds = seconds
+        if seconds is not None:
+            minutes = int(seconds) // 60
+            duration = f"{minutes // 60}h {minutes % 60:02d}m" if minutes >= 60 else f"{minutes}m"
+            self.streak_action.setText(tr("{duration} hands-free", duration=duration))
+        self.streak_action.setVisible(seconds is not None)
+
+    def set_outside_schedule(self, outside: bool):
+        self.schedule_status_action.setVisible(outside)
+        self.override_action.setVisible(outside)
+
+    def set_profiles(self, names, active: str):
+        """One checkable entry per profile, the active one checked"""
+        self.profile_menu.clear()
+        for name in names:
+            action = QAction(name, self.profile_menu)
+            action.setCheckable(True)
+            action.setChecked(name == active)
+            action.triggered.connect(lambda _=False, name=name: self.profile_requested.emit(name))
+            self.profile_menu.addAction(action)
+        self.profile_menu.menuAction().setVisible(bool(names))
+
+    def set_snooze(self, minutes_left: Optional[int]):
+        """Show how long alerts stay snoozed, or hide the indicator (None)"""
+        self.snooze_minutes_left = minutes_left
+        snoozed = minutes_left is not None
+        self.snooze_status_action.setText(tr("Alerts snoozed · {minutes} min left", minutes=minutes_left) if snoozed else "")
+        self.snooze_status_action.setVisible(snoozed)
+        self.unsnooze_action.setVisible(snoozed)
+        self.setToolTip(tr("Mindful Touch (alerts snoozed, {minutes} min left)", minutes=minutes_left) if snoozed else "Mindful Touch")
+
+    def set_state(self, detecting: bool, paused: bool):
+        """Checkmarks and enabled actions follow the window's detection state"""
+        self.pause_action.setEnabled(detecting)
+        self.pause_action.setChecked(detecting and paused)
+        self.resume_action.setEnabled(detecting and paused)
+
+        self.base_indicator = ("paused" if paused else "monitoring") if detecting else "idle"
+        if self.base_indicator != "monitoring":
+            self.pulse_timer.stop()
+            self.pulse_ticks = 0
+        if not self.pulse_timer.isActive():
+            self._show_indicator(self.base_indicator)
+
+    def pulse(self, _=None):
+        """Blink amber for a moment, e.g. when a touch was just noticed"""
+        if self.base_indicator != "monitoring":
+            return
+        self.pulse_ticks = self.PULSE_BLINKS
+        self._show_indicator("touch")
+        self.pulse_timer.start(self.PULSE_INTERVAL_MS)
+
+    def _on_pulse_tick(self):
+        self.pulse_ticks -= 1
+        if self.pulse_ticks <= 0:
+            self.pulse_timer.stop()
+            self._show_indicator(self.base_indicator)
+        else:
+            self._show_indicator("touch" if self.pulse_ticks % 2 == 0 else self.base_indicator)
+
+    def _show_indicator(self, indicator):
+        if indicator == self.indicator:
+            return
+        self.indicator = indicator
+        if indicator not in self.icons:
+            self.icons[indicator] = self._render_icon(self.DOT_COLORS.get(indicator))
+        self.setIcon(self.icons[indicator])
+
+    def _render_icon(self, dot_color=None) -> QIcon:
+        """The logo, with a status dot in the lower right corner"""
+        size = self.ICON_SIZE
+        pixmap = QPixmap(size, size)
+        pixmap.fill(Qt.GlobalColor.transparent)
+        painter = QPainter(pixmap)
+        painter.setRenderHint(QPainter.RenderHint.Antialiasing)
+        QSvgRenderer(LOGO_SVG.encode()).render(painter)
+        if dot_color:
+            dot = size * 3 // 8
+            painter.setPen(QColor(Theme.CANVAS))
+            painter.setBrush(QColor(dot_color))
+            painter.drawEllipse(size - dot - 1, size - dot - 1, dot, dot)
+        painter.end()
+        return QIcon(pixmap)
+
+    def _on_pause_triggered(self, checked):
+        # Unchecking "Pause Detection" is the same as choosing Resume
+        if checked:
+            self.pause_requested.emit()
+        else:
+            self.resume_requested.emit()
+
+    def _on_activated(self, reason):
+        # A click in the macOS menu bar opens the menu; elsewhere it opens the window
+        opens = [QSystemTrayIcon.ActivationReason.DoubleClick]
+        if sys.platform != "darwin":
+            opens.append(QSystemTrayIcon.ActivationReason.Trigger)
+        if reason in opens:
+            self.open_requested.emit()